@@ -143,6 +143,20 @@ fn main() {
         use_feature_or_nothing("wasi_ext");
     }
 
+    // The Linux architectures for which the libc crate has `termios2`, so
+    // that the libc backend can use `TCGETS2` and `TCSETS2`.
+    if (target_os == "linux" || target_os == "android")
+        && (arch == "x86"
+            || arch == "x86_64"
+            || arch == "riscv64"
+            || arch == "aarch64"
+            || arch == "arm"
+            || arch == "mips"
+            || arch == "mips64")
+    {
+        use_feature("termios2");
+    }
+
     println!("cargo:rerun-if-env-changed=CARGO_CFG_RUSTIX_USE_EXPERIMENTAL_ASM");
     println!("cargo:rerun-if-env-changed=CARGO_CFG_RUSTIX_USE_LIBC");
 
@@ -58,264 +58,44 @@ fn show<Fd: AsFd>(fd: Fd) -> io::Result<()> {
             use rustix::termios::*;
             let term = tcgetattr(fd)?;
 
-            if let Some(speed) = speed_value(cfgetispeed(&term)) {
-                println!(" - ispeed: {}", speed);
-            }
-            if let Some(speed) = speed_value(cfgetospeed(&term)) {
-                println!(" - ospeed: {}", speed);
-            }
-
-            print!(" - in flags:");
-            if (term.c_iflag & IGNBRK) != 0 {
-                print!(" IGNBRK");
-            }
-            if (term.c_iflag & BRKINT) != 0 {
-                print!(" BRKINT");
-            }
-            if (term.c_iflag & IGNPAR) != 0 {
-                print!(" IGNPAR");
-            }
-            if (term.c_iflag & PARMRK) != 0 {
-                print!(" PARMRK");
-            }
-            if (term.c_iflag & INPCK) != 0 {
-                print!(" INPCK");
-            }
-            if (term.c_iflag & ISTRIP) != 0 {
-                print!(" ISTRIP");
-            }
-            if (term.c_iflag & INLCR) != 0 {
-                print!(" INLCR");
-            }
-            if (term.c_iflag & IGNCR) != 0 {
-                print!(" IGNCR");
-            }
-            if (term.c_iflag & ICRNL) != 0 {
-                print!(" ICRNL");
-            }
-            #[cfg(any(linux_raw, all(libc, any(solarish, target_os = "haiku"))))]
-            if (term.c_iflag & IUCLC) != 0 {
-                print!(" IUCLC");
-            }
-            if (term.c_iflag & IXON) != 0 {
-                print!(" IXON");
-            }
-            if (term.c_iflag & IXANY) != 0 {
-                print!(" IXANY");
-            }
-            if (term.c_iflag & IXOFF) != 0 {
-                print!(" IXOFF");
-            }
-            #[cfg(not(target_os = "haiku"))]
-            if (term.c_iflag & IMAXBEL) != 0 {
-                print!(" IMAXBEL");
-            }
-            #[cfg(not(any(
-                bsd,
-                solarish,
-                target_os = "aix",
-                target_os = "emscripten",
-                target_os = "haiku",
-                target_os = "redox",
-            )))]
-            if (term.c_iflag & IUTF8) != 0 {
-                print!(" IUTF8");
-            }
-            println!();
-
-            print!(" - out flags:");
-            if (term.c_oflag & OPOST) != 0 {
-                print!(" OPOST");
-            }
-            #[cfg(not(any(bsd, target_os = "aix", target_os = "redox")))]
-            if (term.c_oflag & OLCUC) != 0 {
-                print!(" OLCUC");
-            }
-            if (term.c_oflag & ONLCR) != 0 {
-                print!(" ONLCR");
-            }
-            if (term.c_oflag & OCRNL) != 0 {
-                print!(" OCRNL");
-            }
-            if (term.c_oflag & ONOCR) != 0 {
-                print!(" ONOCR");
-            }
-            if (term.c_oflag & ONLRET) != 0 {
-                print!(" ONLRET");
-            }
-            #[cfg(not(bsd))]
-            if (term.c_oflag & OFILL) != 0 {
-                print!(" OFILL");
-            }
-            #[cfg(not(bsd))]
-            if (term.c_oflag & OFDEL) != 0 {
-                print!(" OFDEL");
-            }
-            #[cfg(not(any(bsd, solarish, target_os = "redox")))]
-            if (term.c_oflag & NLDLY) != 0 {
-                print!(" NLDLY");
-            }
-            #[cfg(not(any(bsd, solarish, target_os = "aix", target_os = "redox")))]
-            if (term.c_oflag & CRDLY) != 0 {
-                print!(" CRDLY");
-            }
-            #[cfg(not(any(netbsdlike, solarish, target_os = "dragonfly", target_os = "redox")))]
-            if (term.c_oflag & TABDLY) != 0 {
-                print!(" TABDLY");
-            }
-            #[cfg(not(any(bsd, solarish, target_os = "redox")))]
-            if (term.c_oflag & BSDLY) != 0 {
-                print!(" BSDLY");
-            }
-            #[cfg(not(any(all(libc, target_env = "musl"), bsd, solarish, target_os = "redox")))]
-            if (term.c_oflag & VTDLY) != 0 {
-                print!(" VTDLY");
-            }
-            #[cfg(not(any(all(libc, target_env = "musl"), bsd, solarish, target_os = "redox")))]
-            if (term.c_oflag & FFDLY) != 0 {
-                print!(" FFDLY");
-            }
-            println!();
-
-            print!(" - control flags:");
-            #[cfg(not(any(bsd, target_os = "haiku", target_os = "redox")))]
-            if (term.c_cflag & CBAUD) != 0 {
-                print!(" CBAUD");
-            }
-            #[cfg(not(any(
-                bsd,
-                solarish,
-                target_os = "aix",
-                target_os = "haiku",
-                target_os = "redox"
-            )))]
-            if (term.c_cflag & CBAUDEX) != 0 {
-                print!(" CBAUDEX");
-            }
-            if (term.c_cflag & CSIZE) != 0 {
-                print!(" CSIZE");
-            }
-            if (term.c_cflag & CSTOPB) != 0 {
-                print!(" CSTOPB");
-            }
-            if (term.c_cflag & CREAD) != 0 {
-                print!(" CREAD");
-            }
-            if (term.c_cflag & PARENB) != 0 {
-                print!(" PARENB");
-            }
-            if (term.c_cflag & PARODD) != 0 {
-                print!(" PARODD");
-            }
-            if (term.c_cflag & HUPCL) != 0 {
-                print!(" HUPCL");
-            }
-            if (term.c_cflag & CLOCAL) != 0 {
-                print!(" CLOCAL");
-            }
-            #[cfg(not(any(
-                bsd,
-                target_os = "emscripten",
-                target_os = "haiku",
-                target_os = "redox",
-            )))]
-            if (term.c_cflag & CIBAUD) != 0 {
-                print!(" CIBAUD");
-            }
-            #[cfg(not(any(
-                bsd,
-                solarish,
-                target_os = "emscripten",
-                target_os = "haiku",
-                target_os = "redox",
-            )))]
-            if (term.c_cflag & CMSPAR) != 0 {
-                print!(" CMSPAR");
-            }
-            if (term.c_cflag & CRTSCTS) != 0 {
-                print!(" CRTSCTS");
-            }
-            println!();
-
-            print!(" - local flags:");
-            if (term.c_lflag & ISIG) != 0 {
-                print!(" ISIG");
-            }
-            if (term.c_lflag & ICANON) != 0 {
-                print!(" ICANON");
-            }
-            #[cfg(any(linux_raw, all(libc, any(target_arch = "s390x", target_os = "haiku"))))]
-            if (term.c_lflag & XCASE) != 0 {
-                print!(" XCASE");
-            }
-            if (term.c_lflag & ECHO) != 0 {
-                print!(" ECHO");
-            }
-            if (term.c_lflag & ECHOE) != 0 {
-                print!(" ECHOE");
-            }
-            if (term.c_lflag & ECHOK) != 0 {
-                print!(" ECHOK");
-            }
-            if (term.c_lflag & ECHONL) != 0 {
-                print!(" ECHONL");
-            }
-            if (term.c_lflag & ECHOCTL) != 0 {
-                print!(" ECHOCTL");
-            }
-            if (term.c_lflag & ECHOPRT) != 0 {
-                print!(" ECHOPRT");
-            }
-            if (term.c_lflag & ECHOKE) != 0 {
-                print!(" ECHOKE");
-            }
-            if (term.c_lflag & FLUSHO) != 0 {
-                print!(" FLUSHO");
-            }
-            if (term.c_lflag & NOFLSH) != 0 {
-                print!(" NOFLSH");
-            }
-            if (term.c_lflag & TOSTOP) != 0 {
-                print!(" TOSTOP");
-            }
-            if (term.c_lflag & PENDIN) != 0 {
-                print!(" PENDIN");
-            }
-            if (term.c_lflag & IEXTEN) != 0 {
-                print!(" IEXTEN");
-            }
-            println!();
-
+            println!(" - ispeed: {}", term.input_speed());
+            println!(" - ospeed: {}", term.output_speed());
+            println!(" - in flags: {:?}", term.input_modes);
+            println!(" - out flags: {:?}", term.output_modes);
+            println!(" - control flags: {:?}", term.control_modes);
+            println!(" - local flags: {:?}", term.local_modes);
+
+            let cc = &term.special_codes;
             println!(
                 " - keys: INTR={} QUIT={} ERASE={} KILL={} EOF={} TIME={} MIN={} ",
-                key(term.c_cc[VINTR]),
-                key(term.c_cc[VQUIT]),
-                key(term.c_cc[VERASE]),
-                key(term.c_cc[VKILL]),
-                key(term.c_cc[VEOF]),
-                term.c_cc[VTIME],
-                term.c_cc[VMIN]
+                key(cc[SpecialCodeIndex::VINTR]),
+                key(cc[SpecialCodeIndex::VQUIT]),
+                key(cc[SpecialCodeIndex::VERASE]),
+                key(cc[SpecialCodeIndex::VKILL]),
+                key(cc[SpecialCodeIndex::VEOF]),
+                cc[SpecialCodeIndex::VTIME],
+                cc[SpecialCodeIndex::VMIN]
             );
             println!(
                 "         START={} STOP={} SUSP={} EOL={}",
-                key(term.c_cc[VSTART]),
-                key(term.c_cc[VSTOP]),
-                key(term.c_cc[VSUSP]),
-                key(term.c_cc[VEOL]),
+                key(cc[SpecialCodeIndex::VSTART]),
+                key(cc[SpecialCodeIndex::VSTOP]),
+                key(cc[SpecialCodeIndex::VSUSP]),
+                key(cc[SpecialCodeIndex::VEOL]),
             );
             #[cfg(not(target_os = "haiku"))]
             println!(
                 "         REPRINT={} DISCARD={}",
-                key(term.c_cc[VREPRINT]),
-                key(term.c_cc[VDISCARD])
+                key(cc[SpecialCodeIndex::VREPRINT]),
+                key(cc[SpecialCodeIndex::VDISCARD])
             );
             #[cfg(not(target_os = "haiku"))]
             println!(
                 "         WERASE={} VLNEXT={}",
-                key(term.c_cc[VWERASE]),
-                key(term.c_cc[VLNEXT]),
+                key(cc[SpecialCodeIndex::VWERASE]),
+                key(cc[SpecialCodeIndex::VLNEXT]),
             );
-            println!("         EOL2={}", key(term.c_cc[VEOL2]));
+            println!("         EOL2={}", key(cc[SpecialCodeIndex::VEOL2]));
         }
     } else {
         println!(" - is not a tty");
@@ -7,3 +7,199 @@ pub(crate) const PROC_SUPER_MAGIC: u32 = 0x0000_9fa0;
 /// `NFS_SUPER_MAGIC`—The magic number for the NFS filesystem.
 #[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
 pub(crate) const NFS_SUPER_MAGIC: u32 = 0x0000_6969;
+
+/// `SO_PASSPIDFD`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+pub(crate) const SO_PASSPIDFD: c_int = 76;
+/// `SO_PASSPIDFD`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+pub(crate) const SO_PASSPIDFD: c_int = 0x55;
+
+/// `SO_PEERPIDFD`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+pub(crate) const SO_PEERPIDFD: c_int = 77;
+/// `SO_PEERPIDFD`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+pub(crate) const SO_PEERPIDFD: c_int = 0x56;
+
+/// `SCM_PIDFD`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SCM_PIDFD: c_int = 4;
+
+/// `PIDFD_SIGNAL_THREAD`—Not yet in all versions of the `libc` crate.
+#[cfg(target_os = "linux")]
+pub(crate) const PIDFD_SIGNAL_THREAD: c_uint = 1 << 0;
+/// `PIDFD_SIGNAL_THREAD_GROUP`—Not yet in all versions of the `libc` crate.
+#[cfg(target_os = "linux")]
+pub(crate) const PIDFD_SIGNAL_THREAD_GROUP: c_uint = 1 << 1;
+/// `PIDFD_SIGNAL_PROCESS_GROUP`—Not yet in all versions of the `libc` crate.
+#[cfg(target_os = "linux")]
+pub(crate) const PIDFD_SIGNAL_PROCESS_GROUP: c_uint = 1 << 2;
+
+/// `UDP_SEGMENT`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const UDP_SEGMENT: c_int = 103;
+
+/// `UDP_GRO`—Not yet in all versions of the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const UDP_GRO: c_int = 104;
+
+// The `_OLD` and `_NEW` timestamp options aren't in all versions of the
+// `libc` crate, so use the definitions in linux-raw-sys.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SO_TIMESTAMPNS_OLD: c_int = linux_raw_sys::general::SO_TIMESTAMPNS_OLD as _;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SO_TIMESTAMPNS_NEW: c_int = linux_raw_sys::general::SO_TIMESTAMPNS_NEW as _;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SO_TIMESTAMPING_OLD: c_int = linux_raw_sys::general::SO_TIMESTAMPING_OLD as _;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SO_TIMESTAMPING_NEW: c_int = linux_raw_sys::general::SO_TIMESTAMPING_NEW as _;
+
+// The netlink definitions vary between versions of the `libc` crate, so use
+// the definitions in linux-raw-sys.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use linux_raw_sys::netlink::{
+    nlattr, nlmsghdr, sockaddr_nl, NETLINK_AUDIT, NETLINK_CONNECTOR, NETLINK_CRYPTO,
+    NETLINK_DNRTMSG, NETLINK_ECRYPTFS, NETLINK_FIB_LOOKUP, NETLINK_GENERIC, NETLINK_ISCSI,
+    NETLINK_KOBJECT_UEVENT, NETLINK_NETFILTER, NETLINK_NFLOG, NETLINK_RDMA, NETLINK_ROUTE,
+    NETLINK_SCSITRANSPORT, NETLINK_SELINUX, NETLINK_SMC, NETLINK_SOCK_DIAG, NETLINK_USERSOCK,
+    NETLINK_XFRM, NLA_F_NESTED, NLA_F_NET_BYTEORDER, NLMSG_DONE, NLMSG_ERROR, NLMSG_NOOP,
+    NLMSG_OVERRUN, NLM_F_ACK, NLM_F_APPEND, NLM_F_ATOMIC, NLM_F_CREATE, NLM_F_DUMP,
+    NLM_F_DUMP_FILTERED, NLM_F_DUMP_INTR, NLM_F_ECHO, NLM_F_EXCL, NLM_F_MATCH, NLM_F_MULTI,
+    NLM_F_REPLACE, NLM_F_REQUEST, NLM_F_ROOT,
+};
+
+// `SOF_TIMESTAMPING_*` flags for `SO_TIMESTAMPING`—Not yet in all versions of
+// the `libc` crate.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_TX_HARDWARE: u32 = 1 << 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_TX_SOFTWARE: u32 = 1 << 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_RX_HARDWARE: u32 = 1 << 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_RX_SOFTWARE: u32 = 1 << 3;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_SOFTWARE: u32 = 1 << 4;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_SYS_HARDWARE: u32 = 1 << 5;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_RAW_HARDWARE: u32 = 1 << 6;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_ID: u32 = 1 << 7;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_TX_SCHED: u32 = 1 << 8;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_TX_ACK: u32 = 1 << 9;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_CMSG: u32 = 1 << 10;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_TSONLY: u32 = 1 << 11;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_STATS: u32 = 1 << 12;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_PKTINFO: u32 = 1 << 13;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const SOF_TIMESTAMPING_OPT_TX_SWHW: u32 = 1 << 14;
+
+// The `AF_PACKET` definitions from `if_packet.h` vary between versions of
+// the `libc` crate, so define them here.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_HOST: u8 = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_BROADCAST: u8 = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_MULTICAST: u8 = 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_OTHERHOST: u8 = 3;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_OUTGOING: u8 = 4;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_ADD_MEMBERSHIP: c_int = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_DROP_MEMBERSHIP: c_int = 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_RX_RING: c_int = 5;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_VERSION: c_int = 10;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_TX_RING: c_int = 13;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT: c_int = 18;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_MR_MULTICAST: u16 = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_MR_PROMISC: u16 = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_MR_ALLMULTI: u16 = 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_MR_UNICAST: u16 = 3;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_HASH: u32 = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_LB: u32 = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_CPU: u32 = 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_ROLLOVER: u32 = 3;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_RND: u32 = 4;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_QM: u32 = 5;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_FLAG_ROLLOVER: u32 = 0x1000;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_FLAG_UNIQUEID: u32 = 0x2000;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_FLAG_IGNORE_OUTGOING: u32 = 0x4000;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const PACKET_FANOUT_FLAG_DEFRAG: u32 = 0x8000;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TPACKET_V1: c_int = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TPACKET_V2: c_int = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TPACKET_V3: c_int = 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_KERNEL: u32 = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_USER: u32 = 1 << 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_LOSING: u32 = 1 << 2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_VLAN_VALID: u32 = 1 << 4;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_BLK_TMO: u32 = 1 << 5;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_VLAN_TPID_VALID: u32 = 1 << 6;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const TP_STATUS_CSUM_VALID: u32 = 1 << 7;
+
+/// `struct packet_mreq`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct packet_mreq {
+    pub(crate) mr_ifindex: c_int,
+    pub(crate) mr_type: c_ushort,
+    pub(crate) mr_alen: c_ushort,
+    pub(crate) mr_address: [c_uchar; 8],
+}
+
+/// `struct tpacket_req3`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct tpacket_req3 {
+    pub(crate) tp_block_size: c_uint,
+    pub(crate) tp_block_nr: c_uint,
+    pub(crate) tp_frame_size: c_uint,
+    pub(crate) tp_frame_nr: c_uint,
+    pub(crate) tp_retire_blk_tov: c_uint,
+    pub(crate) tp_sizeof_priv: c_uint,
+    pub(crate) tp_feature_req_word: c_uint,
+}
@@ -1,7 +1,7 @@
 #[cfg(not(target_os = "redox"))]
 pub(crate) mod dir;
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub mod inotify;
+pub(crate) mod inotify;
 #[cfg(not(any(solarish, target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 pub(crate) mod makedev;
 #[cfg(not(windows))]
@@ -1808,6 +1808,281 @@ pub(crate) fn unmount(target: &CStr, flags: super::types::UnmountFlags) -> io::R
     unsafe { ret(c::umount2(target.as_ptr(), flags.bits())) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fanotify_init(
+    flags: crate::fs::fanotify::InitFlags,
+    event_flags: crate::fs::fanotify::EventFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_fanotify_init,
+            flags.bits(),
+            event_flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fanotify_mark(
+    fd: BorrowedFd<'_>,
+    flags: crate::fs::fanotify::MarkFlags,
+    mask: crate::fs::fanotify::MaskFlags,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    let path = path.map_or_else(null, CStr::as_ptr);
+
+    // On 32-bit platforms, the 64-bit mask is passed in two registers, in
+    // memory order.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let mask = mask.bits().to_ne_bytes();
+        let (first, second) = mask.split_at(4);
+        syscall_ret(c::syscall(
+            c::SYS_fanotify_mark,
+            borrowed_fd(fd),
+            flags.bits(),
+            u32::from_ne_bytes(first.try_into().unwrap()),
+            u32::from_ne_bytes(second.try_into().unwrap()),
+            borrowed_fd(dirfd),
+            path,
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_fanotify_mark,
+            borrowed_fd(fd),
+            flags.bits(),
+            mask.bits(),
+            borrowed_fd(dirfd),
+            path,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsopen(fs_name: &CStr, flags: crate::fs::FsOpenFlags) -> io::Result<OwnedFd> {
+    unsafe { syscall_ret_owned_fd(c::syscall(c::SYS_fsopen, c_str(fs_name), flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsmount(
+    fs_fd: BorrowedFd<'_>,
+    flags: crate::fs::FsMountFlags,
+    attr_flags: crate::fs::MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_fsmount,
+            borrowed_fd(fs_fd),
+            flags.bits(),
+            attr_flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: crate::fs::FsPickFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_fspick,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn fsconfig(
+    fs_fd: BorrowedFd<'_>,
+    cmd: linux_raw_sys::general::fsconfig_command,
+    key: Option<&CStr>,
+    value: *const c::c_void,
+    aux: c::c_int,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_fsconfig,
+            borrowed_fd(fs_fd),
+            cmd as c::c_uint,
+            key.map_or_else(null, CStr::as_ptr),
+            value,
+            aux,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_flag(fs_fd: BorrowedFd<'_>, key: &CStr) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_FLAG,
+        Some(key),
+        null(),
+        0,
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_string(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    value: &CStr,
+) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_STRING,
+        Some(key),
+        value.as_ptr().cast(),
+        0,
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_binary(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    value: &[u8],
+) -> io::Result<()> {
+    let len = value.len().try_into().map_err(|_| io::Errno::INVAL)?;
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_BINARY,
+        Some(key),
+        value.as_ptr().cast(),
+        len,
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_path(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_PATH,
+        Some(key),
+        path.as_ptr().cast(),
+        borrowed_fd(dirfd),
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_path_empty(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_PATH_EMPTY,
+        Some(key),
+        cstr!("").as_ptr().cast(),
+        borrowed_fd(fd),
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_fd(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_SET_FD,
+        Some(key),
+        null(),
+        borrowed_fd(fd),
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_create(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_CMD_CREATE,
+        None,
+        null(),
+        0,
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_reconfigure(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
+    fsconfig(
+        fs_fd,
+        linux_raw_sys::general::fsconfig_command::FSCONFIG_CMD_RECONFIGURE,
+        None,
+        null(),
+        0,
+    )
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: crate::fs::OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_open_tree,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn move_mount(
+    from_dirfd: BorrowedFd<'_>,
+    from_path: &CStr,
+    to_dirfd: BorrowedFd<'_>,
+    to_path: &CStr,
+    flags: crate::fs::MoveMountFlags,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_move_mount,
+            borrowed_fd(from_dirfd),
+            c_str(from_path),
+            borrowed_fd(to_dirfd),
+            c_str(to_path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: AtFlags,
+    attr: &linux_raw_sys::general::mount_attr,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_mount_setattr,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+            attr as *const linux_raw_sys::general::mount_attr,
+            core::mem::size_of::<linux_raw_sys::general::mount_attr>(),
+        ))
+    }
+}
+
 #[cfg(any(apple, target_os = "android", target_os = "linux"))]
 pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
     let value_ptr = value.as_mut_ptr();
@@ -46,6 +46,10 @@ bitflags! {
         ))]
         const EMPTY_PATH = c::AT_EMPTY_PATH;
 
+        /// `AT_RECURSIVE`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const RECURSIVE = linux_raw_sys::general::AT_RECURSIVE as c::c_int;
+
         /// `AT_RESOLVE_BENEATH`
         #[cfg(target_os = "freebsd")]
         const RESOLVE_BENEATH = c::AT_RESOLVE_BENEATH;
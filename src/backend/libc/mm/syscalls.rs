@@ -16,6 +16,18 @@ use crate::fd::BorrowedFd;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::fd::OwnedFd;
 use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    super::super::conv::{syscall_ret, syscall_ret_usize},
+    crate::process::Pid,
+};
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+use {
+    crate::mm::{MreleaseFlags, ProcessMadviseFlags},
+    crate::process::RemoteIoVec,
+    linux_raw_sys::general::{__NR_process_madvise, __NR_process_mrelease},
+};
 
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -216,3 +228,123 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall_ret_owned_fd(c::syscall(c::SYS_userfaultfd, flags.bits()))
 }
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+pub(crate) fn process_madvise(
+    pidfd: BorrowedFd<'_>,
+    remote: &[RemoteIoVec],
+    advice: Advice,
+    flags: ProcessMadviseFlags,
+) -> io::Result<usize> {
+    // See the comment in `madvise` about `LinuxDontNeed`.
+    let advice = match advice {
+        Advice::LinuxDontNeed => c::MADV_DONTNEED,
+        advice => advice as c::c_int,
+    };
+    unsafe {
+        syscall_ret_usize(c::syscall(
+            __NR_process_madvise as _,
+            borrowed_fd(pidfd),
+            remote.as_ptr().cast::<c::iovec>(),
+            remote.len(),
+            advice,
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+pub(crate) fn process_mrelease(pidfd: BorrowedFd<'_>, flags: MreleaseFlags) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_process_mrelease as _,
+            borrowed_fd(pidfd),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: c::c_uint,
+    nodemask: *const c::c_ulong,
+    maxnode: c::c_ulong,
+    flags: c::c_uint,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_mbind,
+        addr,
+        len,
+        mode,
+        nodemask,
+        maxnode,
+        flags,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn set_mempolicy(
+    mode: c::c_int,
+    nodemask: *const c::c_ulong,
+    maxnode: c::c_ulong,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(c::SYS_set_mempolicy, mode, nodemask, maxnode))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn get_mempolicy(
+    mode: *mut c::c_int,
+    nodemask: *mut c::c_ulong,
+    maxnode: c::c_ulong,
+    addr: *mut c::c_void,
+    flags: c::c_uint,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_get_mempolicy,
+        mode,
+        nodemask,
+        maxnode,
+        addr,
+        flags,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn move_pages(
+    pid: Option<Pid>,
+    count: c::c_ulong,
+    pages: *const *const c::c_void,
+    nodes: *const c::c_int,
+    status: *mut c::c_int,
+    flags: c::c_int,
+) -> io::Result<usize> {
+    syscall_ret_usize(c::syscall(
+        c::SYS_move_pages,
+        Pid::as_raw(pid),
+        count,
+        pages,
+        nodes,
+        status,
+        flags,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn migrate_pages(
+    pid: Option<Pid>,
+    maxnode: c::c_ulong,
+    old_nodes: *const c::c_ulong,
+    new_nodes: *const c::c_ulong,
+) -> io::Result<usize> {
+    syscall_ret_usize(c::syscall(
+        c::SYS_migrate_pages,
+        Pid::as_raw(pid),
+        maxnode,
+        old_nodes,
+        new_nodes,
+    ))
+}
@@ -5,9 +5,13 @@
 
 use super::super::c;
 use super::super::conv::{msg_control_len, msg_iov_len};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::net::write_sockaddr::{encode_sockaddr_ll, encode_sockaddr_nl};
 use super::super::net::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
 
 use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrAny, SocketAddrLink, SocketAddrNetlink};
 use crate::net::{SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 
@@ -71,7 +75,7 @@ pub(crate) fn with_v4_msghdr<R>(
     f({
         let mut h: c::msghdr = unsafe { zeroed() };
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV4>() as _;
+        h.msg_namelen = size_of::<c::sockaddr_in>() as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -92,7 +96,7 @@ pub(crate) fn with_v6_msghdr<R>(
     f({
         let mut h: c::msghdr = unsafe { zeroed() };
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV6>() as _;
+        h.msg_namelen = size_of::<c::sockaddr_in6>() as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -120,3 +124,96 @@ pub(crate) fn with_unix_msghdr<R>(
         h
     })
 }
+
+/// Create a message header intended to send with a netlink address.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn with_netlink_msghdr<R>(
+    addr: &SocketAddrNetlink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_nl(addr);
+
+    f({
+        let mut h: c::msghdr = unsafe { zeroed() };
+        h.msg_name = as_ptr(&encoded) as _;
+        h.msg_namelen = size_of::<c::sockaddr_nl>() as _;
+        h.msg_iov = iov.as_ptr() as _;
+        h.msg_iovlen = msg_iov_len(iov.len());
+        h.msg_control = control.as_control_ptr().cast();
+        h.msg_controllen = msg_control_len(control.control_len());
+        h
+    })
+}
+
+/// Create a message header intended to send with a link-layer address.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn with_link_msghdr<R>(
+    addr: &SocketAddrLink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_ll(addr);
+
+    f({
+        let mut h: c::msghdr = unsafe { zeroed() };
+        h.msg_name = as_ptr(&encoded) as _;
+        h.msg_namelen = size_of::<c::sockaddr_ll>() as _;
+        h.msg_iov = iov.as_ptr() as _;
+        h.msg_iovlen = msg_iov_len(iov.len());
+        h.msg_control = control.as_control_ptr().cast();
+        h.msg_controllen = msg_control_len(control.control_len());
+        h
+    })
+}
+
+/// Create a message header for a message to send with `sendmmsg`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn send_mmsghdr(
+    name: Option<(&MaybeUninit<c::sockaddr_storage>, usize)>,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+) -> c::mmsghdr {
+    let mut h: c::mmsghdr = unsafe { zeroed() };
+    if let Some((name, len)) = name {
+        h.msg_hdr.msg_name = name.as_ptr() as _;
+        h.msg_hdr.msg_namelen = len as _;
+    }
+    h.msg_hdr.msg_iov = iov.as_ptr() as _;
+    h.msg_hdr.msg_iovlen = msg_iov_len(iov.len());
+    h.msg_hdr.msg_control = control.as_control_ptr().cast();
+    h.msg_hdr.msg_controllen = msg_control_len(control.control_len());
+    h
+}
+
+/// Create a message header for a message to receive with `recvmmsg`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recv_mmsghdr(
+    name: &mut MaybeUninit<c::sockaddr_storage>,
+    iov: &mut [io::IoSliceMut<'_>],
+    control: &mut [u8],
+) -> c::mmsghdr {
+    let mut h: c::mmsghdr = unsafe { zeroed() };
+    h.msg_hdr.msg_name = name.as_mut_ptr().cast();
+    h.msg_hdr.msg_namelen = size_of::<c::sockaddr_storage>() as c::socklen_t;
+    h.msg_hdr.msg_iov = iov.as_mut_ptr().cast();
+    h.msg_hdr.msg_iovlen = msg_iov_len(iov.len());
+    h.msg_hdr.msg_control = control.as_mut_ptr().cast();
+    h.msg_hdr.msg_controllen = msg_control_len(control.len());
+    h
+}
+
+/// Read the source address of a message received with `recvmmsg`.
+///
+/// # Safety
+///
+/// `raw` must have been filled in by `recvmmsg`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn recv_mmsghdr_address(raw: &c::mmsghdr) -> Option<SocketAddrAny> {
+    super::read_sockaddr::maybe_read_sockaddr_os(
+        raw.msg_hdr.msg_name as _,
+        raw.msg_hdr.msg_namelen as _,
+    )
+}
@@ -6,6 +6,8 @@ use super::ext::{in6_addr_s6_addr, in_addr_s_addr, sockaddr_in6_sin6_scope_id};
 use crate::ffi::CStr;
 use crate::io;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 #[cfg(not(windows))]
 use alloc::vec::Vec;
 use core::mem::size_of;
@@ -122,6 +124,22 @@ pub(crate) unsafe fn read_sockaddr(
                 ))
             }
         }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_PACKET => {
+            let decode = read_sockaddr_ll(storage, len).ok_or(io::Errno::INVAL)?;
+            Ok(SocketAddrAny::Link(decode))
+        }
         _ => Err(io::Errno::INVAL),
     }
 }
@@ -205,6 +223,53 @@ unsafe fn inner_read_sockaddr_os(
                 )
             }
         }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_PACKET => SocketAddrAny::Link(read_sockaddr_ll(storage, len).unwrap()),
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// Decode a `struct sockaddr_ll`, which the kernel may truncate to the length
+/// of the physical-layer address.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn read_sockaddr_ll(
+    storage: *const c::sockaddr_storage,
+    len: usize,
+) -> Option<SocketAddrLink> {
+    let offsetof_sll_addr = {
+        let z = c::sockaddr_ll {
+            sll_family: 0,
+            sll_protocol: 0,
+            sll_ifindex: 0,
+            sll_hatype: 0,
+            sll_pkttype: 0,
+            sll_halen: 0,
+            sll_addr: [0; 8],
+        };
+        (crate::utils::as_ptr(&z.sll_addr) as usize) - (crate::utils::as_ptr(&z) as usize)
+    };
+    if len < offsetof_sll_addr {
+        return None;
+    }
+    let mut decode = core::mem::zeroed::<c::sockaddr_ll>();
+    core::ptr::copy_nonoverlapping(
+        storage.cast::<u8>(),
+        crate::utils::as_mut_ptr(&mut decode).cast::<u8>(),
+        len.min(size_of::<c::sockaddr_ll>()),
+    );
+    let halen = (decode.sll_halen as usize).min(len - offsetof_sll_addr);
+    Some(SocketAddrLink::from_raw(
+        decode.sll_protocol,
+        decode.sll_ifindex,
+        decode.sll_hatype,
+        decode.sll_pkttype,
+        halen as u8,
+        decode.sll_addr,
+    ))
+}
@@ -25,6 +25,13 @@ use core::convert::TryInto;
 use core::mem::{size_of, MaybeUninit};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use core::ptr::null_mut;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    super::super::conv::ret_c_int,
+    super::write_sockaddr::{encode_sockaddr_ll, encode_sockaddr_nl},
+    crate::backend::time::types::Timespec,
+    crate::net::{MMsgHdr, RecvMMsgHdr, SocketAddrLink, SocketAddrNetlink},
+};
 #[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
 use {
     crate::io::{IoSlice, IoSliceMut},
@@ -101,7 +108,7 @@ pub(crate) fn sendto_v4(
             send_recv_len(buf.len()),
             flags.bits(),
             as_ptr(&encode_sockaddr_v4(addr)).cast::<c::sockaddr>(),
-            size_of::<SocketAddrV4>() as _,
+            size_of::<c::sockaddr_in>() as _,
         ))
     }
 }
@@ -120,7 +127,7 @@ pub(crate) fn sendto_v6(
             send_recv_len(buf.len()),
             flags.bits(),
             as_ptr(&encode_sockaddr_v6(addr)).cast::<c::sockaddr>(),
-            size_of::<SocketAddrV6>() as _,
+            size_of::<c::sockaddr_in6>() as _,
         ))
     }
 }
@@ -144,6 +151,44 @@ pub(crate) fn sendto_unix(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            flags.bits(),
+            as_ptr(&encode_sockaddr_nl(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_nl>() as _,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            flags.bits(),
+            as_ptr(&encode_sockaddr_ll(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_ll>() as _,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn socket(
     domain: AddressFamily,
@@ -208,6 +253,28 @@ pub(crate) fn bind_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Re
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn bind_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_nl(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn bind_link(sockfd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_ll(addr)).cast(),
+            size_of::<c::sockaddr_ll>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_v4(sockfd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     unsafe {
@@ -241,6 +308,28 @@ pub(crate) fn connect_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io:
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn connect_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_nl(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn connect_link(sockfd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_ll(addr)).cast(),
+            size_of::<c::sockaddr_ll>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn listen(sockfd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
     unsafe { ret(c::listen(borrowed_fd(sockfd), backlog)) }
@@ -332,6 +421,90 @@ pub(crate) fn sendmsg_unix(
     })
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    super::msghdr::with_netlink_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(borrowed_fd(sockfd), &msghdr, msg_flags.bits()))
+    })
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendmsg_link(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    super::msghdr::with_link_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(borrowed_fd(sockfd), &msghdr, msg_flags.bits()))
+    })
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [MMsgHdr<'_>],
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    // `MMsgHdr` is a transparent wrapper around `c::mmsghdr`.
+    let raw = msgs.as_mut_ptr().cast::<c::mmsghdr>();
+    let vlen: c::c_uint = msgs.len().try_into().unwrap_or(c::c_uint::MAX);
+
+    let sent = unsafe {
+        ret_c_int(c::sendmmsg(
+            borrowed_fd(sockfd),
+            raw,
+            vlen,
+            msg_flags.bits() as _,
+        ))?
+    } as usize;
+
+    Ok(sent)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    msg_flags: RecvFlags,
+    timeout: Option<Timespec>,
+) -> io::Result<usize> {
+    // `RecvMMsgHdr` is a transparent wrapper around `c::mmsghdr`.
+    let raw = msgs.as_mut_ptr().cast::<c::mmsghdr>();
+    let vlen: c::c_uint = msgs.len().try_into().unwrap_or(c::c_uint::MAX);
+
+    // `Timespec` may differ from the libc `timespec` on 32-bit platforms, so
+    // convert it field by field.
+    let mut timeout = match timeout {
+        Some(timeout) => {
+            let mut libc_timeout: c::timespec = unsafe { core::mem::zeroed() };
+            libc_timeout.tv_sec = timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?;
+            libc_timeout.tv_nsec = timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?;
+            Some(libc_timeout)
+        }
+        None => None,
+    };
+
+    let received = unsafe {
+        ret_c_int(c::recvmmsg(
+            borrowed_fd(sockfd),
+            raw,
+            vlen,
+            msg_flags.bits() as _,
+            timeout.as_mut().map_or(null_mut(), |timeout| timeout),
+        ))?
+    } as usize;
+
+    Ok(received)
+}
+
 #[cfg(not(any(
     apple,
     windows,
@@ -480,6 +653,14 @@ pub(crate) mod sockopt {
     use core::time::Duration;
     #[cfg(windows)]
     use windows_sys::Win32::Foundation::BOOL;
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use {
+        crate::fd::{FromRawFd, OwnedFd},
+        crate::net::packet::{
+            PacketFanoutFlags, PacketFanoutMode, PacketMembership, PacketVersion, TpacketReq3,
+        },
+        crate::net::{sockopt::TimestampingFlags, UCred},
+    };
 
     // TODO: With Rust 1.53 we can use `Duration::ZERO` instead.
     const DURATION_ZERO: Duration = Duration::from_secs(0);
@@ -617,6 +798,94 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERCRED).map(UCred::from_raw)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_passpidfd(fd: BorrowedFd<'_>, passpidfd: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_PASSPIDFD,
+            from_bool(passpidfd),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_passpidfd(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSPIDFD).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_peerpidfd(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+        let pidfd: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERPIDFD)?;
+        Ok(unsafe { OwnedFd::from_raw_fd(pidfd) })
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestampns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        match setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_TIMESTAMPNS_NEW,
+            from_bool(value),
+        ) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPNS_NEW != c::SO_TIMESTAMPNS_OLD => {
+                setsockopt(
+                    fd,
+                    c::SOL_SOCKET as _,
+                    c::SO_TIMESTAMPNS_OLD,
+                    from_bool(value),
+                )
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_timestampns(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        match getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS_NEW) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPNS_NEW != c::SO_TIMESTAMPNS_OLD => {
+                getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS_OLD).map(to_bool)
+            }
+            otherwise => otherwise.map(to_bool),
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestamping(
+        fd: BorrowedFd<'_>,
+        flags: TimestampingFlags,
+    ) -> io::Result<()> {
+        match setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_NEW, flags.bits()) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPING_NEW != c::SO_TIMESTAMPING_OLD => {
+                setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_OLD, flags.bits())
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_timestamping(fd: BorrowedFd<'_>) -> io::Result<TimestampingFlags> {
+        let bits = match getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_NEW) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPING_NEW != c::SO_TIMESTAMPING_OLD => {
+                getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_OLD)?
+            }
+            otherwise => otherwise?,
+        };
+        Ok(TimestampingFlags::from_bits_truncate(bits))
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -785,6 +1054,42 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_recvttl(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTTL, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_recvttl(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTTL).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_recvtos(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTOS, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_recvtos(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTOS).map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -795,6 +1100,57 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVPKTINFO,
+            from_bool(value),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVPKTINFO).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ipv6_recvhoplimit(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVHOPLIMIT,
+            from_bool(value),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ipv6_recvhoplimit(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVHOPLIMIT).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ipv6_recvtclass(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVTCLASS,
+            from_bool(value),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ipv6_recvtclass(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVTCLASS).map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -933,6 +1289,135 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_udp_gro(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_UDP as _, c::UDP_GRO, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_udp_gro(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_UDP as _, c::UDP_GRO).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_udp_segment(fd: BorrowedFd<'_>, value: u16) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_UDP as _,
+            c::UDP_SEGMENT,
+            c::c_int::from(value),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_udp_segment(fd: BorrowedFd<'_>) -> io::Result<u16> {
+        getsockopt(fd, c::IPPROTO_UDP as _, c::UDP_SEGMENT).map(|value: c::c_int| value as u16)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_add_membership(
+        fd: BorrowedFd<'_>,
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<()> {
+        let mreq = to_packet_mreq(ifindex, membership, address)?;
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_ADD_MEMBERSHIP, mreq)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_drop_membership(
+        fd: BorrowedFd<'_>,
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<()> {
+        let mreq = to_packet_mreq(ifindex, membership, address)?;
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_DROP_MEMBERSHIP, mreq)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_version(fd: BorrowedFd<'_>, version: PacketVersion) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_VERSION, version as c::c_int)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_packet_version(fd: BorrowedFd<'_>) -> io::Result<PacketVersion> {
+        let version: c::c_int = getsockopt(fd, c::SOL_PACKET, c::PACKET_VERSION)?;
+        match version {
+            v if v == PacketVersion::V1 as c::c_int => Ok(PacketVersion::V1),
+            v if v == PacketVersion::V2 as c::c_int => Ok(PacketVersion::V2),
+            v if v == PacketVersion::V3 as c::c_int => Ok(PacketVersion::V3),
+            _ => Err(io::Errno::INVAL),
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_fanout(
+        fd: BorrowedFd<'_>,
+        group_id: u16,
+        mode: PacketFanoutMode,
+        flags: PacketFanoutFlags,
+    ) -> io::Result<()> {
+        let value = u32::from(group_id) | ((mode as u32 | flags.bits()) << 16);
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_FANOUT, value)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_rx_ring(fd: BorrowedFd<'_>, req: &TpacketReq3) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_RX_RING, to_tpacket_req3(req))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_packet_tx_ring(fd: BorrowedFd<'_>, req: &TpacketReq3) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_TX_RING, to_tpacket_req3(req))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn to_packet_mreq(
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<c::packet_mreq> {
+        let mut mr_address = [0; 8];
+        mr_address
+            .get_mut(..address.len())
+            .ok_or(io::Errno::INVAL)?
+            .copy_from_slice(address);
+        Ok(c::packet_mreq {
+            mr_ifindex: ifindex as c::c_int,
+            mr_type: membership as u16,
+            mr_alen: address.len() as u16,
+            mr_address,
+        })
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn to_tpacket_req3(req: &TpacketReq3) -> c::tpacket_req3 {
+        c::tpacket_req3 {
+            tp_block_size: req.block_size,
+            tp_block_nr: req.block_nr,
+            tp_frame_size: req.frame_size,
+            tp_frame_nr: req.frame_nr,
+            tp_retire_blk_tov: req.retire_blk_tov,
+            tp_sizeof_priv: req.sizeof_priv,
+            tp_feature_req_word: req.feature_req_word,
+        }
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
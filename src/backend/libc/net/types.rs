@@ -498,3 +498,234 @@ pub enum Timeout {
     /// `SO_SNDTIMEO`—Timeout for sending.
     Send = c::SO_SNDTIMEO,
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `SOF_TIMESTAMPING_*` constants for use with
+    /// [`set_socket_timestamping`].
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    pub struct TimestampingFlags: u32 {
+        /// `SOF_TIMESTAMPING_TX_HARDWARE`
+        const TX_HARDWARE = c::SOF_TIMESTAMPING_TX_HARDWARE;
+
+        /// `SOF_TIMESTAMPING_TX_SOFTWARE`
+        const TX_SOFTWARE = c::SOF_TIMESTAMPING_TX_SOFTWARE;
+
+        /// `SOF_TIMESTAMPING_RX_HARDWARE`
+        const RX_HARDWARE = c::SOF_TIMESTAMPING_RX_HARDWARE;
+
+        /// `SOF_TIMESTAMPING_RX_SOFTWARE`
+        const RX_SOFTWARE = c::SOF_TIMESTAMPING_RX_SOFTWARE;
+
+        /// `SOF_TIMESTAMPING_SOFTWARE`
+        const SOFTWARE = c::SOF_TIMESTAMPING_SOFTWARE;
+
+        /// `SOF_TIMESTAMPING_SYS_HARDWARE`
+        const SYS_HARDWARE = c::SOF_TIMESTAMPING_SYS_HARDWARE;
+
+        /// `SOF_TIMESTAMPING_RAW_HARDWARE`
+        const RAW_HARDWARE = c::SOF_TIMESTAMPING_RAW_HARDWARE;
+
+        /// `SOF_TIMESTAMPING_OPT_ID`
+        const OPT_ID = c::SOF_TIMESTAMPING_OPT_ID;
+
+        /// `SOF_TIMESTAMPING_TX_SCHED`
+        const TX_SCHED = c::SOF_TIMESTAMPING_TX_SCHED;
+
+        /// `SOF_TIMESTAMPING_TX_ACK`
+        const TX_ACK = c::SOF_TIMESTAMPING_TX_ACK;
+
+        /// `SOF_TIMESTAMPING_OPT_CMSG`
+        const OPT_CMSG = c::SOF_TIMESTAMPING_OPT_CMSG;
+
+        /// `SOF_TIMESTAMPING_OPT_TSONLY`
+        const OPT_TSONLY = c::SOF_TIMESTAMPING_OPT_TSONLY;
+
+        /// `SOF_TIMESTAMPING_OPT_STATS`
+        const OPT_STATS = c::SOF_TIMESTAMPING_OPT_STATS;
+
+        /// `SOF_TIMESTAMPING_OPT_PKTINFO`
+        const OPT_PKTINFO = c::SOF_TIMESTAMPING_OPT_PKTINFO;
+
+        /// `SOF_TIMESTAMPING_OPT_TX_SWHW`
+        const OPT_TX_SWHW = c::SOF_TIMESTAMPING_OPT_TX_SWHW;
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `NLM_F_*` constants for the flags field of a netlink message header.
+    ///
+    /// Some of these have the same value, as their meaning depends on the
+    /// kind of request.
+    pub struct NetlinkFlags: u16 {
+        /// `NLM_F_REQUEST`
+        const REQUEST = c::NLM_F_REQUEST as u16;
+
+        /// `NLM_F_MULTI`
+        const MULTI = c::NLM_F_MULTI as u16;
+
+        /// `NLM_F_ACK`
+        const ACK = c::NLM_F_ACK as u16;
+
+        /// `NLM_F_ECHO`
+        const ECHO = c::NLM_F_ECHO as u16;
+
+        /// `NLM_F_DUMP_INTR`
+        const DUMP_INTR = c::NLM_F_DUMP_INTR as u16;
+
+        /// `NLM_F_DUMP_FILTERED`
+        const DUMP_FILTERED = c::NLM_F_DUMP_FILTERED as u16;
+
+        /// `NLM_F_ROOT`
+        const ROOT = c::NLM_F_ROOT as u16;
+
+        /// `NLM_F_MATCH`
+        const MATCH = c::NLM_F_MATCH as u16;
+
+        /// `NLM_F_ATOMIC`
+        const ATOMIC = c::NLM_F_ATOMIC as u16;
+
+        /// `NLM_F_DUMP`
+        const DUMP = c::NLM_F_DUMP as u16;
+
+        /// `NLM_F_REPLACE`
+        const REPLACE = c::NLM_F_REPLACE as u16;
+
+        /// `NLM_F_EXCL`
+        const EXCL = c::NLM_F_EXCL as u16;
+
+        /// `NLM_F_CREATE`
+        const CREATE = c::NLM_F_CREATE as u16;
+
+        /// `NLM_F_APPEND`
+        const APPEND = c::NLM_F_APPEND as u16;
+    }
+}
+
+/// `PACKET_*` constants for the packet type of a [`SocketAddrLink`].
+///
+/// [`SocketAddrLink`]: crate::net::SocketAddrLink
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct PacketType(pub(crate) u8);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[rustfmt::skip]
+impl PacketType {
+    /// `PACKET_HOST`—Addressed to the local host.
+    pub const HOST: Self = Self(c::PACKET_HOST);
+    /// `PACKET_BROADCAST`—A physical-layer broadcast.
+    pub const BROADCAST: Self = Self(c::PACKET_BROADCAST);
+    /// `PACKET_MULTICAST`—A physical-layer multicast.
+    pub const MULTICAST: Self = Self(c::PACKET_MULTICAST);
+    /// `PACKET_OTHERHOST`—Addressed to another host, and seen in
+    /// promiscuous mode.
+    pub const OTHERHOST: Self = Self(c::PACKET_OTHERHOST);
+    /// `PACKET_OUTGOING`—Sent by the local host, and looped back.
+    pub const OUTGOING: Self = Self(c::PACKET_OUTGOING);
+
+    /// Constructs a `PacketType` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u8) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `PacketType`.
+    #[inline]
+    pub const fn as_raw(self) -> u8 {
+        self.0
+    }
+}
+
+/// `PACKET_MR_*` constants for use with [`set_packet_add_membership`] and
+/// [`set_packet_drop_membership`].
+///
+/// [`set_packet_add_membership`]: crate::net::sockopt::set_packet_add_membership
+/// [`set_packet_drop_membership`]: crate::net::sockopt::set_packet_drop_membership
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u16)]
+pub enum PacketMembership {
+    /// `PACKET_MR_MULTICAST`—Receive packets for a multicast address.
+    Multicast = c::PACKET_MR_MULTICAST,
+
+    /// `PACKET_MR_PROMISC`—Put the interface in promiscuous mode.
+    Promisc = c::PACKET_MR_PROMISC,
+
+    /// `PACKET_MR_ALLMULTI`—Receive all multicast packets.
+    AllMulti = c::PACKET_MR_ALLMULTI,
+
+    /// `PACKET_MR_UNICAST`—Receive packets for a unicast address.
+    Unicast = c::PACKET_MR_UNICAST,
+}
+
+/// `TPACKET_V*` constants for use with [`set_packet_version`] and
+/// [`get_packet_version`].
+///
+/// [`set_packet_version`]: crate::net::sockopt::set_packet_version
+/// [`get_packet_version`]: crate::net::sockopt::get_packet_version
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PacketVersion {
+    /// `TPACKET_V1`
+    V1 = c::TPACKET_V1,
+
+    /// `TPACKET_V2`
+    V2 = c::TPACKET_V2,
+
+    /// `TPACKET_V3`—Variable-size frames in blocks, for use with
+    /// [`PacketRxRing`].
+    ///
+    /// [`PacketRxRing`]: crate::net::packet::PacketRxRing
+    V3 = c::TPACKET_V3,
+}
+
+/// `PACKET_FANOUT_*` modes for use with [`set_packet_fanout`].
+///
+/// [`set_packet_fanout`]: crate::net::sockopt::set_packet_fanout
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum PacketFanoutMode {
+    /// `PACKET_FANOUT_HASH`—Distribute packets by flow hash.
+    Hash = c::PACKET_FANOUT_HASH,
+
+    /// `PACKET_FANOUT_LB`—Distribute packets round-robin.
+    LoadBalance = c::PACKET_FANOUT_LB,
+
+    /// `PACKET_FANOUT_CPU`—Distribute packets by the receiving CPU.
+    Cpu = c::PACKET_FANOUT_CPU,
+
+    /// `PACKET_FANOUT_ROLLOVER`—Fill one socket before moving to the next.
+    Rollover = c::PACKET_FANOUT_ROLLOVER,
+
+    /// `PACKET_FANOUT_RND`—Distribute packets randomly.
+    Random = c::PACKET_FANOUT_RND,
+
+    /// `PACKET_FANOUT_QM`—Distribute packets by the receive queue.
+    QueueMapping = c::PACKET_FANOUT_QM,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `PACKET_FANOUT_FLAG_*` constants for use with [`set_packet_fanout`].
+    ///
+    /// [`set_packet_fanout`]: crate::net::sockopt::set_packet_fanout
+    pub struct PacketFanoutFlags: u32 {
+        /// `PACKET_FANOUT_FLAG_ROLLOVER`
+        const ROLLOVER = c::PACKET_FANOUT_FLAG_ROLLOVER;
+
+        /// `PACKET_FANOUT_FLAG_UNIQUEID`
+        const UNIQUEID = c::PACKET_FANOUT_FLAG_UNIQUEID;
+
+        /// `PACKET_FANOUT_FLAG_IGNORE_OUTGOING`
+        const IGNORE_OUTGOING = c::PACKET_FANOUT_FLAG_IGNORE_OUTGOING;
+
+        /// `PACKET_FANOUT_FLAG_DEFRAG`
+        const DEFRAG = c::PACKET_FANOUT_FLAG_DEFRAG;
+    }
+}
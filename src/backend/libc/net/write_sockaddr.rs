@@ -7,6 +7,8 @@ use super::addr::SocketAddrStorage;
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new, sockaddr_in6_new};
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 use core::mem::size_of;
 
 pub(crate) unsafe fn write_sockaddr(
@@ -18,6 +20,10 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::V6(v6) => write_sockaddr_v6(v6, storage),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(nl) => write_sockaddr_nl(nl, storage),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(ll) => write_sockaddr_ll(ll, storage),
     }
 }
 
@@ -76,3 +82,41 @@ unsafe fn write_sockaddr_unix(unix: &SocketAddrUnix, storage: *mut SocketAddrSto
     core::ptr::write(storage.cast(), unix.unix);
     unix.len()
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn encode_sockaddr_nl(nl: &SocketAddrNetlink) -> c::sockaddr_nl {
+    c::sockaddr_nl {
+        nl_family: c::AF_NETLINK as _,
+        nl_pad: 0,
+        nl_pid: nl.pid(),
+        nl_groups: nl.groups(),
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn write_sockaddr_nl(nl: &SocketAddrNetlink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_nl(nl);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn encode_sockaddr_ll(ll: &SocketAddrLink) -> c::sockaddr_ll {
+    let (sll_halen, sll_addr) = ll.raw_addr();
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: ll.raw_protocol(),
+        sll_ifindex: ll.ifindex() as _,
+        sll_hatype: ll.hatype(),
+        sll_pkttype: ll.pkttype().as_raw(),
+        sll_halen,
+        sll_addr,
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn write_sockaddr_ll(ll: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_ll(ll);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
@@ -3,11 +3,13 @@
 use super::super::c;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use super::super::conv::borrowed_fd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::syscall_ret_owned_fd;
 use super::super::conv::{c_str, ret, ret_c_int, ret_discarded_char_ptr};
 #[cfg(not(target_os = "wasi"))]
 use super::super::conv::{ret_infallible, ret_pid_t, ret_usize};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use super::super::conv::{syscall_ret, syscall_ret_u32};
+use super::super::conv::{syscall_ret, syscall_ret_u32, syscall_ret_usize};
 #[cfg(any(
     target_os = "android",
     target_os = "dragonfly",
@@ -15,10 +17,12 @@ use super::super::conv::{syscall_ret, syscall_ret_u32};
     target_os = "linux",
 ))]
 use super::types::RawCpuSet;
+#[cfg(target_os = "linux")]
+use crate::fd::AsRawFd;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use crate::fd::BorrowedFd;
-#[cfg(target_os = "linux")]
-use crate::fd::{AsRawFd, OwnedFd};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fd::OwnedFd;
 use crate::ffi::CStr;
 #[cfg(feature = "fs")]
 use crate::fs::Mode;
@@ -27,13 +31,19 @@ use crate::io;
 use crate::process::Sysinfo;
 #[cfg(not(any(target_os = "wasi", target_os = "redox", target_os = "openbsd")))]
 use crate::process::{WaitId, WaitidOptions, WaitidStatus};
-use core::mem::MaybeUninit;
 #[cfg(target_os = "linux")]
-use {super::super::conv::syscall_ret_owned_fd, crate::process::PidfdFlags};
+use crate::{
+    fd::RawFd,
+    process::{PidfdFlags, PidfdGetfdFlags, PidfdSiginfo, PidfdSignalFlags},
+};
+use core::mem::MaybeUninit;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use {
     super::super::offset::libc_prlimit,
-    crate::process::{Cpuid, MembarrierCommand, MembarrierQuery},
+    crate::io::{IoSlice, IoSliceMut},
+    crate::process::{
+        Cpuid, MembarrierCommand, MembarrierQuery, RemoteIoVec, SigSet, SignalfdFlags,
+    },
 };
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 use {
@@ -551,7 +561,7 @@ pub(crate) fn setsid() -> io::Result<Pid> {
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
-    unsafe { ret(c::kill(pid.as_raw_nonzero().get(), sig as i32)) }
+    unsafe { ret(c::kill(pid.as_raw_nonzero().get(), sig.as_raw())) }
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -560,7 +570,7 @@ pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe {
         ret(c::kill(
             pid.as_raw_nonzero().get().wrapping_neg(),
-            sig as i32,
+            sig.as_raw(),
         ))
     }
 }
@@ -568,7 +578,7 @@ pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn kill_current_process_group(sig: Signal) -> io::Result<()> {
-    unsafe { ret(c::kill(0, sig as i32)) }
+    unsafe { ret(c::kill(0, sig.as_raw())) }
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -601,6 +611,121 @@ pub(crate) unsafe fn prctl(
     ret_c_int(c::prctl(option, arg2, arg3, arg4, arg5))
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    unsafe {
+        syscall_ret_usize(c::syscall(
+            c::SYS_process_vm_readv,
+            pid.as_raw_nonzero().get(),
+            local.as_mut_ptr().cast::<c::iovec>(),
+            local.len() as c::c_ulong,
+            remote.as_ptr().cast::<c::iovec>(),
+            remote.len() as c::c_ulong,
+            0 as c::c_ulong,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    syscall_ret_usize(c::syscall(
+        c::SYS_process_vm_writev,
+        pid.as_raw_nonzero().get(),
+        local.as_ptr().cast::<c::iovec>(),
+        local.len() as c::c_ulong,
+        remote.as_ptr().cast::<c::iovec>(),
+        remote.len() as c::c_ulong,
+        0 as c::c_ulong,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<usize> {
+    // Use the raw system call, which stores the result of `PTRACE_PEEK*` at
+    // `data`, rather than libc's `ptrace`, which returns it.
+    syscall_ret_usize(c::syscall(
+        c::SYS_ptrace,
+        request,
+        Pid::as_raw(pid),
+        addr,
+        data,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn seccomp(
+    operation: c::c_uint,
+    flags: c::c_uint,
+    args: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    ret_c_int(c::syscall(c::SYS_seccomp, operation, flags, args) as c::c_int)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn ioctl_seccomp(
+    fd: BorrowedFd<'_>,
+    request: c::c_uint,
+    arg: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    ret_c_int(c::ioctl(borrowed_fd(fd), request as _, arg))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn landlock_create_ruleset(
+    attr: *const c::c_void,
+    size: usize,
+    flags: c::c_uint,
+) -> io::Result<c::c_int> {
+    ret_c_int(c::syscall(c::SYS_landlock_create_ruleset, attr, size, flags) as c::c_int)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn landlock_add_rule(
+    ruleset: BorrowedFd<'_>,
+    rule_type: c::c_uint,
+    rule_attr: *const c::c_void,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_landlock_add_rule,
+        borrowed_fd(ruleset),
+        rule_type,
+        rule_attr,
+        0 as c::c_uint,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn landlock_restrict_self(ruleset: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_landlock_restrict_self,
+            borrowed_fd(ruleset),
+            0 as c::c_uint,
+        ))
+    }
+}
+
 #[cfg(freebsdlike)]
 #[inline]
 pub(crate) unsafe fn procctl(
@@ -623,6 +748,82 @@ pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn clone3(args: &linux_raw_sys::general::clone_args) -> io::Result<Option<Pid>> {
+    let pid = syscall_ret_u32(c::syscall(
+        c::SYS_clone3,
+        args as *const linux_raw_sys::general::clone_args,
+        core::mem::size_of::<linux_raw_sys::general::clone_args>(),
+    ))?;
+    Ok(Pid::from_raw(pid as RawPid))
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn pidfd_send_signal(
+    pidfd: BorrowedFd<'_>,
+    sig: Signal,
+    info: Option<&PidfdSiginfo>,
+    flags: PidfdSignalFlags,
+) -> io::Result<()> {
+    let info = info.map_or(core::ptr::null(), |info| info as *const PidfdSiginfo);
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_pidfd_send_signal,
+            borrowed_fd(pidfd),
+            sig.as_raw(),
+            info,
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn pidfd_getfd(
+    pidfd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_pidfd_getfd,
+            borrowed_fd(pidfd),
+            targetfd,
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_signalfd4,
+            -1 as c::c_int,
+            mask as *const SigSet,
+            core::mem::size_of::<SigSet>(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn signalfd_set_mask(
+    fd: BorrowedFd<'_>,
+    mask: &SigSet,
+    flags: SignalfdFlags,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret_u32(c::syscall(
+            c::SYS_signalfd4,
+            borrowed_fd(fd),
+            mask as *const SigSet,
+            core::mem::size_of::<SigSet>(),
+            flags.bits(),
+        ))
+        .map(drop)
+    }
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
     let len = buf.len().try_into().map_err(|_| io::Errno::NOMEM)?;
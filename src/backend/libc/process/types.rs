@@ -4,6 +4,19 @@ use super::super::c;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub type Sysinfo = c::sysinfo;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags::bitflags! {
+    /// `SFD_*` flags for use with [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::process::signalfd
+    pub struct SignalfdFlags: c::c_int {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = c::SFD_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = c::SFD_NONBLOCK;
+    }
+}
+
 /// A command for use with [`membarrier`] and [`membarrier_cpu`].
 ///
 /// For `MEMBARRIER_CMD_QUERY`, see [`membarrier_query`].
@@ -110,46 +123,54 @@ impl Resource {
 /// A signal number for use with [`kill_process`], [`kill_process_group`],
 /// and [`kill_current_process_group`].
 ///
+/// This is an open set: in addition to the named signals, it can hold any
+/// valid signal number, including the real-time signals between
+/// [`Signal::rt_min`] and [`Signal::rt_max`] on Linux.
+///
 /// [`kill_process`]: crate::process::kill_process
 /// [`kill_process_group`]: crate::process::kill_process_group
 /// [`kill_current_process_group`]: crate::process::kill_current_process_group
 #[cfg(not(target_os = "wasi"))]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(i32)]
-pub enum Signal {
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Signal(c::c_int);
+
+#[cfg(not(target_os = "wasi"))]
+#[allow(non_upper_case_globals)]
+impl Signal {
     /// `SIGHUP`
-    Hup = c::SIGHUP,
+    pub const Hup: Self = Self(c::SIGHUP as _);
     /// `SIGINT`
-    Int = c::SIGINT,
+    pub const Int: Self = Self(c::SIGINT as _);
     /// `SIGQUIT`
-    Quit = c::SIGQUIT,
+    pub const Quit: Self = Self(c::SIGQUIT as _);
     /// `SIGILL`
-    Ill = c::SIGILL,
+    pub const Ill: Self = Self(c::SIGILL as _);
     /// `SIGTRAP`
-    Trap = c::SIGTRAP,
+    pub const Trap: Self = Self(c::SIGTRAP as _);
     /// `SIGABRT`, aka `SIGIOT`
     #[doc(alias = "Iot")]
     #[doc(alias = "Abrt")]
-    Abort = c::SIGABRT,
+    pub const Abort: Self = Self(c::SIGABRT as _);
     /// `SIGBUS`
-    Bus = c::SIGBUS,
+    pub const Bus: Self = Self(c::SIGBUS as _);
     /// `SIGFPE`
-    Fpe = c::SIGFPE,
+    pub const Fpe: Self = Self(c::SIGFPE as _);
     /// `SIGKILL`
-    Kill = c::SIGKILL,
+    pub const Kill: Self = Self(c::SIGKILL as _);
     /// `SIGUSR1`
-    Usr1 = c::SIGUSR1,
+    pub const Usr1: Self = Self(c::SIGUSR1 as _);
     /// `SIGSEGV`
-    Segv = c::SIGSEGV,
+    pub const Segv: Self = Self(c::SIGSEGV as _);
     /// `SIGUSR2`
-    Usr2 = c::SIGUSR2,
+    pub const Usr2: Self = Self(c::SIGUSR2 as _);
     /// `SIGPIPE`
-    Pipe = c::SIGPIPE,
+    pub const Pipe: Self = Self(c::SIGPIPE as _);
     /// `SIGALRM`
     #[doc(alias = "Alrm")]
-    Alarm = c::SIGALRM,
+    pub const Alarm: Self = Self(c::SIGALRM as _);
     /// `SIGTERM`
-    Term = c::SIGTERM,
+    pub const Term: Self = Self(c::SIGTERM as _);
     /// `SIGSTKFLT`
     #[cfg(not(any(
         bsd,
@@ -166,57 +187,57 @@ pub enum Signal {
             ),
         )
     )))]
-    Stkflt = c::SIGSTKFLT,
+    pub const Stkflt: Self = Self(c::SIGSTKFLT as _);
     /// `SIGCHLD`
     #[doc(alias = "Chld")]
-    Child = c::SIGCHLD,
+    pub const Child: Self = Self(c::SIGCHLD as _);
     /// `SIGCONT`
-    Cont = c::SIGCONT,
+    pub const Cont: Self = Self(c::SIGCONT as _);
     /// `SIGSTOP`
-    Stop = c::SIGSTOP,
+    pub const Stop: Self = Self(c::SIGSTOP as _);
     /// `SIGTSTP`
-    Tstp = c::SIGTSTP,
+    pub const Tstp: Self = Self(c::SIGTSTP as _);
     /// `SIGTTIN`
-    Ttin = c::SIGTTIN,
+    pub const Ttin: Self = Self(c::SIGTTIN as _);
     /// `SIGTTOU`
-    Ttou = c::SIGTTOU,
+    pub const Ttou: Self = Self(c::SIGTTOU as _);
     /// `SIGURG`
-    Urg = c::SIGURG,
+    pub const Urg: Self = Self(c::SIGURG as _);
     /// `SIGXCPU`
-    Xcpu = c::SIGXCPU,
+    pub const Xcpu: Self = Self(c::SIGXCPU as _);
     /// `SIGXFSZ`
-    Xfsz = c::SIGXFSZ,
+    pub const Xfsz: Self = Self(c::SIGXFSZ as _);
     /// `SIGVTALRM`
     #[doc(alias = "Vtalrm")]
-    Vtalarm = c::SIGVTALRM,
+    pub const Vtalarm: Self = Self(c::SIGVTALRM as _);
     /// `SIGPROF`
-    Prof = c::SIGPROF,
+    pub const Prof: Self = Self(c::SIGPROF as _);
     /// `SIGWINCH`
-    Winch = c::SIGWINCH,
+    pub const Winch: Self = Self(c::SIGWINCH as _);
     /// `SIGIO`, aka `SIGPOLL`
     #[doc(alias = "Poll")]
     #[cfg(not(target_os = "haiku"))]
-    Io = c::SIGIO,
+    pub const Io: Self = Self(c::SIGIO as _);
     /// `SIGPWR`
     #[cfg(not(any(bsd, target_os = "haiku")))]
     #[doc(alias = "Pwr")]
-    Power = c::SIGPWR,
+    pub const Power: Self = Self(c::SIGPWR as _);
     /// `SIGSYS`, aka `SIGUNUSED`
     #[doc(alias = "Unused")]
-    Sys = c::SIGSYS,
+    pub const Sys: Self = Self(c::SIGSYS as _);
     /// `SIGEMT`
     #[cfg(bsd)]
-    Emt = c::SIGEMT,
+    pub const Emt: Self = Self(c::SIGEMT as _);
     /// `SIGINFO`
     #[cfg(bsd)]
-    Info = c::SIGINFO,
+    pub const Info: Self = Self(c::SIGINFO as _);
     /// `SIGTHR`
     #[cfg(target_os = "freebsd")]
     #[doc(alias = "Lwp")]
-    Thr = c::SIGTHR,
+    pub const Thr: Self = Self(c::SIGTHR as _);
     /// `SIGLIBRT`
     #[cfg(target_os = "freebsd")]
-    Librt = c::SIGLIBRT,
+    pub const Librt: Self = Self(c::SIGLIBRT as _);
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -280,9 +301,123 @@ impl Signal {
             c::SIGTHR => Some(Self::Thr),
             #[cfg(target_os = "freebsd")]
             c::SIGLIBRT => Some(Self::Librt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            _ if (Self::RT_RANGE_START..=Self::RT_RANGE_END).contains(&sig) => Some(Self(sig)),
             _ => None,
         }
     }
+
+    /// Return the raw signal number.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl Signal {
+    /// The first signal number reserved for real-time signals by the kernel.
+    ///
+    /// libc reserves some signals at the start of this range for its own
+    /// use, so this is lower than [`Signal::rt_min`].
+    const RT_RANGE_START: i32 = 32;
+    /// The last valid signal number.
+    const RT_RANGE_END: i32 = linux_raw_sys::general::_NSIG as i32;
+
+    /// Return the lowest real-time signal available to the application,
+    /// `SIGRTMIN`.
+    ///
+    /// This excludes the signals libc reserves for its own use.
+    #[inline]
+    pub fn rt_min() -> Self {
+        Self(c::SIGRTMIN())
+    }
+
+    /// Return the highest real-time signal available to the application,
+    /// `SIGRTMAX`.
+    #[inline]
+    pub fn rt_max() -> Self {
+        Self(c::SIGRTMAX())
+    }
+
+    /// Return the real-time signal `SIGRTMIN + n`, if it's no greater than
+    /// `SIGRTMAX`.
+    #[inline]
+    pub fn rt(n: u32) -> Option<Self> {
+        let min = Self::rt_min().as_raw();
+        let max = Self::rt_max().as_raw();
+        if n <= (max - min) as u32 {
+            Some(Self(min + n as i32))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+impl core::fmt::Debug for Signal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match *self {
+            Self::Hup => "Hup",
+            Self::Int => "Int",
+            Self::Quit => "Quit",
+            Self::Ill => "Ill",
+            Self::Trap => "Trap",
+            Self::Abort => "Abort",
+            Self::Bus => "Bus",
+            Self::Fpe => "Fpe",
+            Self::Kill => "Kill",
+            Self::Usr1 => "Usr1",
+            Self::Segv => "Segv",
+            Self::Usr2 => "Usr2",
+            Self::Pipe => "Pipe",
+            Self::Alarm => "Alarm",
+            Self::Term => "Term",
+            #[cfg(not(any(
+                bsd,
+                solarish,
+                target_os = "aix",
+                target_os = "haiku",
+                all(
+                    any(target_os = "android", target_os = "linux"),
+                    any(
+                        target_arch = "mips",
+                        target_arch = "mips64",
+                        target_arch = "sparc",
+                        target_arch = "sparc64"
+                    ),
+                )
+            )))]
+            Self::Stkflt => "Stkflt",
+            Self::Child => "Child",
+            Self::Cont => "Cont",
+            Self::Stop => "Stop",
+            Self::Tstp => "Tstp",
+            Self::Ttin => "Ttin",
+            Self::Ttou => "Ttou",
+            Self::Urg => "Urg",
+            Self::Xcpu => "Xcpu",
+            Self::Xfsz => "Xfsz",
+            Self::Vtalarm => "Vtalarm",
+            Self::Prof => "Prof",
+            Self::Winch => "Winch",
+            #[cfg(not(target_os = "haiku"))]
+            Self::Io => "Io",
+            #[cfg(not(any(bsd, target_os = "haiku")))]
+            Self::Power => "Power",
+            Self::Sys => "Sys",
+            #[cfg(bsd)]
+            Self::Emt => "Emt",
+            #[cfg(bsd)]
+            Self::Info => "Info",
+            #[cfg(target_os = "freebsd")]
+            Self::Thr => "Thr",
+            #[cfg(target_os = "freebsd")]
+            Self::Librt => "Librt",
+            _ => return write!(f, "Signal({})", self.0),
+        };
+        f.write_str(name)
+    }
 }
 
 pub const EXIT_SUCCESS: c::c_int = c::EXIT_SUCCESS;
@@ -0,0 +1,23 @@
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `O_*` flags for use with [`openpt`] and [`ioctl_tiocgptpeer`].
+    ///
+    /// [`openpt`]: crate::pty::openpt
+    /// [`ioctl_tiocgptpeer`]: crate::pty::ioctl_tiocgptpeer
+    pub struct OpenptFlags: c::c_int {
+        /// `O_RDWR`
+        const RDWR = c::O_RDWR;
+
+        /// `O_NOCTTY`
+        const NOCTTY = c::O_NOCTTY;
+
+        /// `O_CLOEXEC`
+        ///
+        /// The standard `posix_openpt` function doesn't support `CLOEXEC`,
+        /// but Linux's does.
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const CLOEXEC = c::O_CLOEXEC;
+    }
+}
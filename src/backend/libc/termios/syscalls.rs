@@ -6,6 +6,8 @@
 
 use super::super::c;
 use super::super::conv::{borrowed_fd, ret, ret_pid_t};
+#[cfg(not(target_os = "wasi"))]
+use super::types::NCCS;
 use crate::fd::BorrowedFd;
 #[cfg(feature = "procfs")]
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -15,36 +17,81 @@ use crate::io;
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Pid, RawNonZeroPid};
 #[cfg(not(target_os = "wasi"))]
-use crate::termios::{Action, OptionalActions, QueueSelector, Speed, Termios, Winsize};
+use crate::termios::{
+    speed_code, Action, ControlModes, InputModes, LocalModes, OptionalActions, OutputModes,
+    QueueSelector, SpecialCodes, Termios, Winsize,
+};
 use core::mem::MaybeUninit;
 
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn tcgetattr(fd: BorrowedFd<'_>) -> io::Result<Termios> {
-    let mut result = MaybeUninit::<Termios>::uninit();
-    unsafe {
-        ret(c::tcgetattr(borrowed_fd(fd), result.as_mut_ptr()))?;
-        Ok(result.assume_init())
+    // On Linux, use `TCGETS2` where we can, so that we get the actual speeds,
+    // even if they don't have `B*` codes.
+    #[cfg(termios2)]
+    {
+        use linux_raw_sys::general::{CBAUD, CIBAUD};
+
+        let raw = unsafe {
+            let mut result = MaybeUninit::<c::termios2>::uninit();
+            ret(c::ioctl(borrowed_fd(fd), c::TCGETS2, result.as_mut_ptr()))?;
+            result.assume_init()
+        };
+
+        let mut special_codes = SpecialCodes([0; NCCS]);
+        special_codes.0[..raw.c_cc.len()].copy_from_slice(&raw.c_cc);
+
+        // Keep any flags we don't know about, so that `tcsetattr` preserves
+        // them. The speed bits are represented separately, by `input_speed`
+        // and `output_speed`.
+        unsafe {
+            Ok(Termios {
+                input_modes: InputModes::from_bits_unchecked(raw.c_iflag),
+                output_modes: OutputModes::from_bits_unchecked(raw.c_oflag),
+                control_modes: ControlModes::from_bits_unchecked(raw.c_cflag & !(CBAUD | CIBAUD)),
+                local_modes: LocalModes::from_bits_unchecked(raw.c_lflag),
+                line_discipline: raw.c_line,
+                special_codes,
+                input_speed: raw.c_ispeed,
+                output_speed: raw.c_ospeed,
+            })
+        }
     }
-}
 
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
-pub(crate) fn tcgetattr2(fd: BorrowedFd<'_>) -> io::Result<crate::termios::Termios2> {
-    let mut result = MaybeUninit::<crate::termios::Termios2>::uninit();
-    unsafe {
-        ret(c::ioctl(borrowed_fd(fd), c::TCGETS2, result.as_mut_ptr()))?;
-        Ok(result.assume_init())
+    #[cfg(not(termios2))]
+    {
+        use crate::termios::speed_value;
+
+        let raw = unsafe {
+            let mut result = MaybeUninit::<c::termios>::uninit();
+            ret(c::tcgetattr(borrowed_fd(fd), result.as_mut_ptr()))?;
+            result.assume_init()
+        };
+
+        let mut special_codes = SpecialCodes([0; NCCS]);
+        special_codes.0[..raw.c_cc.len()].copy_from_slice(&raw.c_cc);
+
+        // Translate the speed codes into speeds. If a code isn't recognized,
+        // pass it through unchanged, so that `tcsetattr` preserves it.
+        let input_speed = unsafe { c::cfgetispeed(&raw) };
+        let output_speed = unsafe { c::cfgetospeed(&raw) };
+        let input_speed = speed_value(input_speed).unwrap_or(input_speed as u32);
+        let output_speed = speed_value(output_speed).unwrap_or(output_speed as u32);
+
+        // Keep any flags we don't know about, so that `tcsetattr` preserves
+        // them.
+        unsafe {
+            Ok(Termios {
+                input_modes: InputModes::from_bits_unchecked(raw.c_iflag),
+                output_modes: OutputModes::from_bits_unchecked(raw.c_oflag),
+                control_modes: ControlModes::from_bits_unchecked(raw.c_cflag),
+                local_modes: LocalModes::from_bits_unchecked(raw.c_lflag),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                line_discipline: raw.c_line,
+                special_codes,
+                input_speed,
+                output_speed,
+            })
+        }
     }
 }
 
@@ -68,39 +115,69 @@ pub(crate) fn tcsetattr(
     optional_actions: OptionalActions,
     termios: &Termios,
 ) -> io::Result<()> {
-    unsafe {
-        ret(c::tcsetattr(
-            borrowed_fd(fd),
-            optional_actions as _,
-            termios,
-        ))
+    // On Linux, use `TCSETS2` where we can, so that we can set speeds which
+    // don't have `B*` codes, using `BOTHER`.
+    #[cfg(termios2)]
+    {
+        use linux_raw_sys::general::{BOTHER, CBAUD, CIBAUD, IBSHIFT};
+
+        // Use the `B*` codes where possible, for the benefit of anything
+        // reading the flags with the older `TCGETS`, and `BOTHER` otherwise.
+        // As with `cfsetspeed`, an input code of zero means the input speed
+        // is the same as the output speed.
+        let output_code = speed_code(termios.output_speed).unwrap_or(BOTHER);
+        let input_code = if termios.input_speed == termios.output_speed {
+            0
+        } else {
+            speed_code(termios.input_speed).unwrap_or(BOTHER)
+        };
+
+        let mut raw = unsafe { core::mem::zeroed::<c::termios2>() };
+        raw.c_iflag = termios.input_modes.bits();
+        raw.c_oflag = termios.output_modes.bits();
+        raw.c_cflag = (termios.control_modes.bits() & !(CBAUD | CIBAUD))
+            | output_code
+            | (input_code << IBSHIFT);
+        raw.c_lflag = termios.local_modes.bits();
+        raw.c_line = termios.line_discipline;
+        let len = raw.c_cc.len();
+        raw.c_cc.copy_from_slice(&termios.special_codes.0[..len]);
+        raw.c_ispeed = termios.input_speed;
+        raw.c_ospeed = termios.output_speed;
+
+        unsafe {
+            ret(c::ioctl(
+                borrowed_fd(fd),
+                (c::TCSETS2 as u32 + (optional_actions as u32 - c::TCSANOW as u32)) as _,
+                &raw,
+            ))
+        }
     }
-}
 
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
-pub(crate) fn tcsetattr2(
-    fd: BorrowedFd,
-    optional_actions: OptionalActions,
-    termios: &crate::termios::Termios2,
-) -> io::Result<()> {
-    unsafe {
-        ret(c::ioctl(
-            borrowed_fd(fd),
-            (c::TCSETS2 as u32 + optional_actions as u32) as _,
-            termios,
-        ))
+    #[cfg(not(termios2))]
+    {
+        let mut raw = unsafe { core::mem::zeroed::<c::termios>() };
+        raw.c_iflag = termios.input_modes.bits();
+        raw.c_oflag = termios.output_modes.bits();
+        raw.c_cflag = termios.control_modes.bits();
+        raw.c_lflag = termios.local_modes.bits();
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            raw.c_line = termios.line_discipline;
+        }
+        let len = raw.c_cc.len();
+        raw.c_cc.copy_from_slice(&termios.special_codes.0[..len]);
+
+        // Speeds which aren't recognized were passed through unchanged by
+        // `tcgetattr`, so pass them back unchanged here.
+        let input_speed = speed_code(termios.input_speed).unwrap_or(termios.input_speed as _);
+        let output_speed = speed_code(termios.output_speed).unwrap_or(termios.output_speed as _);
+
+        unsafe {
+            ret(c::cfsetispeed(&mut raw, input_speed))?;
+            ret(c::cfsetospeed(&mut raw, output_speed))?;
+            ret(c::tcsetattr(borrowed_fd(fd), optional_actions as _, &raw))
+        }
     }
 }
 
@@ -151,44 +228,6 @@ pub(crate) fn tcgetwinsize(fd: BorrowedFd) -> io::Result<Winsize> {
     }
 }
 
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-#[must_use]
-pub(crate) fn cfgetospeed(termios: &Termios) -> Speed {
-    unsafe { c::cfgetospeed(termios) }
-}
-
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-#[must_use]
-pub(crate) fn cfgetispeed(termios: &Termios) -> Speed {
-    unsafe { c::cfgetispeed(termios) }
-}
-
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-pub(crate) fn cfmakeraw(termios: &mut Termios) {
-    unsafe { c::cfmakeraw(termios) }
-}
-
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-pub(crate) fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    unsafe { ret(c::cfsetospeed(termios, speed)) }
-}
-
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-pub(crate) fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    unsafe { ret(c::cfsetispeed(termios, speed)) }
-}
-
-#[cfg(not(target_os = "wasi"))]
-#[inline]
-pub(crate) fn cfsetspeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    unsafe { ret(c::cfsetspeed(termios, speed)) }
-}
-
 pub(crate) fn isatty(fd: BorrowedFd<'_>) -> bool {
     // Use the return value of `isatty` alone. We don't check `errno` because
     // we return `bool` rather than `io::Result<bool>`, because we assume
@@ -1,4 +1,15 @@
 use super::super::c;
+use crate::termios::SpecialCodeIndex;
+use bitflags::bitflags;
+
+// Older versions of libc lack `IUCLC` and `XCASE` on most Linux
+// architectures, so use the linux-raw-sys definitions there.
+#[cfg(any(solarish, target_os = "haiku"))]
+use c::IUCLC;
+#[cfg(target_os = "haiku")]
+use c::XCASE;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use linux_raw_sys::general::{IUCLC, XCASE};
 
 /// `TCSA*` values for use with [`tcsetattr`].
 ///
@@ -62,363 +73,499 @@ pub enum Action {
     IOn = c::TCION,
 }
 
-/// `struct termios` for use with [`tcgetattr`] and [`tcsetattr`].
-///
-/// [`tcgetattr`]: crate::termios::tcgetattr
-/// [`tcsetattr`]: crate::termios::tcsetattr
-#[doc(alias = "termios")]
-pub type Termios = c::termios;
-
-/// `struct termios2` for use with [`tcgetattr2`] and [`tcsetattr2`].
-///
-/// [`tcgetattr2`]: crate::termios::tcgetattr2
-/// [`tcsetattr2`]: crate::termios::tcsetattr2
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
-#[doc(alias = "termios2")]
-pub type Termios2 = c::termios2;
-
 /// `struct winsize` for use with [`tcgetwinsize`].
 ///
 /// [`tcgetwinsize`]: crate::termios::tcgetwinsize
 #[doc(alias = "winsize")]
 pub type Winsize = c::winsize;
 
-/// `tcflag_t`—A type for the flags fields of [`Termios`].
+/// `tcflag_t`—The type underlying the flags fields of [`Termios`].
+///
+/// [`Termios`]: crate::termios::Termios
 #[doc(alias = "tcflag_t")]
 pub type Tcflag = c::tcflag_t;
 
-/// `speed_t`—A return type for [`cfsetspeed`] and similar.
+/// `speed_t`—A speed code, such as [`B9600`], for use with [`speed_value`].
 ///
-/// [`cfsetspeed`]: crate::termios::cfsetspeed
+/// [`speed_value`]: crate::termios::speed_value
 #[doc(alias = "speed_t")]
 pub type Speed = c::speed_t;
 
-/// `VINTR`
-pub const VINTR: usize = c::VINTR as usize;
-
-/// `VQUIT`
-pub const VQUIT: usize = c::VQUIT as usize;
-
-/// `VERASE`
-pub const VERASE: usize = c::VERASE as usize;
-
-/// `VKILL`
-pub const VKILL: usize = c::VKILL as usize;
-
-/// `VEOF`
-pub const VEOF: usize = c::VEOF as usize;
-
-/// `VTIME`
-pub const VTIME: usize = c::VTIME as usize;
-
-/// `VMIN`
-pub const VMIN: usize = c::VMIN as usize;
-
-/// `VSWTC`
-#[cfg(not(any(
-    apple,
-    solarish,
-    target_os = "aix",
-    target_os = "dragonfly",
-    target_os = "freebsd",
-    target_os = "haiku",
-    target_os = "netbsd",
-    target_os = "openbsd",
-)))]
-pub const VSWTC: usize = c::VSWTC as usize;
-
-/// `VSTART`
-pub const VSTART: usize = c::VSTART as usize;
-
-/// `VSTOP`
-pub const VSTOP: usize = c::VSTOP as usize;
-
-/// `VSUSP`
-pub const VSUSP: usize = c::VSUSP as usize;
-
-/// `VEOL`
-pub const VEOL: usize = c::VEOL as usize;
-
-/// `VREPRINT`
-#[cfg(not(target_os = "haiku"))]
-pub const VREPRINT: usize = c::VREPRINT as usize;
-
-/// `VDISCARD`
-#[cfg(not(any(target_os = "aix", target_os = "haiku")))]
-pub const VDISCARD: usize = c::VDISCARD as usize;
-
-/// `VWERASE`
-#[cfg(not(any(target_os = "aix", target_os = "haiku")))]
-pub const VWERASE: usize = c::VWERASE as usize;
-
-/// `VLNEXT`
-#[cfg(not(target_os = "haiku"))]
-pub const VLNEXT: usize = c::VLNEXT as usize;
-
-/// `VEOL2`
-pub const VEOL2: usize = c::VEOL2 as usize;
-
-/// `IGNBRK`
-pub const IGNBRK: Tcflag = c::IGNBRK;
-
-/// `BRKINT`
-pub const BRKINT: Tcflag = c::BRKINT;
-
-/// `IGNPAR`
-pub const IGNPAR: Tcflag = c::IGNPAR;
-
-/// `PARMRK`
-pub const PARMRK: Tcflag = c::PARMRK;
-
-/// `INPCK`
-pub const INPCK: Tcflag = c::INPCK;
+/// `NCCS`—The number of special codes in a [`Termios`].
+///
+/// [`Termios`]: crate::termios::Termios
+pub(crate) const NCCS: usize = c::NCCS;
+
+bitflags! {
+    /// Flags controlling terminal input.
+    ///
+    /// This is the `c_iflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.input_modes
+    pub struct InputModes: Tcflag {
+        /// `IGNBRK`
+        const IGNBRK = c::IGNBRK;
+
+        /// `BRKINT`
+        const BRKINT = c::BRKINT;
+
+        /// `IGNPAR`
+        const IGNPAR = c::IGNPAR;
+
+        /// `PARMRK`
+        const PARMRK = c::PARMRK;
+
+        /// `INPCK`
+        const INPCK = c::INPCK;
+
+        /// `ISTRIP`
+        const ISTRIP = c::ISTRIP;
+
+        /// `INLCR`
+        const INLCR = c::INLCR;
+
+        /// `IGNCR`
+        const IGNCR = c::IGNCR;
+
+        /// `ICRNL`
+        const ICRNL = c::ICRNL;
+
+        /// `IUCLC`
+        #[cfg(any(
+            solarish,
+            target_os = "android",
+            target_os = "haiku",
+            target_os = "linux",
+        ))]
+        const IUCLC = IUCLC;
+
+        /// `IXON`
+        const IXON = c::IXON;
+
+        /// `IXANY`
+        #[cfg(not(target_os = "redox"))]
+        const IXANY = c::IXANY;
+
+        /// `IXOFF`
+        const IXOFF = c::IXOFF;
+
+        /// `IMAXBEL`
+        #[cfg(not(any(target_os = "haiku", target_os = "redox")))]
+        const IMAXBEL = c::IMAXBEL;
+
+        /// `IUTF8`
+        #[cfg(not(any(
+            solarish,
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "emscripten",
+            target_os = "freebsd",
+            target_os = "haiku",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "redox",
+        )))]
+        const IUTF8 = c::IUTF8;
+    }
+}
 
-/// `ISTRIP`
-pub const ISTRIP: Tcflag = c::ISTRIP;
+bitflags! {
+    /// Flags controlling terminal output.
+    ///
+    /// This is the `c_oflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.output_modes
+    pub struct OutputModes: Tcflag {
+        /// `OPOST`
+        const OPOST = c::OPOST;
+
+        /// `OLCUC`
+        #[cfg(not(any(
+            apple,
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "redox",
+        )))]
+        const OLCUC = c::OLCUC;
+
+        /// `ONLCR`
+        const ONLCR = c::ONLCR;
+
+        /// `OCRNL`
+        const OCRNL = c::OCRNL;
+
+        /// `ONOCR`
+        const ONOCR = c::ONOCR;
+
+        /// `ONLRET`
+        const ONLRET = c::ONLRET;
+
+        /// `OFILL`
+        #[cfg(not(bsd))]
+        const OFILL = c::OFILL;
+
+        /// `OFDEL`
+        #[cfg(not(bsd))]
+        const OFDEL = c::OFDEL;
+
+        /// `NLDLY`
+        #[cfg(not(any(bsd, solarish, target_os = "redox")))]
+        const NLDLY = c::NLDLY;
+
+        /// `NL0`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const NL0 = c::NL0;
+
+        /// `NL1`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const NL1 = c::NL1;
+
+        /// `CRDLY`
+        #[cfg(not(any(bsd, solarish, target_os = "redox")))]
+        const CRDLY = c::CRDLY;
+
+        /// `CR0`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const CR0 = c::CR0;
+
+        /// `CR1`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const CR1 = c::CR1;
+
+        /// `CR2`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const CR2 = c::CR2;
+
+        /// `CR3`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const CR3 = c::CR3;
+
+        /// `TABDLY`
+        #[cfg(not(any(netbsdlike, solarish, target_os = "dragonfly", target_os = "redox")))]
+        const TABDLY = c::TABDLY;
+
+        /// `TAB0`
+        #[cfg(not(any(
+            netbsdlike,
+            solarish,
+            target_os = "dragonfly",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const TAB0 = c::TAB0;
+
+        /// `TAB1`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const TAB1 = c::TAB1;
+
+        /// `TAB2`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const TAB2 = c::TAB2;
+
+        /// `TAB3`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const TAB3 = c::TAB3;
+
+        /// `XTABS`
+        #[cfg(not(any(
+            bsd,
+            solarish,
+            target_os = "aix",
+            target_os = "haiku",
+            target_os = "redox",
+        )))]
+        const XTABS = c::XTABS;
+
+        /// `BSDLY`
+        #[cfg(not(any(bsd, solarish, target_os = "redox")))]
+        const BSDLY = c::BSDLY;
+
+        /// `BS0`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const BS0 = c::BS0;
+
+        /// `BS1`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const BS1 = c::BS1;
+
+        /// `FFDLY`
+        #[cfg(not(any(target_env = "musl", bsd, solarish, target_os = "redox")))]
+        const FFDLY = c::FFDLY;
+
+        /// `FF0`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const FF0 = c::FF0;
+
+        /// `FF1`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const FF1 = c::FF1;
+
+        /// `VTDLY`
+        #[cfg(not(any(target_env = "musl", bsd, solarish, target_os = "redox")))]
+        const VTDLY = c::VTDLY;
+
+        /// `VT0`
+        #[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
+        const VT0 = c::VT0;
+
+        /// `VT1`
+        #[cfg(not(any(
+            target_env = "musl",
+            bsd,
+            solarish,
+            target_os = "emscripten",
+            target_os = "fuchsia",
+            target_os = "redox",
+        )))]
+        const VT1 = c::VT1;
+    }
+}
 
-/// `INLCR`
-pub const INLCR: Tcflag = c::INLCR;
+bitflags! {
+    /// Flags controlling terminal hardware, other than the speed.
+    ///
+    /// This is the `c_cflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.control_modes
+    pub struct ControlModes: Tcflag {
+        /// `CSIZE`
+        const CSIZE = c::CSIZE;
+
+        /// `CS5`
+        const CS5 = c::CS5;
+
+        /// `CS6`
+        const CS6 = c::CS6;
+
+        /// `CS7`
+        const CS7 = c::CS7;
+
+        /// `CS8`
+        const CS8 = c::CS8;
+
+        /// `CSTOPB`
+        const CSTOPB = c::CSTOPB;
+
+        /// `CREAD`
+        const CREAD = c::CREAD;
+
+        /// `PARENB`
+        const PARENB = c::PARENB;
+
+        /// `PARODD`
+        const PARODD = c::PARODD;
+
+        /// `HUPCL`
+        const HUPCL = c::HUPCL;
+
+        /// `CLOCAL`
+        const CLOCAL = c::CLOCAL;
+
+        /// `CRTSCTS`
+        #[cfg(not(any(target_os = "aix", target_os = "redox")))]
+        const CRTSCTS = c::CRTSCTS;
+
+        /// `CMSPAR`
+        #[cfg(not(any(
+            bsd,
+            solarish,
+            target_os = "aix",
+            target_os = "emscripten",
+            target_os = "haiku",
+            target_os = "redox",
+        )))]
+        const CMSPAR = c::CMSPAR;
+    }
+}
 
-/// `IGNCR`
-pub const IGNCR: Tcflag = c::IGNCR;
+bitflags! {
+    /// Flags controlling “local” terminal modes.
+    ///
+    /// This is the `c_lflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.local_modes
+    pub struct LocalModes: Tcflag {
+        /// `ISIG`
+        const ISIG = c::ISIG;
 
-/// `ICRNL`
-pub const ICRNL: Tcflag = c::ICRNL;
+        /// `ICANON`
+        const ICANON = c::ICANON;
 
-/// `IUCLC`
-#[cfg(any(solarish, target_os = "haiku"))]
-pub const IUCLC: Tcflag = c::IUCLC;
+        /// `XCASE`
+        #[cfg(any(target_os = "android", target_os = "haiku", target_os = "linux"))]
+        const XCASE = XCASE;
 
-/// `IXON`
-pub const IXON: Tcflag = c::IXON;
+        /// `ECHO`
+        const ECHO = c::ECHO;
 
-/// `IXANY`
-#[cfg(not(target_os = "redox"))]
-pub const IXANY: Tcflag = c::IXANY;
+        /// `ECHOE`
+        const ECHOE = c::ECHOE;
 
-/// `IXOFF`
-pub const IXOFF: Tcflag = c::IXOFF;
+        /// `ECHOK`
+        const ECHOK = c::ECHOK;
 
-/// `IMAXBEL`
-#[cfg(not(any(target_os = "haiku", target_os = "redox")))]
-pub const IMAXBEL: Tcflag = c::IMAXBEL;
+        /// `ECHONL`
+        const ECHONL = c::ECHONL;
 
-/// `IUTF8`
-#[cfg(not(any(
-    solarish,
-    target_os = "aix",
-    target_os = "dragonfly",
-    target_os = "emscripten",
-    target_os = "freebsd",
-    target_os = "haiku",
-    target_os = "netbsd",
-    target_os = "openbsd",
-    target_os = "redox",
-)))]
-pub const IUTF8: Tcflag = c::IUTF8;
+        /// `ECHOCTL`
+        #[cfg(not(any(target_os = "redox")))]
+        const ECHOCTL = c::ECHOCTL;
 
-/// `OPOST`
-pub const OPOST: Tcflag = c::OPOST;
+        /// `ECHOPRT`
+        #[cfg(not(any(target_os = "redox")))]
+        const ECHOPRT = c::ECHOPRT;
 
-/// `OLCUC`
-#[cfg(not(any(
-    apple,
-    target_os = "aix",
-    target_os = "dragonfly",
-    target_os = "freebsd",
-    target_os = "netbsd",
-    target_os = "redox",
-)))]
-pub const OLCUC: Tcflag = c::OLCUC;
+        /// `ECHOKE`
+        #[cfg(not(any(target_os = "redox")))]
+        const ECHOKE = c::ECHOKE;
 
-/// `ONLCR`
-pub const ONLCR: Tcflag = c::ONLCR;
+        /// `FLUSHO`
+        #[cfg(not(any(target_os = "redox")))]
+        const FLUSHO = c::FLUSHO;
 
-/// `OCRNL`
-pub const OCRNL: Tcflag = c::OCRNL;
+        /// `NOFLSH`
+        const NOFLSH = c::NOFLSH;
 
-/// `ONOCR`
-pub const ONOCR: Tcflag = c::ONOCR;
+        /// `TOSTOP`
+        const TOSTOP = c::TOSTOP;
 
-/// `ONLRET`
-pub const ONLRET: Tcflag = c::ONLRET;
+        /// `PENDIN`
+        #[cfg(not(any(target_os = "redox")))]
+        const PENDIN = c::PENDIN;
 
-/// `OFILL`
-#[cfg(not(bsd))]
-pub const OFILL: Tcflag = c::OFILL;
+        /// `IEXTEN`
+        const IEXTEN = c::IEXTEN;
 
-/// `OFDEL`
-#[cfg(not(bsd))]
-pub const OFDEL: Tcflag = c::OFDEL;
+        /// `EXTPROC`
+        #[cfg(not(any(target_os = "aix", target_os = "haiku", target_os = "redox")))]
+        const EXTPROC = c::EXTPROC;
+    }
+}
 
-/// `NLDLY`
-#[cfg(not(any(bsd, solarish, target_os = "redox")))]
-pub const NLDLY: Tcflag = c::NLDLY;
+impl SpecialCodeIndex {
+    /// `VINTR`
+    pub const VINTR: Self = Self(c::VINTR as usize);
 
-/// `NL0`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const NL0: Tcflag = c::NL0;
+    /// `VQUIT`
+    pub const VQUIT: Self = Self(c::VQUIT as usize);
 
-/// `NL1`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const NL1: Tcflag = c::NL1;
+    /// `VERASE`
+    pub const VERASE: Self = Self(c::VERASE as usize);
 
-/// `CRDLY`
-#[cfg(not(any(bsd, solarish, target_os = "redox")))]
-pub const CRDLY: Tcflag = c::CRDLY;
+    /// `VKILL`
+    pub const VKILL: Self = Self(c::VKILL as usize);
 
-/// `CR0`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const CR0: Tcflag = c::CR0;
+    /// `VEOF`
+    pub const VEOF: Self = Self(c::VEOF as usize);
 
-/// `CR1`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const CR1: Tcflag = c::CR1;
+    /// `VTIME`
+    pub const VTIME: Self = Self(c::VTIME as usize);
 
-/// `CR2`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const CR2: Tcflag = c::CR2;
+    /// `VMIN`
+    pub const VMIN: Self = Self(c::VMIN as usize);
 
-/// `CR3`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const CR3: Tcflag = c::CR3;
+    /// `VSWTC`
+    #[cfg(not(any(
+        apple,
+        solarish,
+        target_os = "aix",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "haiku",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    pub const VSWTC: Self = Self(c::VSWTC as usize);
 
-/// `TABDLY`
-#[cfg(not(any(netbsdlike, solarish, target_os = "dragonfly", target_os = "redox")))]
-pub const TABDLY: Tcflag = c::TABDLY;
+    /// `VSTART`
+    pub const VSTART: Self = Self(c::VSTART as usize);
 
-/// `TAB0`
-#[cfg(not(any(
-    netbsdlike,
-    solarish,
-    target_os = "dragonfly",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const TAB0: Tcflag = c::TAB0;
+    /// `VSTOP`
+    pub const VSTOP: Self = Self(c::VSTOP as usize);
 
-/// `TAB1`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const TAB1: Tcflag = c::TAB1;
+    /// `VSUSP`
+    pub const VSUSP: Self = Self(c::VSUSP as usize);
 
-/// `TAB2`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const TAB2: Tcflag = c::TAB2;
+    /// `VEOL`
+    pub const VEOL: Self = Self(c::VEOL as usize);
 
-/// `TAB3`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const TAB3: Tcflag = c::TAB3;
+    /// `VREPRINT`
+    #[cfg(not(target_os = "haiku"))]
+    pub const VREPRINT: Self = Self(c::VREPRINT as usize);
 
-/// `BSDLY`
-#[cfg(not(any(bsd, solarish, target_os = "redox")))]
-pub const BSDLY: Tcflag = c::BSDLY;
+    /// `VDISCARD`
+    #[cfg(not(any(target_os = "aix", target_os = "haiku")))]
+    pub const VDISCARD: Self = Self(c::VDISCARD as usize);
 
-/// `BS0`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const BS0: Tcflag = c::BS0;
+    /// `VWERASE`
+    #[cfg(not(any(target_os = "aix", target_os = "haiku")))]
+    pub const VWERASE: Self = Self(c::VWERASE as usize);
 
-/// `BS1`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const BS1: Tcflag = c::BS1;
+    /// `VLNEXT`
+    #[cfg(not(target_os = "haiku"))]
+    pub const VLNEXT: Self = Self(c::VLNEXT as usize);
 
-/// `FFDLY`
-#[cfg(not(any(target_env = "musl", bsd, solarish, target_os = "redox")))]
-pub const FFDLY: Tcflag = c::FFDLY;
-
-/// `FF0`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const FF0: Tcflag = c::FF0;
-
-/// `FF1`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const FF1: Tcflag = c::FF1;
-
-/// `VTDLY`
-#[cfg(not(any(target_env = "musl", bsd, solarish, target_os = "redox")))]
-pub const VTDLY: Tcflag = c::VTDLY;
-
-/// `VT0`
-#[cfg(not(any(bsd, solarish, target_os = "fuchsia", target_os = "redox")))]
-pub const VT0: Tcflag = c::VT0;
-
-/// `VT1`
-#[cfg(not(any(
-    target_env = "musl",
-    bsd,
-    solarish,
-    target_os = "emscripten",
-    target_os = "fuchsia",
-    target_os = "redox",
-)))]
-pub const VT1: Tcflag = c::VT1;
+    /// `VEOL2`
+    pub const VEOL2: Self = Self(c::VEOL2 as usize);
+}
 
 /// `B0`
 pub const B0: Speed = c::B0;
@@ -567,170 +714,3 @@ pub const B3500000: Speed = c::B3500000;
     target_os = "solaris",
 )))]
 pub const B4000000: Speed = c::B4000000;
-
-/// `BOTHER`
-#[cfg(any(target_os = "android", target_os = "linux"))]
-pub const BOTHER: Speed = c::BOTHER;
-
-/// `CSIZE`
-pub const CSIZE: Tcflag = c::CSIZE;
-
-/// `CS5`
-pub const CS5: Tcflag = c::CS5;
-
-/// `CS6`
-pub const CS6: Tcflag = c::CS6;
-
-/// `CS7`
-pub const CS7: Tcflag = c::CS7;
-
-/// `CS8`
-pub const CS8: Tcflag = c::CS8;
-
-/// `CSTOPB`
-pub const CSTOPB: Tcflag = c::CSTOPB;
-
-/// `CREAD`
-pub const CREAD: Tcflag = c::CREAD;
-
-/// `PARENB`
-pub const PARENB: Tcflag = c::PARENB;
-
-/// `PARODD`
-pub const PARODD: Tcflag = c::PARODD;
-
-/// `HUPCL`
-pub const HUPCL: Tcflag = c::HUPCL;
-
-/// `CLOCAL`
-pub const CLOCAL: Tcflag = c::CLOCAL;
-
-/// `ISIG`
-pub const ISIG: Tcflag = c::ISIG;
-
-/// `ICANON`—A flag for the `c_lflag` field of [`Termios`] indicating
-/// canonical mode.
-pub const ICANON: Tcflag = c::ICANON;
-
-/// `ECHO`
-pub const ECHO: Tcflag = c::ECHO;
-
-/// `ECHOE`
-pub const ECHOE: Tcflag = c::ECHOE;
-
-/// `ECHOK`
-pub const ECHOK: Tcflag = c::ECHOK;
-
-/// `ECHONL`
-pub const ECHONL: Tcflag = c::ECHONL;
-
-/// `NOFLSH`
-pub const NOFLSH: Tcflag = c::NOFLSH;
-
-/// `TOSTOP`
-pub const TOSTOP: Tcflag = c::TOSTOP;
-
-/// `IEXTEN`
-pub const IEXTEN: Tcflag = c::IEXTEN;
-
-/// `EXTA`
-#[cfg(not(any(
-    solarish,
-    target_os = "emscripten",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const EXTA: Speed = c::EXTA;
-
-/// `EXTB`
-#[cfg(not(any(
-    solarish,
-    target_os = "emscripten",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const EXTB: Speed = c::EXTB;
-
-/// `CBAUD`
-#[cfg(not(any(bsd, target_os = "haiku", target_os = "redox")))]
-pub const CBAUD: Tcflag = c::CBAUD;
-
-/// `CBAUDEX`
-#[cfg(not(any(
-    bsd,
-    solarish,
-    target_os = "aix",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const CBAUDEX: Tcflag = c::CBAUDEX;
-
-/// `CIBAUD`
-#[cfg(not(any(
-    target_arch = "powerpc",
-    target_arch = "powerpc64",
-    bsd,
-    target_os = "emscripten",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const CIBAUD: Tcflag = c::CIBAUD;
-
-/// `CIBAUD`
-// glibc on powerpc lacks a definition for `CIBAUD`, even though the Linux
-// headers and Musl on powerpc both have one. So define it manually.
-#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
-pub const CIBAUD: Tcflag = 0o77600000;
-
-/// `CMSPAR`
-#[cfg(not(any(
-    bsd,
-    solarish,
-    target_os = "aix",
-    target_os = "emscripten",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const CMSPAR: Tcflag = c::CMSPAR;
-
-/// `CRTSCTS`
-#[cfg(not(any(target_os = "aix", target_os = "redox")))]
-pub const CRTSCTS: Tcflag = c::CRTSCTS;
-
-/// `XCASE`
-#[cfg(any(target_arch = "s390x", target_os = "haiku"))]
-pub const XCASE: Tcflag = c::XCASE;
-
-/// `ECHOCTL`
-#[cfg(not(any(target_os = "redox")))]
-pub const ECHOCTL: Tcflag = c::ECHOCTL;
-
-/// `ECHOPRT`
-#[cfg(not(any(target_os = "redox")))]
-pub const ECHOPRT: Tcflag = c::ECHOPRT;
-
-/// `ECHOKE`
-#[cfg(not(any(target_os = "redox")))]
-pub const ECHOKE: Tcflag = c::ECHOKE;
-
-/// `FLUSHO`
-#[cfg(not(any(target_os = "redox")))]
-pub const FLUSHO: Tcflag = c::FLUSHO;
-
-/// `PENDIN`
-#[cfg(not(any(target_os = "redox")))]
-pub const PENDIN: Tcflag = c::PENDIN;
-
-/// `EXTPROC`
-#[cfg(not(any(target_os = "aix", target_os = "haiku", target_os = "redox")))]
-pub const EXTPROC: Tcflag = c::EXTPROC;
-
-/// `XTABS`
-#[cfg(not(any(
-    bsd,
-    solarish,
-    target_os = "aix",
-    target_os = "haiku",
-    target_os = "redox",
-)))]
-pub const XTABS: Tcflag = c::XTABS;
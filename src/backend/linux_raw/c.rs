@@ -9,27 +9,169 @@ pub(crate) use linux_raw_sys::cmsg_macros::*;
 pub(crate) use linux_raw_sys::ctypes::*;
 pub(crate) use linux_raw_sys::errno::EINVAL;
 pub(crate) use linux_raw_sys::general::{
-    AF_DECnet, __kernel_sa_family_t as sa_family_t, __kernel_sockaddr_storage as sockaddr_storage,
-    cmsghdr, in6_addr, in_addr, iovec, ip_mreq, ipv6_mreq, linger, msghdr, siginfo_t, size_t,
-    sockaddr, sockaddr_in, sockaddr_in6, sockaddr_un, socklen_t, AF_APPLETALK, AF_ASH, AF_ATMPVC,
-    AF_ATMSVC, AF_AX25, AF_BLUETOOTH, AF_BRIDGE, AF_CAN, AF_ECONET, AF_IEEE802154, AF_INET,
-    AF_INET6, AF_IPX, AF_IRDA, AF_ISDN, AF_IUCV, AF_KEY, AF_LLC, AF_NETBEUI, AF_NETLINK, AF_NETROM,
-    AF_PACKET, AF_PHONET, AF_PPPOX, AF_RDS, AF_ROSE, AF_RXRPC, AF_SECURITY, AF_SNA, AF_TIPC,
-    AF_UNIX, AF_UNSPEC, AF_WANPIPE, AF_X25, CLD_CONTINUED, CLD_DUMPED, CLD_EXITED, CLD_KILLED,
-    CLD_STOPPED, CLD_TRAPPED, IPPROTO_AH, IPPROTO_BEETPH, IPPROTO_COMP, IPPROTO_DCCP, IPPROTO_EGP,
-    IPPROTO_ENCAP, IPPROTO_ESP, IPPROTO_ETHERNET, IPPROTO_FRAGMENT, IPPROTO_GRE, IPPROTO_ICMP,
-    IPPROTO_ICMPV6, IPPROTO_IDP, IPPROTO_IGMP, IPPROTO_IP, IPPROTO_IPIP, IPPROTO_IPV6, IPPROTO_MH,
-    IPPROTO_MPLS, IPPROTO_MPTCP, IPPROTO_MTP, IPPROTO_PIM, IPPROTO_PUP, IPPROTO_RAW,
-    IPPROTO_ROUTING, IPPROTO_RSVP, IPPROTO_SCTP, IPPROTO_TCP, IPPROTO_TP, IPPROTO_UDP,
-    IPPROTO_UDPLITE, IPV6_ADD_MEMBERSHIP, IPV6_DROP_MEMBERSHIP, IPV6_MULTICAST_HOPS,
-    IPV6_MULTICAST_LOOP, IPV6_UNICAST_HOPS, IPV6_V6ONLY, IP_ADD_MEMBERSHIP, IP_DROP_MEMBERSHIP,
-    IP_MULTICAST_LOOP, IP_MULTICAST_TTL, IP_TTL, MSG_CMSG_CLOEXEC, MSG_CONFIRM, MSG_DONTROUTE,
-    MSG_DONTWAIT, MSG_EOR, MSG_ERRQUEUE, MSG_MORE, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK, MSG_TRUNC,
-    MSG_WAITALL, O_CLOEXEC, O_NONBLOCK, O_NONBLOCK as PIDFD_NONBLOCK, P_ALL, P_PID, P_PIDFD,
-    SCM_CREDENTIALS, SCM_RIGHTS, SHUT_RD, SHUT_RDWR, SHUT_WR, SOCK_DGRAM, SOCK_RAW, SOCK_RDM,
-    SOCK_SEQPACKET, SOCK_STREAM, SOL_SOCKET, SO_BROADCAST, SO_ERROR, SO_KEEPALIVE, SO_LINGER,
-    SO_PASSCRED, SO_RCVBUF, SO_RCVTIMEO_NEW, SO_RCVTIMEO_OLD, SO_REUSEADDR, SO_SNDBUF,
-    SO_SNDTIMEO_NEW, SO_SNDTIMEO_OLD, SO_TYPE, TCP_NODELAY,
+    __kernel_sa_family_t as sa_family_t, __kernel_sockaddr_storage as sockaddr_storage, cmsghdr,
+    in6_addr, in_addr, iovec, ip_mreq, ipv6_mreq, linger, mmsghdr, msghdr, siginfo_t, size_t,
+    sockaddr, sockaddr_in, sockaddr_in6, sockaddr_un, socklen_t, ucred, AF_DECnet, AF_APPLETALK,
+    AF_ASH, AF_ATMPVC, AF_ATMSVC, AF_AX25, AF_BLUETOOTH, AF_BRIDGE, AF_CAN, AF_ECONET,
+    AF_IEEE802154, AF_INET, AF_INET6, AF_IPX, AF_IRDA, AF_ISDN, AF_IUCV, AF_KEY, AF_LLC,
+    AF_NETBEUI, AF_NETLINK, AF_NETROM, AF_PACKET, AF_PHONET, AF_PPPOX, AF_RDS, AF_ROSE, AF_RXRPC,
+    AF_SECURITY, AF_SNA, AF_TIPC, AF_UNIX, AF_UNSPEC, AF_WANPIPE, AF_X25, CLD_CONTINUED,
+    CLD_DUMPED, CLD_EXITED, CLD_KILLED, CLD_STOPPED, CLD_TRAPPED, IPPROTO_AH, IPPROTO_BEETPH,
+    IPPROTO_COMP, IPPROTO_DCCP, IPPROTO_EGP, IPPROTO_ENCAP, IPPROTO_ESP, IPPROTO_ETHERNET,
+    IPPROTO_FRAGMENT, IPPROTO_GRE, IPPROTO_ICMP, IPPROTO_ICMPV6, IPPROTO_IDP, IPPROTO_IGMP,
+    IPPROTO_IP, IPPROTO_IPIP, IPPROTO_IPV6, IPPROTO_MH, IPPROTO_MPLS, IPPROTO_MPTCP, IPPROTO_MTP,
+    IPPROTO_PIM, IPPROTO_PUP, IPPROTO_RAW, IPPROTO_ROUTING, IPPROTO_RSVP, IPPROTO_SCTP,
+    IPPROTO_TCP, IPPROTO_TP, IPPROTO_UDP, IPPROTO_UDPLITE, IPV6_ADD_MEMBERSHIP,
+    IPV6_DROP_MEMBERSHIP, IPV6_HOPLIMIT, IPV6_MULTICAST_HOPS, IPV6_MULTICAST_LOOP, IPV6_PKTINFO,
+    IPV6_RECVHOPLIMIT, IPV6_RECVPKTINFO, IPV6_RECVTCLASS, IPV6_TCLASS, IPV6_UNICAST_HOPS,
+    IPV6_V6ONLY, IP_ADD_MEMBERSHIP, IP_DROP_MEMBERSHIP, IP_MULTICAST_LOOP, IP_MULTICAST_TTL,
+    IP_PKTINFO, IP_RECVTOS, IP_RECVTTL, IP_TOS, IP_TTL, MSG_CMSG_CLOEXEC, MSG_CONFIRM,
+    MSG_DONTROUTE, MSG_DONTWAIT, MSG_EOR, MSG_ERRQUEUE, MSG_MORE, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK,
+    MSG_TRUNC, MSG_WAITALL, O_CLOEXEC, O_NONBLOCK, O_NONBLOCK as PIDFD_NONBLOCK, P_ALL, P_PID,
+    P_PIDFD, SCM_CREDENTIALS, SCM_RIGHTS, SHUT_RD, SHUT_RDWR, SHUT_WR, SOCK_DGRAM, SOCK_RAW,
+    SOCK_RDM, SOCK_SEQPACKET, SOCK_STREAM, SOL_SOCKET, SO_BROADCAST, SO_ERROR, SO_KEEPALIVE,
+    SO_LINGER, SO_PASSCRED, SO_PEERCRED, SO_RCVBUF, SO_RCVTIMEO_NEW, SO_RCVTIMEO_OLD, SO_REUSEADDR,
+    SO_SNDBUF, SO_SNDTIMEO_NEW, SO_SNDTIMEO_OLD, SO_TIMESTAMPING_NEW, SO_TIMESTAMPING_OLD,
+    SO_TIMESTAMPNS_NEW, SO_TIMESTAMPNS_OLD, SO_TYPE, TCP_NODELAY,
 };
 pub(crate) use linux_raw_sys::general::{NFS_SUPER_MAGIC, PROC_SUPER_MAGIC, UTIME_NOW, UTIME_OMIT};
 pub(crate) use linux_raw_sys::general::{XATTR_CREATE, XATTR_REPLACE};
+pub(crate) use linux_raw_sys::netlink::{
+    nlattr, nlmsghdr, sockaddr_nl, NETLINK_AUDIT, NETLINK_CONNECTOR, NETLINK_CRYPTO,
+    NETLINK_DNRTMSG, NETLINK_ECRYPTFS, NETLINK_FIB_LOOKUP, NETLINK_GENERIC, NETLINK_ISCSI,
+    NETLINK_KOBJECT_UEVENT, NETLINK_NETFILTER, NETLINK_NFLOG, NETLINK_RDMA, NETLINK_ROUTE,
+    NETLINK_SCSITRANSPORT, NETLINK_SELINUX, NETLINK_SMC, NETLINK_SOCK_DIAG, NETLINK_USERSOCK,
+    NETLINK_XFRM, NLA_F_NESTED, NLA_F_NET_BYTEORDER, NLMSG_DONE, NLMSG_ERROR, NLMSG_NOOP,
+    NLMSG_OVERRUN, NLM_F_ACK, NLM_F_APPEND, NLM_F_ATOMIC, NLM_F_CREATE, NLM_F_DUMP,
+    NLM_F_DUMP_FILTERED, NLM_F_DUMP_INTR, NLM_F_ECHO, NLM_F_EXCL, NLM_F_MATCH, NLM_F_MULTI,
+    NLM_F_REPLACE, NLM_F_REQUEST, NLM_F_ROOT,
+};
+
+// TODO: Use the definitions in linux-raw-sys once they're available there.
+/// `SO_PASSPIDFD` (since Linux 6.5)
+pub(crate) const SO_PASSPIDFD: u32 = 76;
+/// `SO_PEERPIDFD` (since Linux 6.5)
+pub(crate) const SO_PEERPIDFD: u32 = 77;
+/// `SCM_PIDFD` (since Linux 6.5)
+pub(crate) const SCM_PIDFD: u32 = 4;
+/// `PIDFD_SIGNAL_THREAD` (since Linux 6.9)
+pub(crate) const PIDFD_SIGNAL_THREAD: u32 = 1 << 0;
+/// `PIDFD_SIGNAL_THREAD_GROUP` (since Linux 6.9)
+pub(crate) const PIDFD_SIGNAL_THREAD_GROUP: u32 = 1 << 1;
+/// `PIDFD_SIGNAL_PROCESS_GROUP` (since Linux 6.9)
+pub(crate) const PIDFD_SIGNAL_PROCESS_GROUP: u32 = 1 << 2;
+/// `MSG_WAITFORONE`
+pub(crate) const MSG_WAITFORONE: u32 = 0x10000;
+/// `SOL_IP`
+pub(crate) const SOL_IP: u32 = 0;
+/// `SOL_IPV6`
+pub(crate) const SOL_IPV6: u32 = 41;
+/// `SOL_UDP`
+pub(crate) const SOL_UDP: u32 = 17;
+/// `UDP_SEGMENT` (since Linux 4.18)
+pub(crate) const UDP_SEGMENT: u32 = 103;
+/// `UDP_GRO` (since Linux 5.0)
+pub(crate) const UDP_GRO: u32 = 104;
+/// `SOF_TIMESTAMPING_*` flags for `SO_TIMESTAMPING`
+pub(crate) const SOF_TIMESTAMPING_TX_HARDWARE: u32 = 1 << 0;
+pub(crate) const SOF_TIMESTAMPING_TX_SOFTWARE: u32 = 1 << 1;
+pub(crate) const SOF_TIMESTAMPING_RX_HARDWARE: u32 = 1 << 2;
+pub(crate) const SOF_TIMESTAMPING_RX_SOFTWARE: u32 = 1 << 3;
+pub(crate) const SOF_TIMESTAMPING_SOFTWARE: u32 = 1 << 4;
+pub(crate) const SOF_TIMESTAMPING_SYS_HARDWARE: u32 = 1 << 5;
+pub(crate) const SOF_TIMESTAMPING_RAW_HARDWARE: u32 = 1 << 6;
+pub(crate) const SOF_TIMESTAMPING_OPT_ID: u32 = 1 << 7;
+pub(crate) const SOF_TIMESTAMPING_TX_SCHED: u32 = 1 << 8;
+pub(crate) const SOF_TIMESTAMPING_TX_ACK: u32 = 1 << 9;
+pub(crate) const SOF_TIMESTAMPING_OPT_CMSG: u32 = 1 << 10;
+pub(crate) const SOF_TIMESTAMPING_OPT_TSONLY: u32 = 1 << 11;
+pub(crate) const SOF_TIMESTAMPING_OPT_STATS: u32 = 1 << 12;
+pub(crate) const SOF_TIMESTAMPING_OPT_PKTINFO: u32 = 1 << 13;
+pub(crate) const SOF_TIMESTAMPING_OPT_TX_SWHW: u32 = 1 << 14;
+
+// TODO: Use the definitions in linux-raw-sys once `if_packet.h` and
+// `if_ether.h` are available there.
+/// `SOL_PACKET`
+pub(crate) const SOL_PACKET: u32 = 263;
+/// `ETH_P_*` protocol identifiers
+pub(crate) const ETH_P_ALL: u32 = 0x0003;
+pub(crate) const ETH_P_IP: u32 = 0x0800;
+pub(crate) const ETH_P_ARP: u32 = 0x0806;
+pub(crate) const ETH_P_IPV6: u32 = 0x86dd;
+/// `PACKET_*` packet types, in `sll_pkttype`
+pub(crate) const PACKET_HOST: u8 = 0;
+pub(crate) const PACKET_BROADCAST: u8 = 1;
+pub(crate) const PACKET_MULTICAST: u8 = 2;
+pub(crate) const PACKET_OTHERHOST: u8 = 3;
+pub(crate) const PACKET_OUTGOING: u8 = 4;
+/// `PACKET_*` socket options
+pub(crate) const PACKET_ADD_MEMBERSHIP: u32 = 1;
+pub(crate) const PACKET_DROP_MEMBERSHIP: u32 = 2;
+pub(crate) const PACKET_RX_RING: u32 = 5;
+pub(crate) const PACKET_VERSION: u32 = 10;
+pub(crate) const PACKET_TX_RING: u32 = 13;
+pub(crate) const PACKET_FANOUT: u32 = 18;
+/// `PACKET_MR_*` membership types, in `mr_type`
+pub(crate) const PACKET_MR_MULTICAST: u16 = 0;
+pub(crate) const PACKET_MR_PROMISC: u16 = 1;
+pub(crate) const PACKET_MR_ALLMULTI: u16 = 2;
+pub(crate) const PACKET_MR_UNICAST: u16 = 3;
+/// `PACKET_FANOUT_*` modes and flags
+pub(crate) const PACKET_FANOUT_HASH: u32 = 0;
+pub(crate) const PACKET_FANOUT_LB: u32 = 1;
+pub(crate) const PACKET_FANOUT_CPU: u32 = 2;
+pub(crate) const PACKET_FANOUT_ROLLOVER: u32 = 3;
+pub(crate) const PACKET_FANOUT_RND: u32 = 4;
+pub(crate) const PACKET_FANOUT_QM: u32 = 5;
+pub(crate) const PACKET_FANOUT_FLAG_ROLLOVER: u32 = 0x1000;
+pub(crate) const PACKET_FANOUT_FLAG_UNIQUEID: u32 = 0x2000;
+pub(crate) const PACKET_FANOUT_FLAG_IGNORE_OUTGOING: u32 = 0x4000;
+pub(crate) const PACKET_FANOUT_FLAG_DEFRAG: u32 = 0x8000;
+/// `TPACKET_V*` versions, for `PACKET_VERSION`
+pub(crate) const TPACKET_V1: u32 = 0;
+pub(crate) const TPACKET_V2: u32 = 1;
+pub(crate) const TPACKET_V3: u32 = 2;
+/// `TP_STATUS_*` block and frame status flags
+pub(crate) const TP_STATUS_KERNEL: u32 = 0;
+pub(crate) const TP_STATUS_USER: u32 = 1 << 0;
+pub(crate) const TP_STATUS_LOSING: u32 = 1 << 2;
+pub(crate) const TP_STATUS_VLAN_VALID: u32 = 1 << 4;
+pub(crate) const TP_STATUS_BLK_TMO: u32 = 1 << 5;
+pub(crate) const TP_STATUS_VLAN_TPID_VALID: u32 = 1 << 6;
+pub(crate) const TP_STATUS_CSUM_VALID: u32 = 1 << 7;
+
+/// `struct sockaddr_ll`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct sockaddr_ll {
+    pub(crate) sll_family: c_ushort,
+    pub(crate) sll_protocol: c_ushort,
+    pub(crate) sll_ifindex: c_int,
+    pub(crate) sll_hatype: c_ushort,
+    pub(crate) sll_pkttype: c_uchar,
+    pub(crate) sll_halen: c_uchar,
+    pub(crate) sll_addr: [c_uchar; 8],
+}
+
+/// `struct packet_mreq`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct packet_mreq {
+    pub(crate) mr_ifindex: c_int,
+    pub(crate) mr_type: c_ushort,
+    pub(crate) mr_alen: c_ushort,
+    pub(crate) mr_address: [c_uchar; 8],
+}
+
+/// `struct tpacket_req3`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct tpacket_req3 {
+    pub(crate) tp_block_size: c_uint,
+    pub(crate) tp_block_nr: c_uint,
+    pub(crate) tp_frame_size: c_uint,
+    pub(crate) tp_frame_nr: c_uint,
+    pub(crate) tp_retire_blk_tov: c_uint,
+    pub(crate) tp_sizeof_priv: c_uint,
+    pub(crate) tp_feature_req_word: c_uint,
+}
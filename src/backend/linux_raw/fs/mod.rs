@@ -1,5 +1,5 @@
 pub(crate) mod dir;
-pub mod inotify;
+pub(crate) mod inotify;
 pub(crate) mod makedev;
 pub(crate) mod syscalls;
 pub(crate) mod types;
@@ -24,9 +24,9 @@ use crate::fd::AsFd;
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::fs::{
-    inotify, Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation, MemfdFlags, Mode,
-    OFlags, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs, StatVfs, StatVfsMountFlags,
-    StatxFlags, Timestamps, XattrFlags,
+    fanotify, inotify, Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation,
+    MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs, StatVfs,
+    StatVfsMountFlags, StatxFlags, Timestamps, XattrFlags,
 };
 use crate::io::{self, SeekFrom};
 use crate::process::{Gid, Uid};
@@ -36,10 +36,10 @@ use core::mem::MaybeUninit;
 #[cfg(target_arch = "mips64")]
 use linux_raw_sys::general::stat as linux_stat64;
 use linux_raw_sys::general::{
-    __kernel_fsid_t, __kernel_timespec, open_how, statx, AT_EACCESS, AT_FDCWD, AT_REMOVEDIR,
-    AT_SYMLINK_NOFOLLOW, F_ADD_SEALS, F_GETFL, F_GETLEASE, F_GETOWN, F_GETPIPE_SZ, F_GETSIG,
-    F_GET_SEALS, F_SETFL, F_SETPIPE_SZ, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE, SEEK_SET,
-    STATX__RESERVED,
+    __kernel_fsid_t, __kernel_timespec, fsconfig_command, open_how, statx, AT_EACCESS, AT_FDCWD,
+    AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW, F_ADD_SEALS, F_GETFL, F_GETLEASE, F_GETOWN, F_GETPIPE_SZ,
+    F_GETSIG, F_GET_SEALS, F_SETFL, F_SETPIPE_SZ, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE,
+    SEEK_SET, STATX__RESERVED,
 };
 #[cfg(target_pointer_width = "32")]
 use {
@@ -1515,6 +1515,299 @@ pub(crate) fn unmount(target: &CStr, flags: super::types::UnmountFlags) -> io::R
     unsafe { ret(syscall_readonly!(__NR_umount2, target, flags)) }
 }
 
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsopen(fs_name: &CStr, flags: crate::fs::FsOpenFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fsopen,
+            fs_name,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsmount(
+    fs_fd: BorrowedFd<'_>,
+    flags: crate::fs::FsMountFlags,
+    attr_flags: crate::fs::MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fsmount,
+            fs_fd,
+            c_uint(flags.bits()),
+            c_uint(attr_flags.bits())
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: crate::fs::FsPickFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fspick,
+            dirfd,
+            path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_flag(fs_fd: BorrowedFd<'_>, key: &CStr) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_FLAG as c::c_uint),
+            key,
+            zero(),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_string(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    value: &CStr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_STRING as c::c_uint),
+            key,
+            value,
+            zero()
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_binary(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    value: &[u8],
+) -> io::Result<()> {
+    let (value_addr, value_len) = slice(value);
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_BINARY as c::c_uint),
+            key,
+            value_addr,
+            value_len
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_path(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_PATH as c::c_uint),
+            key,
+            path,
+            dirfd
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_path_empty(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_PATH_EMPTY as c::c_uint),
+            key,
+            cstr!(""),
+            fd
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_set_fd(
+    fs_fd: BorrowedFd<'_>,
+    key: &CStr,
+    fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_SET_FD as c::c_uint),
+            key,
+            zero(),
+            fd
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_create(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_CMD_CREATE as c::c_uint),
+            zero(),
+            zero(),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fsconfig_reconfigure(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fsconfig,
+            fs_fd,
+            c_uint(fsconfig_command::FSCONFIG_CMD_RECONFIGURE as c::c_uint),
+            zero(),
+            zero(),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: crate::fs::OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_open_tree,
+            dirfd,
+            path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn move_mount(
+    from_dirfd: BorrowedFd<'_>,
+    from_path: &CStr,
+    to_dirfd: BorrowedFd<'_>,
+    to_path: &CStr,
+    flags: crate::fs::MoveMountFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_move_mount,
+            from_dirfd,
+            from_path,
+            to_dirfd,
+            to_path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: AtFlags,
+    attr: &linux_raw_sys::general::mount_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mount_setattr,
+            dirfd,
+            path,
+            flags,
+            by_ref(attr),
+            size_of::<linux_raw_sys::general::mount_attr, _>()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fanotify_init(
+    flags: fanotify::InitFlags,
+    event_flags: fanotify::EventFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fanotify_init,
+            c_uint(flags.bits()),
+            c_uint(event_flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fanotify_mark(
+    fd: BorrowedFd<'_>,
+    flags: fanotify::MarkFlags,
+    mask: fanotify::MaskFlags,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fd,
+            c_uint(flags.bits()),
+            hi(mask.bits()),
+            lo(mask.bits()),
+            dirfd,
+            path
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fd,
+            c_uint(flags.bits()),
+            pass_usize(mask.bits() as usize),
+            dirfd,
+            path
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn inotify_init1(flags: inotify::CreateFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_inotify_init1, flags)) }
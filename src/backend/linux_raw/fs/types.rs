@@ -39,6 +39,9 @@ bitflags! {
         /// `AT_EMPTY_PATH`
         const EMPTY_PATH = linux_raw_sys::general::AT_EMPTY_PATH;
 
+        /// `AT_RECURSIVE`
+        const RECURSIVE = linux_raw_sys::general::AT_RECURSIVE;
+
         /// `AT_EACCESS`
         const EACCESS = linux_raw_sys::general::AT_EACCESS;
 
@@ -48,6 +48,13 @@ pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     unsafe { ret_usize(syscall!(__NR_read, fd, buf_addr_mut, buf_len)) }
 }
 
+#[inline]
+pub(crate) fn read_uninit(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+
+    unsafe { ret_usize(syscall!(__NR_read, fd, buf_addr_mut, buf_len)) }
+}
+
 #[inline]
 pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -9,16 +9,25 @@
 use super::super::c;
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::loff_t_from_u64;
-use super::super::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+use super::super::conv::{
+    c_int, c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_usize, ret_void_star,
+};
 use super::types::{
     Advice, MapFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags, ProtFlags,
     UserfaultfdFlags,
 };
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
+use crate::process::Pid;
 #[cfg(target_pointer_width = "32")]
 use core::convert::TryInto;
 use linux_raw_sys::general::MAP_ANONYMOUS;
+#[cfg(feature = "process")]
+use {
+    super::super::conv::slice,
+    crate::mm::{MreleaseFlags, ProcessMadviseFlags},
+    crate::process::RemoteIoVec,
+};
 
 #[inline]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -32,6 +41,127 @@ pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::R
     }
 }
 
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn process_madvise(
+    pidfd: BorrowedFd<'_>,
+    remote: &[RemoteIoVec],
+    advice: Advice,
+    flags: ProcessMadviseFlags,
+) -> io::Result<usize> {
+    let (remote_addr, remote_len) = slice(remote);
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_process_madvise,
+            pidfd,
+            remote_addr,
+            remote_len,
+            c_uint(advice as c::c_uint),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn process_mrelease(pidfd: BorrowedFd<'_>, flags: MreleaseFlags) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_process_mrelease,
+            pidfd,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: c::c_uint,
+    nodemask: *const c::c_ulong,
+    maxnode: c::c_ulong,
+    flags: c::c_uint,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_mbind,
+        addr,
+        pass_usize(len),
+        c_uint(mode),
+        nodemask,
+        pass_usize(maxnode as usize),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn set_mempolicy(
+    mode: c::c_int,
+    nodemask: *const c::c_ulong,
+    maxnode: c::c_ulong,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_set_mempolicy,
+        c_int(mode),
+        nodemask,
+        pass_usize(maxnode as usize)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn get_mempolicy(
+    mode: *mut c::c_int,
+    nodemask: *mut c::c_ulong,
+    maxnode: c::c_ulong,
+    addr: *mut c::c_void,
+    flags: c::c_uint,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_get_mempolicy,
+        mode,
+        nodemask,
+        pass_usize(maxnode as usize),
+        addr,
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn move_pages(
+    pid: Option<Pid>,
+    count: c::c_ulong,
+    pages: *const *const c::c_void,
+    nodes: *const c::c_int,
+    status: *mut c::c_int,
+    flags: c::c_int,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_move_pages,
+        c_uint(Pid::as_raw(pid)),
+        pass_usize(count as usize),
+        pages,
+        nodes,
+        status,
+        c_int(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn migrate_pages(
+    pid: Option<Pid>,
+    maxnode: c::c_ulong,
+    old_nodes: *const c::c_ulong,
+    new_nodes: *const c::c_ulong,
+) -> io::Result<usize> {
+    ret_usize(syscall_readonly!(
+        __NR_migrate_pages,
+        c_uint(Pid::as_raw(pid)),
+        pass_usize(maxnode as usize),
+        old_nodes,
+        new_nodes
+    ))
+}
+
 #[inline]
 pub(crate) unsafe fn msync(addr: *mut c::c_void, len: usize, flags: MsyncFlags) -> io::Result<()> {
     ret(syscall!(__NR_msync, addr, pass_usize(len), flags))
@@ -6,10 +6,12 @@
 #![allow(unsafe_code)]
 
 use super::super::c;
-use super::super::net::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
+use super::super::net::write_sockaddr::{
+    encode_sockaddr_ll, encode_sockaddr_nl, encode_sockaddr_v4, encode_sockaddr_v6,
+};
 
 use crate::io;
-use crate::net::{SocketAddrV4, SocketAddrV6};
+use crate::net::{SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 
 use core::convert::TryInto;
@@ -86,7 +88,7 @@ pub(crate) fn with_v4_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV4>() as _,
+        msg_namelen: size_of::<c::sockaddr_in>() as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -108,7 +110,7 @@ pub(crate) fn with_v6_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV6>() as _,
+        msg_namelen: size_of::<c::sockaddr_in6>() as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -138,3 +140,108 @@ pub(crate) fn with_unix_msghdr<R>(
         ..unsafe { zeroed() }
     })
 }
+
+/// Create a message header intended to send with a netlink address.
+pub(crate) fn with_netlink_msghdr<R>(
+    addr: &SocketAddrNetlink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_nl(addr);
+
+    f(c::msghdr {
+        msg_name: as_ptr(&encoded) as _,
+        msg_namelen: size_of::<c::sockaddr_nl>() as _,
+        msg_iov: iov.as_ptr() as _,
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+
+        // Zero-initialize any padding bytes.
+        ..unsafe { zeroed() }
+    })
+}
+
+/// Create a message header intended to send with a link-layer address.
+pub(crate) fn with_link_msghdr<R>(
+    addr: &SocketAddrLink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_ll(addr);
+
+    f(c::msghdr {
+        msg_name: as_ptr(&encoded) as _,
+        msg_namelen: size_of::<c::sockaddr_ll>() as _,
+        msg_iov: iov.as_ptr() as _,
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+
+        // Zero-initialize any padding bytes.
+        ..unsafe { zeroed() }
+    })
+}
+
+/// Create a message header for a message to send with `sendmmsg`.
+pub(crate) fn send_mmsghdr(
+    name: Option<(&MaybeUninit<c::sockaddr_storage>, usize)>,
+    iov: &[io::IoSlice<'_>],
+    control: &mut crate::net::SendAncillaryBuffer<'_, '_, '_>,
+) -> c::mmsghdr {
+    let (msg_name, msg_namelen) = match name {
+        Some((name, len)) => (name.as_ptr() as _, len as _),
+        None => (null_mut(), 0),
+    };
+
+    c::mmsghdr {
+        msg_hdr: c::msghdr {
+            msg_name,
+            msg_namelen,
+            msg_iov: iov.as_ptr() as _,
+            msg_iovlen: msg_iov_len(iov.len()),
+            msg_control: control.as_control_ptr().cast(),
+            msg_controllen: msg_control_len(control.control_len()),
+
+            // Zero-initialize any padding bytes.
+            ..unsafe { zeroed() }
+        },
+        msg_len: 0,
+    }
+}
+
+/// Create a message header for a message to receive with `recvmmsg`.
+pub(crate) fn recv_mmsghdr(
+    name: &mut MaybeUninit<c::sockaddr_storage>,
+    iov: &mut [io::IoSliceMut<'_>],
+    control: &mut [u8],
+) -> c::mmsghdr {
+    c::mmsghdr {
+        msg_hdr: c::msghdr {
+            msg_name: name.as_mut_ptr().cast(),
+            msg_namelen: size_of::<c::sockaddr_storage>() as _,
+            msg_iov: iov.as_mut_ptr().cast(),
+            msg_iovlen: msg_iov_len(iov.len()),
+            msg_control: control.as_mut_ptr().cast(),
+            msg_controllen: msg_control_len(control.len()),
+
+            // Zero-initialize any padding bytes.
+            ..unsafe { zeroed() }
+        },
+        msg_len: 0,
+    }
+}
+
+/// Read the source address of a message received with `recvmmsg`.
+///
+/// # Safety
+///
+/// `raw` must have been filled in by `recvmmsg`.
+pub(crate) unsafe fn recv_mmsghdr_address(raw: &c::mmsghdr) -> Option<SocketAddrAny> {
+    super::read_sockaddr::maybe_read_sockaddr_os(
+        raw.msg_hdr.msg_name as _,
+        raw.msg_hdr.msg_namelen as _,
+    )
+}
@@ -4,7 +4,10 @@
 
 use super::super::c;
 use crate::io;
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrUnix,
+    SocketAddrV4, SocketAddrV6,
+};
 use alloc::vec::Vec;
 use core::mem::size_of;
 
@@ -101,6 +104,20 @@ pub(crate) unsafe fn read_sockaddr(
                 )?))
             }
         }
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        c::AF_PACKET => {
+            let decode = read_sockaddr_ll(storage, len).ok_or(io::Errno::INVAL)?;
+            Ok(SocketAddrAny::Link(decode))
+        }
         _ => Err(io::Errno::NOTSUP),
     }
 }
@@ -170,6 +187,47 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
                 )
             }
         }
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        c::AF_PACKET => SocketAddrAny::Link(read_sockaddr_ll(storage, len).unwrap()),
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// Decode a `struct sockaddr_ll`, which the kernel may truncate to the length
+/// of the physical-layer address.
+unsafe fn read_sockaddr_ll(storage: *const c::sockaddr, len: usize) -> Option<SocketAddrLink> {
+    let offsetof_sll_addr = {
+        let z = c::sockaddr_ll {
+            sll_family: 0,
+            sll_protocol: 0,
+            sll_ifindex: 0,
+            sll_hatype: 0,
+            sll_pkttype: 0,
+            sll_halen: 0,
+            sll_addr: [0; 8],
+        };
+        (crate::utils::as_ptr(&z.sll_addr) as usize) - (crate::utils::as_ptr(&z) as usize)
+    };
+    if len < offsetof_sll_addr {
+        return None;
+    }
+    let mut decode = core::mem::zeroed::<c::sockaddr_ll>();
+    core::ptr::copy_nonoverlapping(
+        storage.cast::<u8>(),
+        crate::utils::as_mut_ptr(&mut decode).cast::<u8>(),
+        len.min(size_of::<c::sockaddr_ll>()),
+    );
+    let halen = (decode.sll_halen as usize).min(len - offsetof_sll_addr);
+    Some(SocketAddrLink::from_raw(
+        decode.sll_protocol,
+        decode.sll_ifindex,
+        decode.sll_hatype,
+        decode.sll_pkttype,
+        halen as u8,
+        decode.sll_addr,
+    ))
+}
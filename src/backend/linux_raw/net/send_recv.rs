@@ -44,5 +44,7 @@ bitflags! {
         const TRUNC = c::MSG_TRUNC;
         /// `MSG_WAITALL`
         const WAITALL = c::MSG_WAITALL;
+        /// `MSG_WAITFORONE`
+        const WAITFORONE = c::MSG_WAITFORONE;
     }
 }
@@ -8,33 +8,40 @@
 
 use super::super::c;
 use super::super::conv::{
-    by_mut, by_ref, c_int, c_uint, ret, ret_owned_fd, ret_usize, size_of, slice, slice_mut,
-    socklen_t, zero,
+    by_mut, by_ref, c_int, c_uint, opt_mut, ret, ret_owned_fd, ret_usize, size_of, slice,
+    slice_mut, socklen_t, zero,
 };
 use super::msghdr::{
-    with_noaddr_msghdr, with_recv_msghdr, with_unix_msghdr, with_v4_msghdr, with_v6_msghdr,
+    with_link_msghdr, with_netlink_msghdr, with_noaddr_msghdr, with_recv_msghdr, with_unix_msghdr,
+    with_v4_msghdr, with_v6_msghdr,
 };
 use super::read_sockaddr::{initialize_family_to_unspec, maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
 use super::types::{AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
-use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
+use super::write_sockaddr::{
+    encode_sockaddr_ll, encode_sockaddr_nl, encode_sockaddr_v4, encode_sockaddr_v6,
+};
+use crate::backend::time::types::Timespec;
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::net::{
-    RecvAncillaryBuffer, RecvMsgReturn, SendAncillaryBuffer, SocketAddrAny, SocketAddrUnix,
-    SocketAddrV4, SocketAddrV6,
+    MMsgHdr, RecvAncillaryBuffer, RecvMMsgHdr, RecvMsgReturn, SendAncillaryBuffer, SocketAddrAny,
+    SocketAddrLink, SocketAddrNetlink, SocketAddrUnix, SocketAddrV4, SocketAddrV6,
 };
 use c::{sockaddr, sockaddr_in, sockaddr_in6, socklen_t};
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
+#[cfg(target_pointer_width = "32")]
+use linux_raw_sys::general::timespec as __kernel_old_timespec;
 #[cfg(target_arch = "x86")]
 use {
     super::super::conv::{slice_just_addr, x86_sys},
     super::super::reg::{ArgReg, SocketArg},
     linux_raw_sys::general::{
         SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
-        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDMSG,
-        SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMMSG, SYS_RECVMSG, SYS_SEND,
+        SYS_SENDMMSG, SYS_SENDMSG, SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET,
+        SYS_SOCKETPAIR,
     },
 };
 
@@ -435,6 +442,207 @@ pub(crate) fn sendmsg_unix(
     })
 }
 
+#[inline]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_netlink_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_sendmsg,
+                sockfd,
+                by_ref(&msghdr),
+                c_uint(msg_flags.bits())
+            ))
+        };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    c_uint(msg_flags.bits())
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg_link(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_link_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_sendmsg,
+                sockfd,
+                by_ref(&msghdr),
+                c_uint(msg_flags.bits())
+            ))
+        };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    c_uint(msg_flags.bits())
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [MMsgHdr<'_>],
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    // `MMsgHdr` is a transparent wrapper around `c::mmsghdr`.
+    let raw = msgs.as_mut_ptr().cast::<c::mmsghdr>();
+    let vlen: c::c_uint = msgs.len().try_into().unwrap_or(c::c_uint::MAX);
+
+    #[cfg(not(target_arch = "x86"))]
+    let sent = unsafe {
+        ret_usize(syscall!(
+            __NR_sendmmsg,
+            sockfd,
+            raw,
+            c_uint(vlen),
+            c_uint(msg_flags.bits())
+        ))?
+    };
+
+    #[cfg(target_arch = "x86")]
+    let sent = unsafe {
+        ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDMMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                sockfd.into(),
+                raw.into(),
+                c_uint(vlen),
+                c_uint(msg_flags.bits()),
+            ])
+        ))?
+    };
+
+    Ok(sent)
+}
+
+#[inline]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    msg_flags: RecvFlags,
+    timeout: Option<Timespec>,
+) -> io::Result<usize> {
+    // `RecvMMsgHdr` is a transparent wrapper around `c::mmsghdr`.
+    let raw = msgs.as_mut_ptr().cast::<c::mmsghdr>();
+    let vlen: c::c_uint = msgs.len().try_into().unwrap_or(c::c_uint::MAX);
+
+    #[cfg(target_pointer_width = "32")]
+    let received = unsafe {
+        let mut timeout = timeout;
+        match ret_usize(syscall!(
+            __NR_recvmmsg_time64,
+            sockfd,
+            raw,
+            c_uint(vlen),
+            c_uint(msg_flags.bits()),
+            opt_mut(timeout.as_mut())
+        )) {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            Err(io::Errno::NOSYS) => recvmmsg_old(sockfd, raw, vlen, msg_flags, timeout)?,
+            otherwise => otherwise?,
+        }
+    };
+
+    #[cfg(target_pointer_width = "64")]
+    let received = unsafe {
+        let mut timeout = timeout;
+        ret_usize(syscall!(
+            __NR_recvmmsg,
+            sockfd,
+            raw,
+            c_uint(vlen),
+            c_uint(msg_flags.bits()),
+            opt_mut(timeout.as_mut())
+        ))?
+    };
+
+    Ok(received)
+}
+
+/// Same as `recvmmsg` but uses the old `timespec` for 32-bit platforms
+/// without `recvmmsg_time64`.
+#[cfg(target_pointer_width = "32")]
+unsafe fn recvmmsg_old(
+    sockfd: BorrowedFd<'_>,
+    raw: *mut c::mmsghdr,
+    vlen: c::c_uint,
+    msg_flags: RecvFlags,
+    timeout: Option<Timespec>,
+) -> io::Result<usize> {
+    let mut old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+        }),
+        None => None,
+    };
+
+    #[cfg(not(target_arch = "x86"))]
+    {
+        ret_usize(syscall!(
+            __NR_recvmmsg,
+            sockfd,
+            raw,
+            c_uint(vlen),
+            c_uint(msg_flags.bits()),
+            opt_mut(old_timeout.as_mut())
+        ))
+    }
+
+    #[cfg(target_arch = "x86")]
+    {
+        ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_RECVMMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                sockfd.into(),
+                raw.into(),
+                c_uint(vlen),
+                c_uint(msg_flags.bits()),
+                opt_mut(old_timeout.as_mut()),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -610,6 +818,82 @@ pub(crate) fn sendto_unix(
     }
 }
 
+#[inline]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn recv(fd: BorrowedFd<'_>, buf: &mut [u8], flags: RecvFlags) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -853,6 +1137,56 @@ pub(crate) fn bind_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Result
     }
 }
 
+#[inline]
+pub(crate) fn bind_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_link(fd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_v4(fd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -928,6 +1262,56 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
     }
 }
 
+#[inline]
+pub(crate) fn connect_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_link(fd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -946,9 +1330,13 @@ pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
 
 pub(crate) mod sockopt {
     use super::{c, BorrowedFd};
+    use crate::fd::{FromRawFd, OwnedFd};
     use crate::io;
-    use crate::net::sockopt::Timeout;
-    use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+    use crate::net::packet::{
+        PacketFanoutFlags, PacketFanoutMode, PacketMembership, PacketVersion, TpacketReq3,
+    };
+    use crate::net::sockopt::{Timeout, TimestampingFlags};
+    use crate::net::{Ipv4Addr, Ipv6Addr, SocketType, UCred};
     use c::{SO_RCVTIMEO_NEW, SO_RCVTIMEO_OLD, SO_SNDTIMEO_NEW, SO_SNDTIMEO_OLD};
     use core::convert::TryInto;
     use core::time::Duration;
@@ -1124,6 +1512,86 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERCRED).map(UCred::from_raw)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_passpidfd(fd: BorrowedFd<'_>, passpidfd: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_PASSPIDFD,
+            from_bool(passpidfd),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_passpidfd(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSPIDFD).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_peerpidfd(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+        let pidfd: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERPIDFD)?;
+        Ok(unsafe { OwnedFd::from_raw_fd(pidfd) })
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_timestampns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        match setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_TIMESTAMPNS_NEW,
+            from_bool(value),
+        ) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPNS_NEW != c::SO_TIMESTAMPNS_OLD => {
+                setsockopt(
+                    fd,
+                    c::SOL_SOCKET as _,
+                    c::SO_TIMESTAMPNS_OLD,
+                    from_bool(value),
+                )
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_timestampns(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        match getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS_NEW) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPNS_NEW != c::SO_TIMESTAMPNS_OLD => {
+                getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS_OLD).map(to_bool)
+            }
+            otherwise => otherwise.map(to_bool),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_timestamping(
+        fd: BorrowedFd<'_>,
+        flags: TimestampingFlags,
+    ) -> io::Result<()> {
+        match setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_NEW, flags.bits()) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPING_NEW != c::SO_TIMESTAMPING_OLD => {
+                setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_OLD, flags.bits())
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_timestamping(fd: BorrowedFd<'_>) -> io::Result<TimestampingFlags> {
+        let bits = match getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_NEW) {
+            Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPING_NEW != c::SO_TIMESTAMPING_OLD => {
+                getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_OLD)?
+            }
+            otherwise => otherwise?,
+        };
+        Ok(TimestampingFlags::from_bits_truncate(bits))
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -1318,6 +1786,36 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[inline]
+    pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO, from_bool(value))
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ip_recvttl(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTTL, from_bool(value))
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_recvttl(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTTL).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ip_recvtos(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTOS, from_bool(value))
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_recvtos(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVTOS).map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -1328,6 +1826,51 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVPKTINFO,
+            from_bool(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVPKTINFO).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ipv6_recvhoplimit(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVHOPLIMIT,
+            from_bool(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_recvhoplimit(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVHOPLIMIT).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ipv6_recvtclass(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVTCLASS,
+            from_bool(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_recvtclass(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVTCLASS).map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -1456,6 +1999,122 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_udp_gro(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_UDP as _, c::UDP_GRO, from_bool(value))
+    }
+
+    #[inline]
+    pub(crate) fn get_udp_gro(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_UDP as _, c::UDP_GRO).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_udp_segment(fd: BorrowedFd<'_>, value: u16) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_UDP as _,
+            c::UDP_SEGMENT,
+            c::c_int::from(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_udp_segment(fd: BorrowedFd<'_>) -> io::Result<u16> {
+        getsockopt(fd, c::IPPROTO_UDP as _, c::UDP_SEGMENT).map(|value: c::c_int| value as u16)
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_add_membership(
+        fd: BorrowedFd<'_>,
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<()> {
+        let mreq = to_packet_mreq(ifindex, membership, address)?;
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_ADD_MEMBERSHIP, mreq)
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_drop_membership(
+        fd: BorrowedFd<'_>,
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<()> {
+        let mreq = to_packet_mreq(ifindex, membership, address)?;
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_DROP_MEMBERSHIP, mreq)
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_version(fd: BorrowedFd<'_>, version: PacketVersion) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_VERSION, version as c::c_int)
+    }
+
+    #[inline]
+    pub(crate) fn get_packet_version(fd: BorrowedFd<'_>) -> io::Result<PacketVersion> {
+        let version: c::c_int = getsockopt(fd, c::SOL_PACKET, c::PACKET_VERSION)?;
+        match version {
+            v if v == PacketVersion::V1 as c::c_int => Ok(PacketVersion::V1),
+            v if v == PacketVersion::V2 as c::c_int => Ok(PacketVersion::V2),
+            v if v == PacketVersion::V3 as c::c_int => Ok(PacketVersion::V3),
+            _ => Err(io::Errno::INVAL),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_fanout(
+        fd: BorrowedFd<'_>,
+        group_id: u16,
+        mode: PacketFanoutMode,
+        flags: PacketFanoutFlags,
+    ) -> io::Result<()> {
+        let value = u32::from(group_id) | ((mode as u32 | flags.bits()) << 16);
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_FANOUT, value)
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_rx_ring(fd: BorrowedFd<'_>, req: &TpacketReq3) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_RX_RING, to_tpacket_req3(req))
+    }
+
+    #[inline]
+    pub(crate) fn set_packet_tx_ring(fd: BorrowedFd<'_>, req: &TpacketReq3) -> io::Result<()> {
+        setsockopt(fd, c::SOL_PACKET, c::PACKET_TX_RING, to_tpacket_req3(req))
+    }
+
+    #[inline]
+    fn to_packet_mreq(
+        ifindex: u32,
+        membership: PacketMembership,
+        address: &[u8],
+    ) -> io::Result<c::packet_mreq> {
+        let mut mr_address = [0; 8];
+        mr_address
+            .get_mut(..address.len())
+            .ok_or(io::Errno::INVAL)?
+            .copy_from_slice(address);
+        Ok(c::packet_mreq {
+            mr_ifindex: ifindex as c::c_int,
+            mr_type: membership as u16,
+            mr_alen: address.len() as u16,
+            mr_address,
+        })
+    }
+
+    #[inline]
+    fn to_tpacket_req3(req: &TpacketReq3) -> c::tpacket_req3 {
+        c::tpacket_req3 {
+            tp_block_size: req.block_size,
+            tp_block_nr: req.block_nr,
+            tp_frame_size: req.frame_size,
+            tp_frame_nr: req.frame_nr,
+            tp_retire_blk_tov: req.retire_blk_tov,
+            tp_sizeof_priv: req.sizeof_priv,
+            tp_feature_req_word: req.feature_req_word,
+        }
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
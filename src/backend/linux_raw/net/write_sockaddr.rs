@@ -3,7 +3,10 @@
 #![allow(unsafe_code)]
 
 use super::super::c;
-use crate::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrStorage, SocketAddrUnix,
+    SocketAddrV4, SocketAddrV6,
+};
 use core::mem::size_of;
 
 pub(crate) unsafe fn write_sockaddr(
@@ -14,6 +17,8 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::V4(v4) => write_sockaddr_v4(v4, storage),
         SocketAddrAny::V6(v6) => write_sockaddr_v6(v6, storage),
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
+        SocketAddrAny::Netlink(nl) => write_sockaddr_nl(nl, storage),
+        SocketAddrAny::Link(ll) => write_sockaddr_ll(ll, storage),
     }
 }
 
@@ -58,3 +63,37 @@ unsafe fn write_sockaddr_unix(unix: &SocketAddrUnix, storage: *mut SocketAddrSto
     core::ptr::write(storage.cast(), unix.unix);
     unix.len()
 }
+
+pub(crate) fn encode_sockaddr_nl(nl: &SocketAddrNetlink) -> c::sockaddr_nl {
+    c::sockaddr_nl {
+        nl_family: c::AF_NETLINK as _,
+        nl_pad: 0,
+        nl_pid: nl.pid(),
+        nl_groups: nl.groups(),
+    }
+}
+
+unsafe fn write_sockaddr_nl(nl: &SocketAddrNetlink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_nl(nl);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+pub(crate) fn encode_sockaddr_ll(ll: &SocketAddrLink) -> c::sockaddr_ll {
+    let (sll_halen, sll_addr) = ll.raw_addr();
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: ll.raw_protocol(),
+        sll_ifindex: ll.ifindex() as _,
+        sll_hatype: ll.hatype(),
+        sll_pkttype: ll.pkttype().as_raw(),
+        sll_halen,
+        sll_addr,
+    }
+}
+
+unsafe fn write_sockaddr_ll(ll: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_ll(ll);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
@@ -8,27 +8,28 @@
 
 use super::super::c;
 use super::super::conv::{
-    by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, ret, ret_c_int, ret_c_uint,
-    ret_infallible, ret_usize, ret_usize_infallible, size_of, slice, slice_just_addr,
+    by_mut, by_ref, c_int, c_uint, negative_pid, opt_ref, pass_usize, raw_fd, ret, ret_c_int,
+    ret_c_uint, ret_infallible, ret_usize, ret_usize_infallible, size_of, slice, slice_just_addr,
     slice_just_addr_mut, slice_mut, zero,
 };
 use super::types::{RawCpuSet, RawUname};
 use crate::backend::conv::ret_owned_fd;
-use crate::fd::{AsRawFd, BorrowedFd, OwnedFd};
+use crate::fd::{AsRawFd, BorrowedFd, OwnedFd, RawFd};
 use crate::ffi::CStr;
-use crate::io;
+use crate::io::{self, IoSlice, IoSliceMut};
 use crate::process::{
-    Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, PidfdFlags, RawNonZeroPid, RawPid,
-    Resource, Rlimit, Signal, Sysinfo, Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions,
-    WaitidStatus,
+    Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, PidfdFlags, PidfdGetfdFlags, PidfdSiginfo,
+    PidfdSignalFlags, RawNonZeroPid, RawPid, RemoteIoVec, Resource, Rlimit, SigSet, Signal,
+    SignalfdFlags, Sysinfo, Uid, WaitId, WaitOptions, WaitStatus, WaitidOptions, WaitidStatus,
 };
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
 use core::num::NonZeroU32;
 use core::ptr::{null, null_mut};
 use linux_raw_sys::general::{
-    __kernel_gid_t, __kernel_pid_t, __kernel_uid_t, membarrier_cmd, membarrier_cmd_flag, rlimit,
-    rlimit64, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY, RLIM_INFINITY,
+    __kernel_gid_t, __kernel_pid_t, __kernel_uid_t, clone_args, membarrier_cmd,
+    membarrier_cmd_flag, rlimit, rlimit64, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY,
+    RLIM_INFINITY,
 };
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "fs")]
@@ -706,6 +707,125 @@ pub(crate) unsafe fn prctl(
     ret_c_int(syscall!(__NR_prctl, c_int(option), arg2, arg3, arg4, arg5))
 }
 
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice_mut(local);
+    let (remote_addr, remote_len) = slice(remote);
+    unsafe {
+        ret_usize(syscall!(
+            __NR_process_vm_readv,
+            pid,
+            local_addr,
+            local_len,
+            remote_addr,
+            remote_len,
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(local);
+    let (remote_addr, remote_len) = slice(remote);
+    ret_usize(syscall_readonly!(
+        __NR_process_vm_writev,
+        pid,
+        local_addr,
+        local_len,
+        remote_addr,
+        remote_len,
+        zero()
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_ptrace,
+        c_uint(request),
+        c_uint(Pid::as_raw(pid)),
+        addr,
+        data
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn seccomp(
+    operation: c::c_uint,
+    flags: c::c_uint,
+    args: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall!(
+        __NR_seccomp,
+        c_uint(operation),
+        c_uint(flags),
+        args
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn ioctl_seccomp(
+    fd: BorrowedFd<'_>,
+    request: c::c_uint,
+    arg: *mut c::c_void,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall!(__NR_ioctl, fd, c_uint(request), arg))
+}
+
+#[inline]
+pub(crate) unsafe fn landlock_create_ruleset(
+    attr: *const c::c_void,
+    size: usize,
+    flags: c::c_uint,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall_readonly!(
+        __NR_landlock_create_ruleset,
+        attr,
+        pass_usize(size),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn landlock_add_rule(
+    ruleset: BorrowedFd<'_>,
+    rule_type: c::c_uint,
+    rule_attr: *const c::c_void,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_landlock_add_rule,
+        ruleset,
+        c_uint(rule_type),
+        rule_attr,
+        c_uint(0)
+    ))
+}
+
+#[inline]
+pub(crate) fn landlock_restrict_self(ruleset: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_restrict_self,
+            ruleset,
+            c_uint(0)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     unsafe {
@@ -717,6 +837,81 @@ pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     }
 }
 
+#[inline]
+pub(crate) unsafe fn clone3(args: &clone_args) -> io::Result<Option<Pid>> {
+    let pid = ret_c_uint(syscall!(
+        __NR_clone3,
+        by_ref(args),
+        size_of::<clone_args, _>()
+    ))?;
+    Ok(Pid::from_raw(pid))
+}
+
+#[inline]
+pub(crate) fn pidfd_send_signal(
+    pidfd: BorrowedFd<'_>,
+    sig: Signal,
+    info: Option<&PidfdSiginfo>,
+    flags: PidfdSignalFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_pidfd_send_signal,
+            pidfd,
+            sig,
+            opt_ref(info),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pidfd_getfd(
+    pidfd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_pidfd_getfd,
+            pidfd,
+            raw_fd(targetfd),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_signalfd4,
+            c_int(-1),
+            by_ref(mask),
+            size_of::<SigSet, _>(),
+            flags
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd_set_mask(
+    fd: BorrowedFd<'_>,
+    mask: &SigSet,
+    flags: SignalfdFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_signalfd4,
+            fd,
+            by_ref(mask),
+            size_of::<SigSet, _>(),
+            flags
+        ))
+        .map(drop)
+    }
+}
+
 #[inline]
 pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
     let len = buf.len().try_into().map_err(|_| io::Errno::NOMEM)?;
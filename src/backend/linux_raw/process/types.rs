@@ -84,85 +84,92 @@ pub enum Resource {
 /// A signal number for use with [`kill_process`], [`kill_process_group`],
 /// and [`kill_current_process_group`].
 ///
+/// This is an open set: in addition to the named signals, it can hold any
+/// valid signal number, including the real-time signals between
+/// [`Signal::rt_min`] and [`Signal::rt_max`] on Linux.
+///
 /// [`kill_process`]: crate::process::kill_process
 /// [`kill_process_group`]: crate::process::kill_process_group
 /// [`kill_current_process_group`]: crate::process::kill_current_process_group
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(u32)]
-pub enum Signal {
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Signal(c::c_int);
+
+#[allow(non_upper_case_globals)]
+impl Signal {
     /// `SIGHUP`
-    Hup = linux_raw_sys::general::SIGHUP,
+    pub const Hup: Self = Self(linux_raw_sys::general::SIGHUP as _);
     /// `SIGINT`
-    Int = linux_raw_sys::general::SIGINT,
+    pub const Int: Self = Self(linux_raw_sys::general::SIGINT as _);
     /// `SIGQUIT`
-    Quit = linux_raw_sys::general::SIGQUIT,
+    pub const Quit: Self = Self(linux_raw_sys::general::SIGQUIT as _);
     /// `SIGILL`
-    Ill = linux_raw_sys::general::SIGILL,
+    pub const Ill: Self = Self(linux_raw_sys::general::SIGILL as _);
     /// `SIGTRAP`
-    Trap = linux_raw_sys::general::SIGTRAP,
+    pub const Trap: Self = Self(linux_raw_sys::general::SIGTRAP as _);
     /// `SIGABRT`, aka `SIGIOT`
     #[doc(alias = "Iot")]
     #[doc(alias = "Abrt")]
-    Abort = linux_raw_sys::general::SIGABRT,
+    pub const Abort: Self = Self(linux_raw_sys::general::SIGABRT as _);
     /// `SIGBUS`
-    Bus = linux_raw_sys::general::SIGBUS,
+    pub const Bus: Self = Self(linux_raw_sys::general::SIGBUS as _);
     /// `SIGFPE`
-    Fpe = linux_raw_sys::general::SIGFPE,
+    pub const Fpe: Self = Self(linux_raw_sys::general::SIGFPE as _);
     /// `SIGKILL`
-    Kill = linux_raw_sys::general::SIGKILL,
+    pub const Kill: Self = Self(linux_raw_sys::general::SIGKILL as _);
     /// `SIGUSR1`
-    Usr1 = linux_raw_sys::general::SIGUSR1,
+    pub const Usr1: Self = Self(linux_raw_sys::general::SIGUSR1 as _);
     /// `SIGSEGV`
-    Segv = linux_raw_sys::general::SIGSEGV,
+    pub const Segv: Self = Self(linux_raw_sys::general::SIGSEGV as _);
     /// `SIGUSR2`
-    Usr2 = linux_raw_sys::general::SIGUSR2,
+    pub const Usr2: Self = Self(linux_raw_sys::general::SIGUSR2 as _);
     /// `SIGPIPE`
-    Pipe = linux_raw_sys::general::SIGPIPE,
+    pub const Pipe: Self = Self(linux_raw_sys::general::SIGPIPE as _);
     /// `SIGALRM`
     #[doc(alias = "Alrm")]
-    Alarm = linux_raw_sys::general::SIGALRM,
+    pub const Alarm: Self = Self(linux_raw_sys::general::SIGALRM as _);
     /// `SIGTERM`
-    Term = linux_raw_sys::general::SIGTERM,
+    pub const Term: Self = Self(linux_raw_sys::general::SIGTERM as _);
     /// `SIGSTKFLT`
     #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
-    Stkflt = linux_raw_sys::general::SIGSTKFLT,
+    pub const Stkflt: Self = Self(linux_raw_sys::general::SIGSTKFLT as _);
     /// `SIGCHLD`
     #[doc(alias = "Chld")]
-    Child = linux_raw_sys::general::SIGCHLD,
+    pub const Child: Self = Self(linux_raw_sys::general::SIGCHLD as _);
     /// `SIGCONT`
-    Cont = linux_raw_sys::general::SIGCONT,
+    pub const Cont: Self = Self(linux_raw_sys::general::SIGCONT as _);
     /// `SIGSTOP`
-    Stop = linux_raw_sys::general::SIGSTOP,
+    pub const Stop: Self = Self(linux_raw_sys::general::SIGSTOP as _);
     /// `SIGTSTP`
-    Tstp = linux_raw_sys::general::SIGTSTP,
+    pub const Tstp: Self = Self(linux_raw_sys::general::SIGTSTP as _);
     /// `SIGTTIN`
-    Ttin = linux_raw_sys::general::SIGTTIN,
+    pub const Ttin: Self = Self(linux_raw_sys::general::SIGTTIN as _);
     /// `SIGTTOU`
-    Ttou = linux_raw_sys::general::SIGTTOU,
+    pub const Ttou: Self = Self(linux_raw_sys::general::SIGTTOU as _);
     /// `SIGURG`
-    Urg = linux_raw_sys::general::SIGURG,
+    pub const Urg: Self = Self(linux_raw_sys::general::SIGURG as _);
     /// `SIGXCPU`
-    Xcpu = linux_raw_sys::general::SIGXCPU,
+    pub const Xcpu: Self = Self(linux_raw_sys::general::SIGXCPU as _);
     /// `SIGXFSZ`
-    Xfsz = linux_raw_sys::general::SIGXFSZ,
+    pub const Xfsz: Self = Self(linux_raw_sys::general::SIGXFSZ as _);
     /// `SIGVTALRM`
     #[doc(alias = "Vtalrm")]
-    Vtalarm = linux_raw_sys::general::SIGVTALRM,
+    pub const Vtalarm: Self = Self(linux_raw_sys::general::SIGVTALRM as _);
     /// `SIGPROF`
-    Prof = linux_raw_sys::general::SIGPROF,
+    pub const Prof: Self = Self(linux_raw_sys::general::SIGPROF as _);
     /// `SIGWINCH`
-    Winch = linux_raw_sys::general::SIGWINCH,
+    pub const Winch: Self = Self(linux_raw_sys::general::SIGWINCH as _);
     /// `SIGIO`, aka `SIGPOLL`
     #[doc(alias = "Poll")]
-    Io = linux_raw_sys::general::SIGIO,
+    pub const Io: Self = Self(linux_raw_sys::general::SIGIO as _);
     /// `SIGPWR`
     #[doc(alias = "Pwr")]
-    Power = linux_raw_sys::general::SIGPWR,
+    pub const Power: Self = Self(linux_raw_sys::general::SIGPWR as _);
     /// `SIGSYS`, aka `SIGUNUSED`
     #[doc(alias = "Unused")]
-    Sys = linux_raw_sys::general::SIGSYS,
+    pub const Sys: Self = Self(linux_raw_sys::general::SIGSYS as _);
     /// `SIGRTMIN`
-    Rtmin = linux_raw_sys::general::SIGRTMIN,
+    pub const Rtmin: Self = Self(linux_raw_sys::general::SIGRTMIN as _);
 }
 
 impl Signal {
@@ -202,9 +209,105 @@ impl Signal {
             linux_raw_sys::general::SIGPWR => Some(Self::Power),
             linux_raw_sys::general::SIGSYS => Some(Self::Sys),
             linux_raw_sys::general::SIGRTMIN => Some(Self::Rtmin),
+            _ if (Self::RT_RANGE_START..=Self::RT_RANGE_END).contains(&sig) => Some(Self(sig)),
             _ => None,
         }
     }
+
+    /// Return the raw signal number.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+
+    /// The first signal number reserved for real-time signals by the kernel.
+    const RT_RANGE_START: i32 = linux_raw_sys::general::SIGRTMIN as i32;
+    /// The last valid signal number.
+    const RT_RANGE_END: i32 = linux_raw_sys::general::_NSIG as i32;
+
+    /// Return the lowest real-time signal available to the application,
+    /// `SIGRTMIN`.
+    ///
+    /// This is the kernel's `SIGRTMIN`. If the process also uses a libc, it
+    /// may reserve the lowest few real-time signals for its own use.
+    #[inline]
+    pub const fn rt_min() -> Self {
+        Self(Self::RT_RANGE_START)
+    }
+
+    /// Return the highest real-time signal available to the application,
+    /// `SIGRTMAX`.
+    #[inline]
+    pub const fn rt_max() -> Self {
+        Self(Self::RT_RANGE_END)
+    }
+
+    /// Return the real-time signal `SIGRTMIN + n`, if it's no greater than
+    /// `SIGRTMAX`.
+    #[inline]
+    pub fn rt(n: u32) -> Option<Self> {
+        let min = Self::rt_min().as_raw();
+        let max = Self::rt_max().as_raw();
+        if n <= (max - min) as u32 {
+            Some(Self(min + n as i32))
+        } else {
+            None
+        }
+    }
+}
+
+impl core::fmt::Debug for Signal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match *self {
+            Self::Hup => "Hup",
+            Self::Int => "Int",
+            Self::Quit => "Quit",
+            Self::Ill => "Ill",
+            Self::Trap => "Trap",
+            Self::Abort => "Abort",
+            Self::Bus => "Bus",
+            Self::Fpe => "Fpe",
+            Self::Kill => "Kill",
+            Self::Usr1 => "Usr1",
+            Self::Segv => "Segv",
+            Self::Usr2 => "Usr2",
+            Self::Pipe => "Pipe",
+            Self::Alarm => "Alarm",
+            Self::Term => "Term",
+            #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+            Self::Stkflt => "Stkflt",
+            Self::Child => "Child",
+            Self::Cont => "Cont",
+            Self::Stop => "Stop",
+            Self::Tstp => "Tstp",
+            Self::Ttin => "Ttin",
+            Self::Ttou => "Ttou",
+            Self::Urg => "Urg",
+            Self::Xcpu => "Xcpu",
+            Self::Xfsz => "Xfsz",
+            Self::Vtalarm => "Vtalarm",
+            Self::Prof => "Prof",
+            Self::Winch => "Winch",
+            Self::Io => "Io",
+            Self::Power => "Power",
+            Self::Sys => "Sys",
+            Self::Rtmin => "Rtmin",
+            _ => return write!(f, "Signal({})", self.0),
+        };
+        f.write_str(name)
+    }
+}
+
+bitflags::bitflags! {
+    /// `SFD_*` flags for use with [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::process::signalfd
+    pub struct SignalfdFlags: c::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
 }
 
 /// `EXIT_SUCCESS`
@@ -0,0 +1,83 @@
+//! linux_raw syscalls supporting `rustix::pty`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::super::conv::{by_ref, c_uint, ret, ret_owned_fd};
+use super::types::OpenptFlags;
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::fs::{Mode, OFlags};
+use crate::io;
+use crate::path::DecInt;
+use core::mem::MaybeUninit;
+use linux_raw_sys::ioctl::{TIOCGPTN, TIOCGPTPEER, TIOCSPTLCK};
+
+#[inline]
+pub(crate) fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    // On Linux, `posix_openpt` is just an `open` of the multiplexer device.
+    super::super::fs::syscalls::open(
+        cstr!("/dev/ptmx"),
+        OFlags::from_bits_truncate(flags.bits()),
+        Mode::empty(),
+    )
+}
+
+#[inline]
+pub(crate) fn grantpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    // With devpts, the kernel sets the user side's owner and permissions
+    // itself, so there's nothing to do other than check that `fd` is a
+    // controller.
+    ioctl_tiocgptn(fd).map(|_| ())
+}
+
+#[inline]
+pub(crate) fn unlockpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    let unlock: u32 = 0;
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(TIOCSPTLCK),
+            by_ref(&unlock)
+        ))
+    }
+}
+
+pub(crate) fn ptsname(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+    let index = DecInt::new(ioctl_tiocgptn(fd)?);
+    let prefix = b"/dev/pts/";
+    let len = prefix.len() + index.as_bytes().len();
+
+    // Leave space for the NUL terminator.
+    if len >= buf.len() {
+        return Err(io::Errno::RANGE);
+    }
+    buf[..prefix.len()].copy_from_slice(prefix);
+    buf[prefix.len()..len].copy_from_slice(index.as_bytes());
+    buf[len] = b'\0';
+    Ok(len)
+}
+
+#[inline]
+fn ioctl_tiocgptn(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    unsafe {
+        let mut result = MaybeUninit::<u32>::uninit();
+        ret(syscall!(__NR_ioctl, fd, c_uint(TIOCGPTN), &mut result))?;
+        Ok(result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn ioctl_tiocgptpeer(fd: BorrowedFd<'_>, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(TIOCGPTPEER),
+            c_uint(flags.bits())
+        ))
+    }
+}
@@ -0,0 +1,19 @@
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `O_*` flags for use with [`openpt`] and [`ioctl_tiocgptpeer`].
+    ///
+    /// [`openpt`]: crate::pty::openpt
+    /// [`ioctl_tiocgptpeer`]: crate::pty::ioctl_tiocgptpeer
+    pub struct OpenptFlags: c::c_uint {
+        /// `O_RDWR`
+        const RDWR = linux_raw_sys::general::O_RDWR;
+
+        /// `O_NOCTTY`
+        const NOCTTY = linux_raw_sys::general::O_NOCTTY;
+
+        /// `O_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+    }
+}
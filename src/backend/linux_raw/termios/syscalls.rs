@@ -11,16 +11,28 @@ use crate::fd::BorrowedFd;
 use crate::io;
 use crate::process::{Pid, RawNonZeroPid};
 use crate::termios::{
-    Action, OptionalActions, QueueSelector, Termios, Winsize, BRKINT, CBAUD, CS8, CSIZE, ECHO,
-    ECHONL, ICANON, ICRNL, IEXTEN, IGNBRK, IGNCR, INLCR, ISIG, ISTRIP, IXON, OPOST, PARENB, PARMRK,
-    VMIN, VTIME,
+    speed_code, Action, ControlModes, InputModes, LocalModes, OptionalActions, OutputModes,
+    QueueSelector, SpecialCodes, Termios, Winsize,
 };
 #[cfg(feature = "procfs")]
 use crate::{ffi::CStr, fs::FileType, path::DecInt};
 use core::mem::MaybeUninit;
-use linux_raw_sys::general::__kernel_pid_t;
+use linux_raw_sys::general::{__kernel_pid_t, BOTHER, CBAUD, CIBAUD, IBSHIFT, TCSANOW};
 use linux_raw_sys::ioctl::{
-    TCFLSH, TCGETS, TCSBRK, TCSETS, TCXONC, TIOCGPGRP, TIOCGSID, TIOCGWINSZ, TIOCSPGRP, TIOCSWINSZ,
+    TCFLSH, TCSBRK, TCXONC, TIOCGPGRP, TIOCGSID, TIOCGWINSZ, TIOCSPGRP, TIOCSWINSZ,
+};
+
+// On PowerPC, `termios` already has `c_ispeed` and `c_ospeed`, so there's no
+// separate `termios2`.
+#[cfg(target_arch = "powerpc64")]
+use linux_raw_sys::{
+    general::termios as termios2,
+    ioctl::{TCGETS as TCGETS2, TCSETS as TCSETS2},
+};
+#[cfg(not(target_arch = "powerpc64"))]
+use linux_raw_sys::{
+    general::termios2,
+    ioctl::{TCGETS2, TCSETS2},
 };
 
 #[inline]
@@ -34,34 +46,26 @@ pub(crate) fn tcgetwinsize(fd: BorrowedFd<'_>) -> io::Result<Winsize> {
 
 #[inline]
 pub(crate) fn tcgetattr(fd: BorrowedFd<'_>) -> io::Result<Termios> {
-    unsafe {
-        let mut result = MaybeUninit::<Termios>::uninit();
-        ret(syscall!(__NR_ioctl, fd, c_uint(TCGETS), &mut result))?;
-        Ok(result.assume_init())
-    }
-}
+    let raw = unsafe {
+        let mut result = MaybeUninit::<termios2>::uninit();
+        ret(syscall!(__NR_ioctl, fd, c_uint(TCGETS2), &mut result))?;
+        result.assume_init()
+    };
 
-#[inline]
-#[cfg(any(
-    target_arch = "x86",
-    target_arch = "x86_64",
-    target_arch = "x32",
-    target_arch = "riscv64",
-    target_arch = "aarch64",
-    target_arch = "arm",
-    target_arch = "mips",
-    target_arch = "mips64",
-))]
-pub(crate) fn tcgetattr2(fd: BorrowedFd<'_>) -> io::Result<crate::termios::Termios2> {
+    // Keep any flags we don't know about, so that `tcsetattr` preserves them.
+    // The speed bits are represented separately, by `input_speed` and
+    // `output_speed`.
     unsafe {
-        let mut result = MaybeUninit::<crate::termios::Termios2>::uninit();
-        ret(syscall!(
-            __NR_ioctl,
-            fd,
-            c_uint(linux_raw_sys::ioctl::TCGETS2),
-            &mut result
-        ))?;
-        Ok(result.assume_init())
+        Ok(Termios {
+            input_modes: InputModes::from_bits_unchecked(raw.c_iflag),
+            output_modes: OutputModes::from_bits_unchecked(raw.c_oflag),
+            control_modes: ControlModes::from_bits_unchecked(raw.c_cflag & !(CBAUD | CIBAUD)),
+            local_modes: LocalModes::from_bits_unchecked(raw.c_lflag),
+            line_discipline: raw.c_line,
+            special_codes: SpecialCodes(raw.c_cc),
+            input_speed: raw.c_ispeed,
+            output_speed: raw.c_ospeed,
+        })
     }
 }
 
@@ -85,44 +89,40 @@ pub(crate) fn tcsetattr(
     termios: &Termios,
 ) -> io::Result<()> {
     // Translate from `optional_actions` into an ioctl request code. On MIPS,
-    // `optional_actions` already has `TCGETS` added to it.
-    let request = if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
-        optional_actions as u32
+    // `optional_actions` already has `TCSETS` added to it, so subtract
+    // `TCSANOW` to get the offset from `TCSETS2`.
+    let request = TCSETS2 + (optional_actions as u32 - TCSANOW);
+
+    // Use the `B*` codes where possible, for the benefit of anything reading
+    // the flags with the older `TCGETS`, and `BOTHER` otherwise. As with
+    // `cfsetspeed`, an input code of zero means the input speed is the same
+    // as the output speed.
+    let output_code = speed_code(termios.output_speed).unwrap_or(BOTHER);
+    let input_code = if termios.input_speed == termios.output_speed {
+        0
     } else {
-        TCSETS + optional_actions as u32
+        speed_code(termios.input_speed).unwrap_or(BOTHER)
+    };
+
+    let raw = termios2 {
+        c_iflag: termios.input_modes.bits(),
+        c_oflag: termios.output_modes.bits(),
+        c_cflag: (termios.control_modes.bits() & !(CBAUD | CIBAUD))
+            | output_code
+            | (input_code << IBSHIFT),
+        c_lflag: termios.local_modes.bits(),
+        c_line: termios.line_discipline,
+        c_cc: termios.special_codes.0,
+        c_ispeed: termios.input_speed,
+        c_ospeed: termios.output_speed,
     };
-    unsafe {
-        ret(syscall_readonly!(
-            __NR_ioctl,
-            fd,
-            c_uint(request as u32),
-            by_ref(termios)
-        ))
-    }
-}
 
-#[inline]
-#[cfg(any(
-    target_arch = "x86",
-    target_arch = "x86_64",
-    target_arch = "x32",
-    target_arch = "riscv64",
-    target_arch = "aarch64",
-    target_arch = "arm",
-    target_arch = "mips",
-    target_arch = "mips64",
-))]
-pub(crate) fn tcsetattr2(
-    fd: BorrowedFd,
-    optional_actions: OptionalActions,
-    termios: &crate::termios::Termios2,
-) -> io::Result<()> {
     unsafe {
         ret(syscall_readonly!(
             __NR_ioctl,
             fd,
-            c_uint(linux_raw_sys::ioctl::TCSETS2 + optional_actions as u32),
-            by_ref(termios)
+            c_uint(request),
+            by_ref(&raw)
         ))
     }
 }
@@ -191,69 +191,6 @@ pub(crate) fn tcsetpgrp(fd: BorrowedFd<'_>, pid: Pid) -> io::Result<()> {
     unsafe { ret(syscall!(__NR_ioctl, fd, c_uint(TIOCSPGRP), pid)) }
 }
 
-#[inline]
-#[must_use]
-#[allow(clippy::missing_const_for_fn)]
-pub(crate) fn cfgetospeed(termios: &Termios) -> u32 {
-    termios.c_cflag & CBAUD
-}
-
-#[inline]
-#[must_use]
-#[allow(clippy::missing_const_for_fn)]
-pub(crate) fn cfgetispeed(termios: &Termios) -> u32 {
-    termios.c_cflag & CBAUD
-}
-
-#[inline]
-pub(crate) fn cfmakeraw(termios: &mut Termios) {
-    // From the Linux [`cfmakeraw` manual page]:
-    //
-    // [`cfmakeraw` manual page]: https://man7.org/linux/man-pages/man3/cfmakeraw.3.html
-    termios.c_iflag &= !(IGNBRK | BRKINT | PARMRK | ISTRIP | INLCR | IGNCR | ICRNL | IXON);
-    termios.c_oflag &= !OPOST;
-    termios.c_lflag &= !(ECHO | ECHONL | ICANON | ISIG | IEXTEN);
-    termios.c_cflag &= !(CSIZE | PARENB);
-    termios.c_cflag |= CS8;
-
-    // Musl and glibc also do these:
-    termios.c_cc[VMIN] = 1;
-    termios.c_cc[VTIME] = 0;
-}
-
-#[inline]
-pub(crate) fn cfsetospeed(termios: &mut Termios, speed: u32) -> io::Result<()> {
-    if (speed & !CBAUD) != 0 {
-        return Err(io::Errno::INVAL);
-    }
-    termios.c_cflag &= !CBAUD;
-    termios.c_cflag |= speed;
-    Ok(())
-}
-
-#[inline]
-pub(crate) fn cfsetispeed(termios: &mut Termios, speed: u32) -> io::Result<()> {
-    if speed == 0 {
-        return Ok(());
-    }
-    if (speed & !CBAUD) != 0 {
-        return Err(io::Errno::INVAL);
-    }
-    termios.c_cflag &= !CBAUD;
-    termios.c_cflag |= speed;
-    Ok(())
-}
-
-#[inline]
-pub(crate) fn cfsetspeed(termios: &mut Termios, speed: u32) -> io::Result<()> {
-    if (speed & !CBAUD) != 0 {
-        return Err(io::Errno::INVAL);
-    }
-    termios.c_cflag &= !CBAUD;
-    termios.c_cflag |= speed;
-    Ok(())
-}
-
 #[inline]
 pub(crate) fn isatty(fd: BorrowedFd<'_>) -> bool {
     // On error, Linux will return either `EINVAL` (2.6.32) or `ENOTTY`
@@ -1,4 +1,5 @@
-use super::super::c;
+use crate::termios::SpecialCodeIndex;
+use bitflags::bitflags;
 
 /// `TCSA*` values for use with [`tcsetattr`].
 ///
@@ -62,231 +63,342 @@ pub enum Action {
     IOn = linux_raw_sys::general::TCION,
 }
 
-/// `struct termios` for use with [`tcgetattr`] and [`tcsetattr`].
-///
-/// [`tcgetattr`]: crate::termios::tcgetattr
-/// [`tcsetattr`]: crate::termios::tcsetattr
-#[doc(alias = "termios")]
-pub type Termios = linux_raw_sys::general::termios;
-
-/// `struct termios2` for use with [`tcgetattr2`] and [`tcsetattr2`].
-///
-/// [`tcgetattr2`]: crate::termios::tcgetattr2
-/// [`tcsetattr2`]: crate::termios::tcsetattr2
-#[cfg(any(
-    target_arch = "x86",
-    target_arch = "x86_64",
-    target_arch = "x32",
-    target_arch = "riscv64",
-    target_arch = "aarch64",
-    target_arch = "arm",
-    target_arch = "mips",
-    target_arch = "mips64",
-))]
-#[doc(alias = "termios2")]
-pub type Termios2 = linux_raw_sys::general::termios2;
-
 /// `struct winsize` for use with [`tcgetwinsize`].
 ///
 /// [`tcgetwinsize`]: crate::termios::tcgetwinsize
 #[doc(alias = "winsize")]
 pub type Winsize = linux_raw_sys::general::winsize;
 
-/// `tcflag_t`—A type for the flags fields of [`Termios`].
+/// `tcflag_t`—The type underlying the flags fields of [`Termios`].
+///
+/// [`Termios`]: crate::termios::Termios
 #[doc(alias = "tcflag_t")]
 pub type Tcflag = linux_raw_sys::general::tcflag_t;
 
-/// `speed_t`—A return type for [`cfsetspeed`] and similar.
+/// `speed_t`—A speed code, such as [`B9600`], for use with [`speed_value`].
 ///
-/// [`cfsetspeed`]: crate::termios::cfsetspeed
+/// [`speed_value`]: crate::termios::speed_value
 #[doc(alias = "speed_t")]
 pub type Speed = linux_raw_sys::general::speed_t;
 
-/// `VINTR`
-pub const VINTR: usize = linux_raw_sys::general::VINTR as usize;
+/// `NCCS`—The number of special codes in a [`Termios`].
+///
+/// [`Termios`]: crate::termios::Termios
+pub(crate) const NCCS: usize = linux_raw_sys::general::NCCS as usize;
+
+bitflags! {
+    /// Flags controlling terminal input.
+    ///
+    /// This is the `c_iflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.input_modes
+    pub struct InputModes: Tcflag {
+        /// `IGNBRK`
+        const IGNBRK = linux_raw_sys::general::IGNBRK;
+
+        /// `BRKINT`
+        const BRKINT = linux_raw_sys::general::BRKINT;
+
+        /// `IGNPAR`
+        const IGNPAR = linux_raw_sys::general::IGNPAR;
+
+        /// `PARMRK`
+        const PARMRK = linux_raw_sys::general::PARMRK;
+
+        /// `INPCK`
+        const INPCK = linux_raw_sys::general::INPCK;
+
+        /// `ISTRIP`
+        const ISTRIP = linux_raw_sys::general::ISTRIP;
+
+        /// `INLCR`
+        const INLCR = linux_raw_sys::general::INLCR;
+
+        /// `IGNCR`
+        const IGNCR = linux_raw_sys::general::IGNCR;
+
+        /// `ICRNL`
+        const ICRNL = linux_raw_sys::general::ICRNL;
+
+        /// `IUCLC`
+        const IUCLC = linux_raw_sys::general::IUCLC;
+
+        /// `IXON`
+        const IXON = linux_raw_sys::general::IXON;
+
+        /// `IXANY`
+        const IXANY = linux_raw_sys::general::IXANY;
+
+        /// `IXOFF`
+        const IXOFF = linux_raw_sys::general::IXOFF;
 
-/// `VQUIT`
-pub const VQUIT: usize = linux_raw_sys::general::VQUIT as usize;
+        /// `IMAXBEL`
+        const IMAXBEL = linux_raw_sys::general::IMAXBEL;
 
-/// `VERASE`
-pub const VERASE: usize = linux_raw_sys::general::VERASE as usize;
+        /// `IUTF8`
+        const IUTF8 = linux_raw_sys::general::IUTF8;
+    }
+}
+
+bitflags! {
+    /// Flags controlling terminal output.
+    ///
+    /// This is the `c_oflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.output_modes
+    pub struct OutputModes: Tcflag {
+        /// `OPOST`
+        const OPOST = linux_raw_sys::general::OPOST;
+
+        /// `OLCUC`
+        const OLCUC = linux_raw_sys::general::OLCUC;
+
+        /// `ONLCR`
+        const ONLCR = linux_raw_sys::general::ONLCR;
+
+        /// `OCRNL`
+        const OCRNL = linux_raw_sys::general::OCRNL;
+
+        /// `ONOCR`
+        const ONOCR = linux_raw_sys::general::ONOCR;
+
+        /// `ONLRET`
+        const ONLRET = linux_raw_sys::general::ONLRET;
+
+        /// `OFILL`
+        const OFILL = linux_raw_sys::general::OFILL;
+
+        /// `OFDEL`
+        const OFDEL = linux_raw_sys::general::OFDEL;
+
+        /// `NLDLY`
+        const NLDLY = linux_raw_sys::general::NLDLY;
 
-/// `VKILL`
-pub const VKILL: usize = linux_raw_sys::general::VKILL as usize;
+        /// `NL0`
+        const NL0 = linux_raw_sys::general::NL0;
 
-/// `VEOF`
-pub const VEOF: usize = linux_raw_sys::general::VEOF as usize;
+        /// `NL1`
+        const NL1 = linux_raw_sys::general::NL1;
 
-/// `VTIME`
-pub const VTIME: usize = linux_raw_sys::general::VTIME as usize;
+        /// `CRDLY`
+        const CRDLY = linux_raw_sys::general::CRDLY;
 
-/// `VMIN`
-pub const VMIN: usize = linux_raw_sys::general::VMIN as usize;
+        /// `CR0`
+        const CR0 = linux_raw_sys::general::CR0;
 
-/// `VSWTC`
-pub const VSWTC: usize = linux_raw_sys::general::VSWTC as usize;
+        /// `CR1`
+        const CR1 = linux_raw_sys::general::CR1;
 
-/// `VSTART`
-pub const VSTART: usize = linux_raw_sys::general::VSTART as usize;
+        /// `CR2`
+        const CR2 = linux_raw_sys::general::CR2;
 
-/// `VSTOP`
-pub const VSTOP: usize = linux_raw_sys::general::VSTOP as usize;
+        /// `CR3`
+        const CR3 = linux_raw_sys::general::CR3;
 
-/// `VSUSP`
-pub const VSUSP: usize = linux_raw_sys::general::VSUSP as usize;
+        /// `TABDLY`
+        const TABDLY = linux_raw_sys::general::TABDLY;
 
-/// `VEOL`
-pub const VEOL: usize = linux_raw_sys::general::VEOL as usize;
+        /// `TAB0`
+        const TAB0 = linux_raw_sys::general::TAB0;
 
-/// `VREPRINT`
-pub const VREPRINT: usize = linux_raw_sys::general::VREPRINT as usize;
+        /// `TAB1`
+        const TAB1 = linux_raw_sys::general::TAB1;
 
-/// `VDISCARD`
-pub const VDISCARD: usize = linux_raw_sys::general::VDISCARD as usize;
+        /// `TAB2`
+        const TAB2 = linux_raw_sys::general::TAB2;
 
-/// `VWERASE`
-pub const VWERASE: usize = linux_raw_sys::general::VWERASE as usize;
+        /// `TAB3`
+        const TAB3 = linux_raw_sys::general::TAB3;
 
-/// `VLNEXT`
-pub const VLNEXT: usize = linux_raw_sys::general::VLNEXT as usize;
+        /// `XTABS`
+        const XTABS = linux_raw_sys::general::XTABS;
 
-/// `VEOL2`
-pub const VEOL2: usize = linux_raw_sys::general::VEOL2 as usize;
+        /// `BSDLY`
+        const BSDLY = linux_raw_sys::general::BSDLY;
 
-/// `IGNBRK`
-pub const IGNBRK: c::c_uint = linux_raw_sys::general::IGNBRK;
+        /// `BS0`
+        const BS0 = linux_raw_sys::general::BS0;
 
-/// `BRKINT`
-pub const BRKINT: c::c_uint = linux_raw_sys::general::BRKINT;
+        /// `BS1`
+        const BS1 = linux_raw_sys::general::BS1;
 
-/// `IGNPAR`
-pub const IGNPAR: c::c_uint = linux_raw_sys::general::IGNPAR;
+        /// `FFDLY`
+        const FFDLY = linux_raw_sys::general::FFDLY;
 
-/// `PARMRK`
-pub const PARMRK: c::c_uint = linux_raw_sys::general::PARMRK;
+        /// `FF0`
+        const FF0 = linux_raw_sys::general::FF0;
 
-/// `INPCK`
-pub const INPCK: c::c_uint = linux_raw_sys::general::INPCK;
+        /// `FF1`
+        const FF1 = linux_raw_sys::general::FF1;
 
-/// `ISTRIP`
-pub const ISTRIP: c::c_uint = linux_raw_sys::general::ISTRIP;
+        /// `VTDLY`
+        const VTDLY = linux_raw_sys::general::VTDLY;
 
-/// `INLCR`
-pub const INLCR: c::c_uint = linux_raw_sys::general::INLCR;
+        /// `VT0`
+        const VT0 = linux_raw_sys::general::VT0;
 
-/// `IGNCR`
-pub const IGNCR: c::c_uint = linux_raw_sys::general::IGNCR;
+        /// `VT1`
+        const VT1 = linux_raw_sys::general::VT1;
+    }
+}
+
+bitflags! {
+    /// Flags controlling terminal hardware, other than the speed.
+    ///
+    /// This is the `c_cflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.control_modes
+    pub struct ControlModes: Tcflag {
+        /// `CSIZE`
+        const CSIZE = linux_raw_sys::general::CSIZE;
+
+        /// `CS5`
+        const CS5 = linux_raw_sys::general::CS5;
+
+        /// `CS6`
+        const CS6 = linux_raw_sys::general::CS6;
+
+        /// `CS7`
+        const CS7 = linux_raw_sys::general::CS7;
 
-/// `ICRNL`
-pub const ICRNL: c::c_uint = linux_raw_sys::general::ICRNL;
+        /// `CS8`
+        const CS8 = linux_raw_sys::general::CS8;
 
-/// `IUCLC`
-pub const IUCLC: c::c_uint = linux_raw_sys::general::IUCLC;
+        /// `CSTOPB`
+        const CSTOPB = linux_raw_sys::general::CSTOPB;
 
-/// `IXON`
-pub const IXON: c::c_uint = linux_raw_sys::general::IXON;
+        /// `CREAD`
+        const CREAD = linux_raw_sys::general::CREAD;
 
-/// `IXANY`
-pub const IXANY: c::c_uint = linux_raw_sys::general::IXANY;
+        /// `PARENB`
+        const PARENB = linux_raw_sys::general::PARENB;
 
-/// `IXOFF`
-pub const IXOFF: c::c_uint = linux_raw_sys::general::IXOFF;
+        /// `PARODD`
+        const PARODD = linux_raw_sys::general::PARODD;
 
-/// `IMAXBEL`
-pub const IMAXBEL: c::c_uint = linux_raw_sys::general::IMAXBEL;
+        /// `HUPCL`
+        const HUPCL = linux_raw_sys::general::HUPCL;
 
-/// `IUTF8`
-pub const IUTF8: c::c_uint = linux_raw_sys::general::IUTF8;
+        /// `CLOCAL`
+        const CLOCAL = linux_raw_sys::general::CLOCAL;
 
-/// `OPOST`
-pub const OPOST: c::c_uint = linux_raw_sys::general::OPOST;
+        /// `CRTSCTS`
+        const CRTSCTS = linux_raw_sys::general::CRTSCTS;
 
-/// `OLCUC`
-pub const OLCUC: c::c_uint = linux_raw_sys::general::OLCUC;
+        /// `CMSPAR`
+        const CMSPAR = linux_raw_sys::general::CMSPAR;
+    }
+}
+
+bitflags! {
+    /// Flags controlling “local” terminal modes.
+    ///
+    /// This is the `c_lflag` field of a [`Termios`].
+    ///
+    /// [`Termios`]: crate::termios::Termios#structfield.local_modes
+    pub struct LocalModes: Tcflag {
+        /// `ISIG`
+        const ISIG = linux_raw_sys::general::ISIG;
+
+        /// `ICANON`
+        const ICANON = linux_raw_sys::general::ICANON;
 
-/// `ONLCR`
-pub const ONLCR: c::c_uint = linux_raw_sys::general::ONLCR;
+        /// `XCASE`
+        const XCASE = linux_raw_sys::general::XCASE;
 
-/// `OCRNL`
-pub const OCRNL: c::c_uint = linux_raw_sys::general::OCRNL;
+        /// `ECHO`
+        const ECHO = linux_raw_sys::general::ECHO;
 
-/// `ONOCR`
-pub const ONOCR: c::c_uint = linux_raw_sys::general::ONOCR;
+        /// `ECHOE`
+        const ECHOE = linux_raw_sys::general::ECHOE;
 
-/// `ONLRET`
-pub const ONLRET: c::c_uint = linux_raw_sys::general::ONLRET;
+        /// `ECHOK`
+        const ECHOK = linux_raw_sys::general::ECHOK;
 
-/// `OFILL`
-pub const OFILL: c::c_uint = linux_raw_sys::general::OFILL;
+        /// `ECHONL`
+        const ECHONL = linux_raw_sys::general::ECHONL;
 
-/// `OFDEL`
-pub const OFDEL: c::c_uint = linux_raw_sys::general::OFDEL;
+        /// `ECHOCTL`
+        const ECHOCTL = linux_raw_sys::general::ECHOCTL;
 
-/// `NLDLY`
-pub const NLDLY: c::c_uint = linux_raw_sys::general::NLDLY;
+        /// `ECHOPRT`
+        const ECHOPRT = linux_raw_sys::general::ECHOPRT;
 
-/// `NL0`
-pub const NL0: c::c_uint = linux_raw_sys::general::NL0;
+        /// `ECHOKE`
+        const ECHOKE = linux_raw_sys::general::ECHOKE;
 
-/// `NL1`
-pub const NL1: c::c_uint = linux_raw_sys::general::NL1;
+        /// `FLUSHO`
+        const FLUSHO = linux_raw_sys::general::FLUSHO;
 
-/// `CRDLY`
-pub const CRDLY: c::c_uint = linux_raw_sys::general::CRDLY;
+        /// `NOFLSH`
+        const NOFLSH = linux_raw_sys::general::NOFLSH;
 
-/// `CR0`
-pub const CR0: c::c_uint = linux_raw_sys::general::CR0;
+        /// `TOSTOP`
+        const TOSTOP = linux_raw_sys::general::TOSTOP;
 
-/// `CR1`
-pub const CR1: c::c_uint = linux_raw_sys::general::CR1;
+        /// `PENDIN`
+        const PENDIN = linux_raw_sys::general::PENDIN;
 
-/// `CR2`
-pub const CR2: c::c_uint = linux_raw_sys::general::CR2;
+        /// `IEXTEN`
+        const IEXTEN = linux_raw_sys::general::IEXTEN;
+
+        /// `EXTPROC`
+        const EXTPROC = linux_raw_sys::general::EXTPROC;
+    }
+}
 
-/// `CR3`
-pub const CR3: c::c_uint = linux_raw_sys::general::CR3;
+impl SpecialCodeIndex {
+    /// `VINTR`
+    pub const VINTR: Self = Self(linux_raw_sys::general::VINTR as usize);
 
-/// `TABDLY`
-pub const TABDLY: c::c_uint = linux_raw_sys::general::TABDLY;
+    /// `VQUIT`
+    pub const VQUIT: Self = Self(linux_raw_sys::general::VQUIT as usize);
 
-/// `TAB0`
-pub const TAB0: c::c_uint = linux_raw_sys::general::TAB0;
+    /// `VERASE`
+    pub const VERASE: Self = Self(linux_raw_sys::general::VERASE as usize);
 
-/// `TAB1`
-pub const TAB1: c::c_uint = linux_raw_sys::general::TAB1;
+    /// `VKILL`
+    pub const VKILL: Self = Self(linux_raw_sys::general::VKILL as usize);
 
-/// `TAB2`
-pub const TAB2: c::c_uint = linux_raw_sys::general::TAB2;
+    /// `VEOF`
+    pub const VEOF: Self = Self(linux_raw_sys::general::VEOF as usize);
 
-/// `TAB3`
-pub const TAB3: c::c_uint = linux_raw_sys::general::TAB3;
+    /// `VTIME`
+    pub const VTIME: Self = Self(linux_raw_sys::general::VTIME as usize);
 
-/// `BSDLY`
-pub const BSDLY: c::c_uint = linux_raw_sys::general::BSDLY;
+    /// `VMIN`
+    pub const VMIN: Self = Self(linux_raw_sys::general::VMIN as usize);
 
-/// `BS0`
-pub const BS0: c::c_uint = linux_raw_sys::general::BS0;
+    /// `VSWTC`
+    pub const VSWTC: Self = Self(linux_raw_sys::general::VSWTC as usize);
 
-/// `BS1`
-pub const BS1: c::c_uint = linux_raw_sys::general::BS1;
+    /// `VSTART`
+    pub const VSTART: Self = Self(linux_raw_sys::general::VSTART as usize);
 
-/// `FFDLY`
-pub const FFDLY: c::c_uint = linux_raw_sys::general::FFDLY;
+    /// `VSTOP`
+    pub const VSTOP: Self = Self(linux_raw_sys::general::VSTOP as usize);
 
-/// `FF0`
-pub const FF0: c::c_uint = linux_raw_sys::general::FF0;
+    /// `VSUSP`
+    pub const VSUSP: Self = Self(linux_raw_sys::general::VSUSP as usize);
 
-/// `FF1`
-pub const FF1: c::c_uint = linux_raw_sys::general::FF1;
+    /// `VEOL`
+    pub const VEOL: Self = Self(linux_raw_sys::general::VEOL as usize);
 
-/// `VTDLY`
-pub const VTDLY: c::c_uint = linux_raw_sys::general::VTDLY;
+    /// `VREPRINT`
+    pub const VREPRINT: Self = Self(linux_raw_sys::general::VREPRINT as usize);
 
-/// `VT0`
-pub const VT0: c::c_uint = linux_raw_sys::general::VT0;
+    /// `VDISCARD`
+    pub const VDISCARD: Self = Self(linux_raw_sys::general::VDISCARD as usize);
 
-/// `VT1`
-pub const VT1: c::c_uint = linux_raw_sys::general::VT1;
+    /// `VWERASE`
+    pub const VWERASE: Self = Self(linux_raw_sys::general::VWERASE as usize);
+
+    /// `VLNEXT`
+    pub const VLNEXT: Self = Self(linux_raw_sys::general::VLNEXT as usize);
+
+    /// `VEOL2`
+    pub const VEOL2: Self = Self(linux_raw_sys::general::VEOL2 as usize);
+}
 
 /// `B0`
 pub const B0: Speed = linux_raw_sys::general::B0;
@@ -384,112 +496,3 @@ pub const B3500000: Speed = linux_raw_sys::general::B3500000;
 /// `B4000000`
 #[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
 pub const B4000000: Speed = linux_raw_sys::general::B4000000;
-
-/// `BOTHER`
-pub const BOTHER: c::c_uint = linux_raw_sys::general::BOTHER;
-
-/// `CSIZE`
-pub const CSIZE: c::c_uint = linux_raw_sys::general::CSIZE;
-
-/// `CS5`
-pub const CS5: c::c_uint = linux_raw_sys::general::CS5;
-
-/// `CS6`
-pub const CS6: c::c_uint = linux_raw_sys::general::CS6;
-
-/// `CS7`
-pub const CS7: c::c_uint = linux_raw_sys::general::CS7;
-
-/// `CS8`
-pub const CS8: c::c_uint = linux_raw_sys::general::CS8;
-
-/// `CSTOPB`
-pub const CSTOPB: c::c_uint = linux_raw_sys::general::CSTOPB;
-
-/// `CREAD`
-pub const CREAD: c::c_uint = linux_raw_sys::general::CREAD;
-
-/// `PARENB`
-pub const PARENB: c::c_uint = linux_raw_sys::general::PARENB;
-
-/// `PARODD`
-pub const PARODD: c::c_uint = linux_raw_sys::general::PARODD;
-
-/// `HUPCL`
-pub const HUPCL: c::c_uint = linux_raw_sys::general::HUPCL;
-
-/// `CLOCAL`
-pub const CLOCAL: c::c_uint = linux_raw_sys::general::CLOCAL;
-
-/// `ISIG`
-pub const ISIG: c::c_uint = linux_raw_sys::general::ISIG;
-
-/// `ICANON`—A flag for the `c_lflag` field of [`Termios`] indicating
-/// canonical mode.
-pub const ICANON: Tcflag = linux_raw_sys::general::ICANON;
-
-/// `ECHO`
-pub const ECHO: c::c_uint = linux_raw_sys::general::ECHO;
-
-/// `ECHOE`
-pub const ECHOE: c::c_uint = linux_raw_sys::general::ECHOE;
-
-/// `ECHOK`
-pub const ECHOK: c::c_uint = linux_raw_sys::general::ECHOK;
-
-/// `ECHONL`
-pub const ECHONL: c::c_uint = linux_raw_sys::general::ECHONL;
-
-/// `NOFLSH`
-pub const NOFLSH: c::c_uint = linux_raw_sys::general::NOFLSH;
-
-/// `TOSTOP`
-pub const TOSTOP: c::c_uint = linux_raw_sys::general::TOSTOP;
-
-/// `IEXTEN`
-pub const IEXTEN: c::c_uint = linux_raw_sys::general::IEXTEN;
-
-/// `EXTA`
-pub const EXTA: c::c_uint = linux_raw_sys::general::EXTA;
-
-/// `EXTB`
-pub const EXTB: c::c_uint = linux_raw_sys::general::EXTB;
-
-/// `CBAUD`
-pub const CBAUD: c::c_uint = linux_raw_sys::general::CBAUD;
-
-/// `CBAUDEX`
-pub const CBAUDEX: c::c_uint = linux_raw_sys::general::CBAUDEX;
-
-/// `CIBAUD`
-pub const CIBAUD: c::c_uint = linux_raw_sys::general::CIBAUD;
-
-/// `CMSPAR`
-pub const CMSPAR: c::c_uint = linux_raw_sys::general::CMSPAR;
-
-/// `CRTSCTS`
-pub const CRTSCTS: c::c_uint = linux_raw_sys::general::CRTSCTS;
-
-/// `XCASE`
-pub const XCASE: c::c_uint = linux_raw_sys::general::XCASE;
-
-/// `ECHOCTL`
-pub const ECHOCTL: c::c_uint = linux_raw_sys::general::ECHOCTL;
-
-/// `ECHOPRT`
-pub const ECHOPRT: c::c_uint = linux_raw_sys::general::ECHOPRT;
-
-/// `ECHOKE`
-pub const ECHOKE: c::c_uint = linux_raw_sys::general::ECHOKE;
-
-/// `FLUSHO`
-pub const FLUSHO: c::c_uint = linux_raw_sys::general::FLUSHO;
-
-/// `PENDIN`
-pub const PENDIN: c::c_uint = linux_raw_sys::general::PENDIN;
-
-/// `EXTPROC`
-pub const EXTPROC: c::c_uint = linux_raw_sys::general::EXTPROC;
-
-/// `XTABS`
-pub const XTABS: c::c_uint = linux_raw_sys::general::XTABS;
@@ -0,0 +1,741 @@
+//! fanotify support for monitoring filesystem events
+
+use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr;
+
+use crate::backend::fs::syscalls;
+use crate::backend::io::syscalls::read_uninit;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::ffi::CStr;
+use crate::process::Pid;
+use crate::{io, path};
+
+bitflags::bitflags! {
+    /// `FAN_*` flags for use with [`fanotify_init`].
+    pub struct InitFlags: u32 {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = 0x0000_0002;
+        /// `FAN_CLASS_NOTIF`
+        const CLASS_NOTIF = 0x0000_0000;
+        /// `FAN_CLASS_CONTENT`
+        const CLASS_CONTENT = 0x0000_0004;
+        /// `FAN_CLASS_PRE_CONTENT`
+        const CLASS_PRE_CONTENT = 0x0000_0008;
+        /// `FAN_UNLIMITED_QUEUE`
+        const UNLIMITED_QUEUE = 0x0000_0010;
+        /// `FAN_UNLIMITED_MARKS`
+        const UNLIMITED_MARKS = 0x0000_0020;
+        /// `FAN_ENABLE_AUDIT`
+        const ENABLE_AUDIT = 0x0000_0040;
+        /// `FAN_REPORT_PIDFD` (since Linux 5.15)
+        const REPORT_PIDFD = 0x0000_0080;
+        /// `FAN_REPORT_TID`
+        const REPORT_TID = 0x0000_0100;
+        /// `FAN_REPORT_FID` (since Linux 5.1)
+        const REPORT_FID = 0x0000_0200;
+        /// `FAN_REPORT_DIR_FID` (since Linux 5.9)
+        const REPORT_DIR_FID = 0x0000_0400;
+        /// `FAN_REPORT_NAME` (since Linux 5.9)
+        const REPORT_NAME = 0x0000_0800;
+        /// `FAN_REPORT_TARGET_FID` (since Linux 5.17)
+        const REPORT_TARGET_FID = 0x0000_1000;
+        /// `FAN_REPORT_DFID_NAME`
+        const REPORT_DFID_NAME = Self::REPORT_DIR_FID.bits | Self::REPORT_NAME.bits;
+        /// `FAN_REPORT_DFID_NAME_TARGET`
+        const REPORT_DFID_NAME_TARGET =
+            Self::REPORT_DFID_NAME.bits | Self::REPORT_FID.bits | Self::REPORT_TARGET_FID.bits;
+    }
+}
+
+bitflags::bitflags! {
+    /// `O_*` flags for the file descriptors opened for events, for use with
+    /// [`fanotify_init`].
+    pub struct EventFlags: u32 {
+        /// `O_RDONLY`
+        const RDONLY = linux_raw_sys::general::O_RDONLY;
+        /// `O_WRONLY`
+        const WRONLY = linux_raw_sys::general::O_WRONLY;
+        /// `O_RDWR`
+        const RDWR = linux_raw_sys::general::O_RDWR;
+        /// `O_LARGEFILE`
+        const LARGEFILE = linux_raw_sys::general::O_LARGEFILE;
+        /// `O_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `O_APPEND`
+        const APPEND = linux_raw_sys::general::O_APPEND;
+        /// `O_DSYNC`
+        const DSYNC = linux_raw_sys::general::O_DSYNC;
+        /// `O_NOATIME`
+        const NOATIME = linux_raw_sys::general::O_NOATIME;
+        /// `O_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+        /// `O_SYNC`
+        const SYNC = linux_raw_sys::general::O_SYNC;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FAN_MARK_*` flags for use with [`fanotify_mark`].
+    pub struct MarkFlags: u32 {
+        /// `FAN_MARK_ADD`
+        const ADD = 0x0000_0001;
+        /// `FAN_MARK_REMOVE`
+        const REMOVE = 0x0000_0002;
+        /// `FAN_MARK_DONT_FOLLOW`
+        const DONT_FOLLOW = 0x0000_0004;
+        /// `FAN_MARK_ONLYDIR`
+        const ONLYDIR = 0x0000_0008;
+        /// `FAN_MARK_MOUNT`
+        const MOUNT = 0x0000_0010;
+        /// `FAN_MARK_IGNORED_MASK`
+        const IGNORED_MASK = 0x0000_0020;
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`
+        const IGNORED_SURV_MODIFY = 0x0000_0040;
+        /// `FAN_MARK_FLUSH`
+        const FLUSH = 0x0000_0080;
+        /// `FAN_MARK_FILESYSTEM` (since Linux 4.20)
+        const FILESYSTEM = 0x0000_0100;
+        /// `FAN_MARK_EVICTABLE` (since Linux 5.19)
+        const EVICTABLE = 0x0000_0200;
+        /// `FAN_MARK_IGNORE` (since Linux 6.0)
+        const IGNORE = 0x0000_0400;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FAN_*` event flags for use with [`fanotify_mark`], and reported in
+    /// [`Event::mask`].
+    pub struct MaskFlags: u64 {
+        /// `FAN_ACCESS`
+        const ACCESS = 0x0000_0001;
+        /// `FAN_MODIFY`
+        const MODIFY = 0x0000_0002;
+        /// `FAN_ATTRIB` (since Linux 5.1)
+        const ATTRIB = 0x0000_0004;
+        /// `FAN_CLOSE_WRITE`
+        const CLOSE_WRITE = 0x0000_0008;
+        /// `FAN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = 0x0000_0010;
+        /// `FAN_OPEN`
+        const OPEN = 0x0000_0020;
+        /// `FAN_MOVED_FROM` (since Linux 5.1)
+        const MOVED_FROM = 0x0000_0040;
+        /// `FAN_MOVED_TO` (since Linux 5.1)
+        const MOVED_TO = 0x0000_0080;
+        /// `FAN_CREATE` (since Linux 5.1)
+        const CREATE = 0x0000_0100;
+        /// `FAN_DELETE` (since Linux 5.1)
+        const DELETE = 0x0000_0200;
+        /// `FAN_DELETE_SELF` (since Linux 5.1)
+        const DELETE_SELF = 0x0000_0400;
+        /// `FAN_MOVE_SELF` (since Linux 5.1)
+        const MOVE_SELF = 0x0000_0800;
+        /// `FAN_OPEN_EXEC` (since Linux 5.0)
+        const OPEN_EXEC = 0x0000_1000;
+        /// `FAN_Q_OVERFLOW`
+        const Q_OVERFLOW = 0x0000_4000;
+        /// `FAN_FS_ERROR` (since Linux 5.16)
+        const FS_ERROR = 0x0000_8000;
+        /// `FAN_OPEN_PERM`
+        const OPEN_PERM = 0x0001_0000;
+        /// `FAN_ACCESS_PERM`
+        const ACCESS_PERM = 0x0002_0000;
+        /// `FAN_OPEN_EXEC_PERM` (since Linux 5.0)
+        const OPEN_EXEC_PERM = 0x0004_0000;
+        /// `FAN_EVENT_ON_CHILD`
+        const EVENT_ON_CHILD = 0x0800_0000;
+        /// `FAN_RENAME` (since Linux 5.17)
+        const RENAME = 0x1000_0000;
+        /// `FAN_ONDIR`
+        const ONDIR = 0x4000_0000;
+
+        /// `FAN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+        /// `FAN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FAN_*` responses for use with [`fanotify_respond`].
+    pub struct Response: u32 {
+        /// `FAN_ALLOW`
+        const ALLOW = 0x01;
+        /// `FAN_DENY`
+        const DENY = 0x02;
+        /// `FAN_AUDIT`
+        ///
+        /// This requires [`InitFlags::ENABLE_AUDIT`].
+        const AUDIT = 0x10;
+    }
+}
+
+/// `FAN_NOFD`
+const FAN_NOFD: RawFd = -1;
+
+// `FAN_EVENT_INFO_TYPE_*`
+const FAN_EVENT_INFO_TYPE_FID: u8 = 1;
+const FAN_EVENT_INFO_TYPE_DFID_NAME: u8 = 2;
+const FAN_EVENT_INFO_TYPE_DFID: u8 = 3;
+const FAN_EVENT_INFO_TYPE_PIDFD: u8 = 4;
+const FAN_EVENT_INFO_TYPE_ERROR: u8 = 5;
+const FAN_EVENT_INFO_TYPE_OLD_DFID_NAME: u8 = 10;
+const FAN_EVENT_INFO_TYPE_NEW_DFID_NAME: u8 = 12;
+
+/// `struct fanotify_event_metadata`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct fanotify_event_metadata {
+    event_len: u32,
+    vers: u8,
+    reserved: u8,
+    metadata_len: u16,
+    mask: u64,
+    fd: i32,
+    pid: i32,
+}
+
+/// `struct fanotify_event_info_header`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct fanotify_event_info_header {
+    info_type: u8,
+    pad: u8,
+    len: u16,
+}
+
+/// `struct fanotify_response`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct fanotify_response {
+    fd: i32,
+    response: u32,
+}
+
+/// `fanotify_init(flags, event_f_flags)`—Creates a new fanotify group.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_init.2.html
+#[inline]
+pub fn fanotify_init(flags: InitFlags, event_flags: EventFlags) -> io::Result<OwnedFd> {
+    syscalls::fanotify_init(flags, event_flags)
+}
+
+/// `fanotify_mark(fd, flags, mask, dirfd, path)`—Adds, removes, or
+/// modifies a mark on a filesystem object.
+///
+/// If `path` is empty, the object marked is the one `dirfd` refers to.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[inline]
+pub fn fanotify_mark<Fd: AsFd, DirFd: AsFd, P: path::Arg>(
+    fd: Fd,
+    flags: MarkFlags,
+    mask: MaskFlags,
+    dirfd: DirFd,
+    path: P,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        let path = if path.to_bytes().is_empty() {
+            None
+        } else {
+            Some(path)
+        };
+        syscalls::fanotify_mark(fd.as_fd(), flags, mask, dirfd.as_fd(), path)
+    })
+}
+
+/// `write(fd, &fanotify_response { fd: event_fd, response }, ...)`—Responds
+/// to a permission event.
+///
+/// `event_fd` is the file descriptor of the permission event, from
+/// [`Event::fd`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+#[allow(unsafe_code)]
+pub fn fanotify_respond<Fd: AsFd>(
+    fd: Fd,
+    event_fd: BorrowedFd<'_>,
+    response: Response,
+) -> io::Result<()> {
+    let response = fanotify_response {
+        fd: event_fd.as_raw_fd(),
+        response: response.bits(),
+    };
+    // SAFETY: `fanotify_response` is a plain `repr(C)` struct of integers
+    // with no padding.
+    let bytes = unsafe {
+        core::slice::from_raw_parts(
+            (&response as *const fanotify_response).cast::<u8>(),
+            size_of::<fanotify_response>(),
+        )
+    };
+    let n = io::write(fd, bytes)?;
+    if n != bytes.len() {
+        return Err(io::Errno::IO);
+    }
+    Ok(())
+}
+
+/// A reader of fanotify events, which parses the events read from a fanotify
+/// file descriptor into [`Event`]s without allocating.
+///
+/// Like [`inotify::Reader`], this does not handle growing the buffer. The
+/// buffer must be large enough to hold at least one event, including its
+/// information records, or reading fails with [`io::Errno::INVAL`].
+///
+/// Each event read from the kernel comes with newly opened file descriptors,
+/// which are owned by the [`Event`] once it's returned. The file descriptors
+/// of events that are read into the buffer but not returned by [`next`] are
+/// closed when the `Reader` is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::mem::MaybeUninit;
+/// use rustix::fs::cwd;
+/// use rustix::fs::fanotify::{
+///     fanotify_init, fanotify_mark, EventFlags, InitFlags, MarkFlags, MaskFlags, Reader,
+/// };
+///
+/// let fan = fanotify_init(
+///     InitFlags::CLOEXEC | InitFlags::REPORT_DFID_NAME,
+///     EventFlags::RDONLY,
+/// )
+/// .unwrap();
+/// fanotify_mark(
+///     &fan,
+///     MarkFlags::ADD | MarkFlags::FILESYSTEM,
+///     MaskFlags::CREATE | MaskFlags::DELETE | MaskFlags::ONDIR,
+///     cwd(),
+///     "/home",
+/// )
+/// .unwrap();
+///
+/// let mut buf = [MaybeUninit::uninit(); 4096];
+/// let mut reader = Reader::new(&fan, &mut buf);
+/// loop {
+///     let event = reader.next().unwrap();
+///     for info in event.info() {
+///         dbg!(info);
+///     }
+/// }
+/// ```
+///
+/// [`inotify::Reader`]: crate::fs::inotify::Reader
+/// [`next`]: Self::next
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+    initialized: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new reader from the given fanotify file descriptor and
+    /// buffer.
+    pub fn new(fd: Fd, buf: &'buf mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            fd,
+            buf,
+            initialized: 0,
+            offset: 0,
+        }
+    }
+
+    /// Read the next fanotify event.
+    ///
+    /// If no events are buffered, this reads more from the file descriptor,
+    /// which blocks until events are available, or fails with
+    /// [`io::Errno::AGAIN`] if the file descriptor is non-blocking.
+    #[allow(unsafe_code)]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<Event<'_>> {
+        if self.is_buffer_empty() {
+            match read_uninit(self.fd.as_fd(), self.buf) {
+                Ok(0) => return Err(io::Errno::INVAL),
+                Ok(bytes_read) => {
+                    self.initialized = bytes_read;
+                    self.offset = 0;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let (metadata, info) = self.split_event().ok_or(io::Errno::INVAL)?;
+        Ok(Event {
+            mask: MaskFlags::from_bits_truncate(metadata.mask),
+            // SAFETY: The kernel opened this file descriptor for us.
+            fd: if metadata.fd >= 0 {
+                Some(unsafe { OwnedFd::from_raw_fd(metadata.fd) })
+            } else {
+                debug_assert_eq!(metadata.fd, FAN_NOFD);
+                None
+            },
+            // SAFETY: The kernel reports a process ID here.
+            pid: unsafe { Pid::from_raw(metadata.pid as _) },
+            pidfd: InfoIter { bytes: info }
+                .find_pidfd()
+                // SAFETY: The kernel opened this file descriptor for us.
+                .map(|pidfd| unsafe { OwnedFd::from_raw_fd(pidfd) }),
+            info,
+        })
+    }
+
+    /// Returns true if the internal buffer is empty and will be refilled when
+    /// calling [`next`].
+    ///
+    /// [`next`]: Self::next
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.initialized
+    }
+
+    /// Split the next event off the buffer, returning its metadata and its
+    /// information records.
+    ///
+    /// If the event is malformed, this discards the rest of the buffer, as
+    /// there's no telling where the next event starts, and returns `None`.
+    #[allow(unsafe_code)]
+    fn split_event(&mut self) -> Option<(fanotify_event_metadata, &[u8])> {
+        // SAFETY: The kernel initialized `initialized` bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                self.buf[self.offset..].as_ptr().cast::<u8>(),
+                self.initialized - self.offset,
+            )
+        };
+        if bytes.len() < size_of::<fanotify_event_metadata>() {
+            self.offset = self.initialized;
+            return None;
+        }
+        // SAFETY: We checked that there's room for a metadata struct. Events
+        // aren't necessarily 8-byte aligned, so read it unaligned.
+        let metadata: fanotify_event_metadata =
+            unsafe { ptr::read_unaligned(bytes.as_ptr().cast()) };
+        let event_len = metadata.event_len as usize;
+        let metadata_len = usize::from(metadata.metadata_len);
+        if metadata_len < size_of::<fanotify_event_metadata>()
+            || metadata_len > event_len
+            || event_len > bytes.len()
+        {
+            self.offset = self.initialized;
+            return None;
+        }
+        self.offset += event_len;
+        Some((metadata, &bytes[metadata_len..event_len]))
+    }
+}
+
+impl<'buf, Fd: AsFd> Drop for Reader<'buf, Fd> {
+    /// Close the file descriptors of the events which were read into the
+    /// buffer but not returned by [`next`].
+    ///
+    /// [`next`]: Self::next
+    #[allow(unsafe_code)]
+    fn drop(&mut self) {
+        while !self.is_buffer_empty() {
+            let (metadata, info) = match self.split_event() {
+                Some(event) => event,
+                None => break,
+            };
+            // SAFETY: The kernel opened these file descriptors for us, and
+            // nothing else owns them.
+            if metadata.fd >= 0 {
+                drop(unsafe { OwnedFd::from_raw_fd(metadata.fd) });
+            }
+            if let Some(pidfd) = (InfoIter { bytes: info }).find_pidfd() {
+                drop(unsafe { OwnedFd::from_raw_fd(pidfd) });
+            }
+        }
+    }
+}
+
+/// A fanotify event, read by a [`Reader`].
+pub struct Event<'a> {
+    mask: MaskFlags,
+    fd: Option<OwnedFd>,
+    pid: Option<Pid>,
+    pidfd: Option<OwnedFd>,
+    info: &'a [u8],
+}
+
+impl<'a> Event<'a> {
+    /// Returns the events that occurred.
+    #[inline]
+    pub fn mask(&self) -> MaskFlags {
+        self.mask
+    }
+
+    /// Returns the file descriptor the kernel opened for the object this
+    /// event is for.
+    ///
+    /// This is `None` for overflow events, and for groups which report file
+    /// handles with [`InitFlags::REPORT_FID`] or similar. For permission
+    /// events, pass this to [`fanotify_respond`].
+    #[inline]
+    pub fn fd(&self) -> Option<BorrowedFd<'_>> {
+        self.fd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Take ownership of the file descriptor the kernel opened for the
+    /// object this event is for.
+    #[inline]
+    pub fn into_fd(self) -> Option<OwnedFd> {
+        self.fd
+    }
+
+    /// Returns the ID of the process which caused the event, or of the
+    /// thread with [`InitFlags::REPORT_TID`].
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+
+    /// Returns a pidfd for the process which caused the event, with
+    /// [`InitFlags::REPORT_PIDFD`].
+    ///
+    /// This is `None` if the process had already exited, or if the pidfd
+    /// couldn't be created.
+    #[inline]
+    #[doc(alias = "FAN_EVENT_INFO_TYPE_PIDFD")]
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.pidfd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Returns an iterator over the information records of this event.
+    ///
+    /// Pidfd records are reported by [`Event::pidfd`] instead.
+    #[inline]
+    pub fn info(&self) -> InfoIter<'a> {
+        InfoIter { bytes: self.info }
+    }
+}
+
+impl<'a> fmt::Debug for Event<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("Event");
+        f.field("mask", &self.mask());
+        f.field("fd", &self.fd());
+        f.field("pid", &self.pid());
+        f.field("pidfd", &self.pidfd());
+        f.field("info", &self.info());
+        f.finish()
+    }
+}
+
+/// A file identifier from an [`Info`] record: a filesystem ID and a file
+/// handle, as used by `open_by_handle_at`.
+#[derive(Copy, Clone)]
+pub struct FileId<'a> {
+    fsid: [i32; 2],
+    handle_type: i32,
+    handle: &'a [u8],
+}
+
+impl<'a> FileId<'a> {
+    /// Returns the ID of the filesystem containing the object, as reported
+    /// by `statfs`.
+    #[inline]
+    pub fn fsid(&self) -> [i32; 2] {
+        self.fsid
+    }
+
+    /// Returns the type of the file handle.
+    #[inline]
+    pub fn handle_type(&self) -> i32 {
+        self.handle_type
+    }
+
+    /// Returns the bytes of the file handle.
+    #[inline]
+    pub fn handle(&self) -> &'a [u8] {
+        self.handle
+    }
+}
+
+impl<'a> fmt::Debug for FileId<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileId")
+            .field("fsid", &self.fsid)
+            .field("handle_type", &self.handle_type)
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+/// An information record of an [`Event`].
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Info<'a> {
+    /// `FAN_EVENT_INFO_TYPE_FID`—The object the event is for.
+    Fid(FileId<'a>),
+
+    /// `FAN_EVENT_INFO_TYPE_DFID`—The directory containing the object the
+    /// event is for.
+    Dfid(FileId<'a>),
+
+    /// `FAN_EVENT_INFO_TYPE_DFID_NAME`—The directory containing the object
+    /// the event is for, and the object's name within it.
+    DfidName(FileId<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_OLD_DFID_NAME`—For [`MaskFlags::RENAME`], the
+    /// directory and name the object was renamed from.
+    OldDfidName(FileId<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_NEW_DFID_NAME`—For [`MaskFlags::RENAME`], the
+    /// directory and name the object was renamed to.
+    NewDfidName(FileId<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_ERROR`—For [`MaskFlags::FS_ERROR`], the first
+    /// error, and the number of errors since the last event.
+    Error {
+        /// The `errno` value of the first error.
+        error: i32,
+        /// The number of errors.
+        error_count: u32,
+    },
+
+    /// A record of a type rustix doesn't know about, or which is malformed,
+    /// with its type and the bytes following its header.
+    Unknown(u8, &'a [u8]),
+}
+
+/// An iterator over the information records of an [`Event`].
+#[derive(Clone)]
+pub struct InfoIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> InfoIter<'a> {
+    /// Split off the next record, returning its type and the bytes following
+    /// its header.
+    #[allow(unsafe_code)]
+    fn next_record(&mut self) -> Option<(u8, &'a [u8])> {
+        const HEADER_LEN: usize = size_of::<fanotify_event_info_header>();
+        if self.bytes.len() < HEADER_LEN {
+            return None;
+        }
+        // SAFETY: We checked that there's room for a header.
+        let header: fanotify_event_info_header =
+            unsafe { ptr::read_unaligned(self.bytes.as_ptr().cast()) };
+        let len = usize::from(header.len);
+        if len < HEADER_LEN || len > self.bytes.len() {
+            self.bytes = &[];
+            return None;
+        }
+        let (record, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some((header.info_type, &record[HEADER_LEN..]))
+    }
+
+    /// Find the pidfd record, if any.
+    fn find_pidfd(mut self) -> Option<RawFd> {
+        while let Some((info_type, body)) = self.next_record() {
+            if info_type == FAN_EVENT_INFO_TYPE_PIDFD && body.len() >= 4 {
+                let pidfd = read_i32(body, 0);
+                // `FAN_NOPIDFD` and `FAN_EPIDFD` are negative.
+                return if pidfd >= 0 { Some(pidfd) } else { None };
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for InfoIter<'a> {
+    type Item = Info<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (info_type, body) = self.next_record()?;
+            let info = match info_type {
+                FAN_EVENT_INFO_TYPE_PIDFD => continue,
+                FAN_EVENT_INFO_TYPE_FID => parse_fid(body).map(|(fid, _)| Info::Fid(fid)),
+                FAN_EVENT_INFO_TYPE_DFID => parse_fid(body).map(|(fid, _)| Info::Dfid(fid)),
+                FAN_EVENT_INFO_TYPE_DFID_NAME => {
+                    parse_fid_name(body).map(|(fid, name)| Info::DfidName(fid, name))
+                }
+                FAN_EVENT_INFO_TYPE_OLD_DFID_NAME => {
+                    parse_fid_name(body).map(|(fid, name)| Info::OldDfidName(fid, name))
+                }
+                FAN_EVENT_INFO_TYPE_NEW_DFID_NAME => {
+                    parse_fid_name(body).map(|(fid, name)| Info::NewDfidName(fid, name))
+                }
+                FAN_EVENT_INFO_TYPE_ERROR if body.len() >= 8 => Some(Info::Error {
+                    error: read_i32(body, 0),
+                    error_count: read_i32(body, 4) as u32,
+                }),
+                _ => None,
+            };
+            // Report malformed records as unknown, so that the records after
+            // them can still be read.
+            return Some(info.unwrap_or(Info::Unknown(info_type, body)));
+        }
+    }
+}
+
+impl<'a> fmt::Debug for InfoIter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Read a native-endian `i32` from `bytes` at `offset`.
+fn read_i32(bytes: &[u8], offset: usize) -> i32 {
+    let mut buf = [0_u8; 4];
+    buf.copy_from_slice(&bytes[offset..offset + 4]);
+    i32::from_ne_bytes(buf)
+}
+
+/// Parse the body of a `struct fanotify_event_info_fid`, returning the file
+/// ID and the bytes following the file handle.
+fn parse_fid(body: &[u8]) -> Option<(FileId<'_>, &[u8])> {
+    // `__kernel_fsid_t fsid`, then `struct file_handle`, with
+    // `handle_bytes` and `handle_type` followed by the handle.
+    if body.len() < 16 {
+        return None;
+    }
+    let handle_bytes = read_i32(body, 8) as u32 as usize;
+    let handle_end = 16_usize.checked_add(handle_bytes)?;
+    if handle_end > body.len() {
+        return None;
+    }
+    Some((
+        FileId {
+            fsid: [read_i32(body, 0), read_i32(body, 4)],
+            handle_type: read_i32(body, 12),
+            handle: &body[16..handle_end],
+        },
+        &body[handle_end..],
+    ))
+}
+
+/// Parse the body of a `struct fanotify_event_info_fid` followed by a
+/// NUL-terminated name.
+#[allow(unsafe_code)]
+fn parse_fid_name(body: &[u8]) -> Option<(FileId<'_>, &CStr)> {
+    let (fid, rest) = parse_fid(body)?;
+    let nul = rest.iter().position(|b| *b == b'\0')?;
+    // SAFETY: We found the NUL terminator, and there are no NULs before it.
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(&rest[..=nul]) };
+    Some((fid, name))
+}
+
+#[test]
+fn fanotify_layouts() {
+    assert_eq!(size_of::<fanotify_event_metadata>(), 24);
+    assert_eq!(size_of::<fanotify_event_info_header>(), 4);
+    assert_eq!(size_of::<fanotify_response>(), 8);
+}
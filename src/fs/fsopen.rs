@@ -0,0 +1,470 @@
+//! The file-descriptor-based Linux mount API: `fsopen`, `fsconfig`,
+//! `fsmount`, `fspick`, `open_tree`, `move_mount`, and `mount_setattr`.
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use crate::fs::{AtFlags, MountPropagationFlags};
+use crate::{backend, io, path};
+use linux_raw_sys::general::mount_attr;
+
+bitflags::bitflags! {
+    /// `FSOPEN_*` constants for use with [`fsopen`].
+    pub struct FsOpenFlags: u32 {
+        /// `FSOPEN_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::FSOPEN_CLOEXEC;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSMOUNT_*` constants for use with [`fsmount`].
+    pub struct FsMountFlags: u32 {
+        /// `FSMOUNT_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::FSMOUNT_CLOEXEC;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSPICK_*` constants for use with [`fspick`].
+    pub struct FsPickFlags: u32 {
+        /// `FSPICK_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::FSPICK_CLOEXEC;
+        /// `FSPICK_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = linux_raw_sys::general::FSPICK_SYMLINK_NOFOLLOW;
+        /// `FSPICK_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = linux_raw_sys::general::FSPICK_NO_AUTOMOUNT;
+        /// `FSPICK_EMPTY_PATH`
+        const EMPTY_PATH = linux_raw_sys::general::FSPICK_EMPTY_PATH;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MOUNT_ATTR_*` constants for use with [`fsmount`] and [`MountAttr`].
+    pub struct MountAttrFlags: u32 {
+        /// `MOUNT_ATTR_RDONLY`
+        const RDONLY = linux_raw_sys::general::MOUNT_ATTR_RDONLY;
+        /// `MOUNT_ATTR_NOSUID`
+        const NOSUID = linux_raw_sys::general::MOUNT_ATTR_NOSUID;
+        /// `MOUNT_ATTR_NODEV`
+        const NODEV = linux_raw_sys::general::MOUNT_ATTR_NODEV;
+        /// `MOUNT_ATTR_NOEXEC`
+        const NOEXEC = linux_raw_sys::general::MOUNT_ATTR_NOEXEC;
+        /// `MOUNT_ATTR__ATIME`
+        ///
+        /// This is the mask of the access-time settings. To change the
+        /// access-time setting with [`mount_setattr`], clear this and set
+        /// one of the settings.
+        const ATIME = linux_raw_sys::general::MOUNT_ATTR__ATIME;
+        /// `MOUNT_ATTR_RELATIME`
+        const RELATIME = linux_raw_sys::general::MOUNT_ATTR_RELATIME;
+        /// `MOUNT_ATTR_NOATIME`
+        const NOATIME = linux_raw_sys::general::MOUNT_ATTR_NOATIME;
+        /// `MOUNT_ATTR_STRICTATIME`
+        const STRICTATIME = linux_raw_sys::general::MOUNT_ATTR_STRICTATIME;
+        /// `MOUNT_ATTR_NODIRATIME`
+        const NODIRATIME = linux_raw_sys::general::MOUNT_ATTR_NODIRATIME;
+        /// `MOUNT_ATTR_IDMAP` (since Linux 5.12)
+        ///
+        /// This is set automatically by [`MountAttr::idmap`].
+        const IDMAP = linux_raw_sys::general::MOUNT_ATTR_IDMAP;
+        /// `MOUNT_ATTR_NOSYMFOLLOW` (since Linux 5.14)
+        const NOSYMFOLLOW = linux_raw_sys::general::MOUNT_ATTR_NOSYMFOLLOW;
+    }
+}
+
+bitflags::bitflags! {
+    /// `OPEN_TREE_*` and `AT_*` constants for use with [`open_tree`].
+    pub struct OpenTreeFlags: u32 {
+        /// `OPEN_TREE_CLONE`
+        const CLONE = linux_raw_sys::general::OPEN_TREE_CLONE;
+        /// `OPEN_TREE_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::OPEN_TREE_CLOEXEC;
+        /// `AT_RECURSIVE`
+        const RECURSIVE = linux_raw_sys::general::AT_RECURSIVE;
+        /// `AT_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = linux_raw_sys::general::AT_SYMLINK_NOFOLLOW;
+        /// `AT_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = linux_raw_sys::general::AT_NO_AUTOMOUNT;
+        /// `AT_EMPTY_PATH`
+        const EMPTY_PATH = linux_raw_sys::general::AT_EMPTY_PATH;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MOVE_MOUNT_*` constants for use with [`move_mount_at`].
+    pub struct MoveMountFlags: u32 {
+        /// `MOVE_MOUNT_F_SYMLINKS`
+        const F_SYMLINKS = linux_raw_sys::general::MOVE_MOUNT_F_SYMLINKS;
+        /// `MOVE_MOUNT_F_AUTOMOUNTS`
+        const F_AUTOMOUNTS = linux_raw_sys::general::MOVE_MOUNT_F_AUTOMOUNTS;
+        /// `MOVE_MOUNT_F_EMPTY_PATH`
+        const F_EMPTY_PATH = linux_raw_sys::general::MOVE_MOUNT_F_EMPTY_PATH;
+        /// `MOVE_MOUNT_T_SYMLINKS`
+        const T_SYMLINKS = linux_raw_sys::general::MOVE_MOUNT_T_SYMLINKS;
+        /// `MOVE_MOUNT_T_AUTOMOUNTS`
+        const T_AUTOMOUNTS = linux_raw_sys::general::MOVE_MOUNT_T_AUTOMOUNTS;
+        /// `MOVE_MOUNT_T_EMPTY_PATH`
+        const T_EMPTY_PATH = linux_raw_sys::general::MOVE_MOUNT_T_EMPTY_PATH;
+        /// `MOVE_MOUNT_SET_GROUP` (since Linux 5.15)
+        const SET_GROUP = linux_raw_sys::general::MOVE_MOUNT_SET_GROUP;
+    }
+}
+
+/// Mount attributes to change with [`mount_setattr`].
+///
+/// # Examples
+///
+/// Create an idmapped mount of `/srv/data`, with IDs mapped through the user
+/// namespace of some process, and attach it at `/mnt`:
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::fd::AsFd;
+/// use rustix::fs::{
+///     cwd, mount_setattr, move_mount_at, open_tree, openat, AtFlags, Mode, MountAttr,
+///     MoveMountFlags, OFlags, OpenTreeFlags,
+/// };
+///
+/// let userns = openat(cwd(), "/proc/1234/ns/user", OFlags::RDONLY, Mode::empty())?;
+/// let tree = open_tree(
+///     cwd(),
+///     "/srv/data",
+///     OpenTreeFlags::CLONE | OpenTreeFlags::CLOEXEC,
+/// )?;
+/// mount_setattr(
+///     &tree,
+///     "",
+///     AtFlags::EMPTY_PATH,
+///     &MountAttr::new().idmap(userns.as_fd()),
+/// )?;
+/// move_mount_at(&tree, "", cwd(), "/mnt", MoveMountFlags::F_EMPTY_PATH)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MountAttr<'a> {
+    attr_set: MountAttrFlags,
+    attr_clr: MountAttrFlags,
+    propagation: Option<MountPropagationFlags>,
+    userns_fd: Option<BorrowedFd<'a>>,
+}
+
+impl<'a> MountAttr<'a> {
+    /// Construct a new `MountAttr` which changes nothing.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            attr_set: MountAttrFlags::empty(),
+            attr_clr: MountAttrFlags::empty(),
+            propagation: None,
+            userns_fd: None,
+        }
+    }
+
+    /// Set the attributes in `flags`.
+    #[inline]
+    pub fn set(mut self, flags: MountAttrFlags) -> Self {
+        self.attr_set |= flags;
+        self
+    }
+
+    /// Clear the attributes in `flags`.
+    #[inline]
+    pub fn clear(mut self, flags: MountAttrFlags) -> Self {
+        self.attr_clr |= flags;
+        self
+    }
+
+    /// Change the propagation type, to one of
+    /// [`MountPropagationFlags::SHARED`], [`MountPropagationFlags::PRIVATE`],
+    /// [`MountPropagationFlags::SLAVE`], or
+    /// [`MountPropagationFlags::UNBINDABLE`].
+    #[inline]
+    pub fn propagation(mut self, propagation: MountPropagationFlags) -> Self {
+        self.propagation = Some(propagation);
+        self
+    }
+
+    /// Make the mount an idmapped mount, with IDs mapped through the user
+    /// namespace referred to by `userns`.
+    ///
+    /// This sets [`MountAttrFlags::IDMAP`]. The mount must not already be
+    /// attached to the filesystem tree; use a mount from [`open_tree`] with
+    /// [`OpenTreeFlags::CLONE`] or from [`fsmount`].
+    #[inline]
+    pub fn idmap(mut self, userns: BorrowedFd<'a>) -> Self {
+        self.attr_set |= MountAttrFlags::IDMAP;
+        self.userns_fd = Some(userns);
+        self
+    }
+}
+
+impl<'a> Default for MountAttr<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `fsopen(fs_name, flags)`—Creates a filesystem context.
+///
+/// Configure the context with the `fsconfig_*` functions, create the
+/// filesystem with [`fsconfig_create`], and then mount it with [`fsmount`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+pub fn fsopen<Fs: path::Arg>(fs_name: Fs, flags: FsOpenFlags) -> io::Result<OwnedFd> {
+    fs_name.into_with_c_str(|fs_name| backend::fs::syscalls::fsopen(fs_name, flags))
+}
+
+/// `fsmount(fs_fd, flags, attr_flags)`—Creates a detached mount from a
+/// filesystem context.
+///
+/// The returned file descriptor refers to the root of the new mount. It may
+/// be used with `*at` functions directly, or attached to the filesystem tree
+/// with [`move_mount_at`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+#[inline]
+pub fn fsmount<Fd: AsFd>(
+    fs_fd: Fd,
+    flags: FsMountFlags,
+    attr_flags: MountAttrFlags,
+) -> io::Result<OwnedFd> {
+    backend::fs::syscalls::fsmount(fs_fd.as_fd(), flags, attr_flags)
+}
+
+/// `fspick(dirfd, path, flags)`—Creates a filesystem context for
+/// reconfiguring an existing mount's filesystem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+pub fn fspick<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: FsPickFlags,
+) -> io::Result<OwnedFd> {
+    path.into_with_c_str(|path| backend::fs::syscalls::fspick(dirfd.as_fd(), path, flags))
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_FLAG, key, NULL, 0)`—Sets a flag
+/// parameter, such as `"ro"`, on a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_flag<Fd: AsFd, Key: path::Arg>(fs_fd: Fd, key: Key) -> io::Result<()> {
+    key.into_with_c_str(|key| backend::fs::syscalls::fsconfig_set_flag(fs_fd.as_fd(), key))
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_STRING, key, value, 0)`—Sets a string
+/// parameter on a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_string<Fd: AsFd, Key: path::Arg, Value: path::Arg>(
+    fs_fd: Fd,
+    key: Key,
+    value: Value,
+) -> io::Result<()> {
+    key.into_with_c_str(|key| {
+        value.into_with_c_str(|value| {
+            backend::fs::syscalls::fsconfig_set_string(fs_fd.as_fd(), key, value)
+        })
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_BINARY, key, value, value.len())`—Sets a
+/// binary parameter on a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_binary<Fd: AsFd, Key: path::Arg>(
+    fs_fd: Fd,
+    key: Key,
+    value: &[u8],
+) -> io::Result<()> {
+    key.into_with_c_str(|key| backend::fs::syscalls::fsconfig_set_binary(fs_fd.as_fd(), key, value))
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_PATH, key, path, dirfd)`—Sets a path
+/// parameter, such as a block device for `"source"`, on a filesystem
+/// context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_path<Fd: AsFd, Key: path::Arg, DirFd: AsFd, P: path::Arg>(
+    fs_fd: Fd,
+    key: Key,
+    dirfd: DirFd,
+    path: P,
+) -> io::Result<()> {
+    key.into_with_c_str(|key| {
+        path.into_with_c_str(|path| {
+            backend::fs::syscalls::fsconfig_set_path(fs_fd.as_fd(), key, dirfd.as_fd(), path)
+        })
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_PATH_EMPTY, key, "", fd)`—Sets a path
+/// parameter to the file referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_path_empty<Fd: AsFd, Key: path::Arg, PathFd: AsFd>(
+    fs_fd: Fd,
+    key: Key,
+    fd: PathFd,
+) -> io::Result<()> {
+    key.into_with_c_str(|key| {
+        backend::fs::syscalls::fsconfig_set_path_empty(fs_fd.as_fd(), key, fd.as_fd())
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_SET_FD, key, NULL, fd)`—Sets a file
+/// descriptor parameter on a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_set_fd<Fd: AsFd, Key: path::Arg, ValueFd: AsFd>(
+    fs_fd: Fd,
+    key: Key,
+    fd: ValueFd,
+) -> io::Result<()> {
+    key.into_with_c_str(|key| {
+        backend::fs::syscalls::fsconfig_set_fd(fs_fd.as_fd(), key, fd.as_fd())
+    })
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_CMD_CREATE, NULL, NULL, 0)`—Creates the
+/// filesystem described by a filesystem context.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_create<Fd: AsFd>(fs_fd: Fd) -> io::Result<()> {
+    backend::fs::syscalls::fsconfig_create(fs_fd.as_fd())
+}
+
+/// `fsconfig(fs_fd, FSCONFIG_CMD_RECONFIGURE, NULL, NULL, 0)`—Applies the
+/// parameters of a filesystem context from [`fspick`] to the existing
+/// filesystem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/fsopen.c
+#[inline]
+#[doc(alias = "fsconfig")]
+pub fn fsconfig_reconfigure<Fd: AsFd>(fs_fd: Fd) -> io::Result<()> {
+    backend::fs::syscalls::fsconfig_reconfigure(fs_fd.as_fd())
+}
+
+/// `open_tree(dirfd, path, flags)`—Opens a mount, or with
+/// [`OpenTreeFlags::CLONE`], creates a detached copy of it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+#[inline]
+pub fn open_tree<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    path.into_with_c_str(|path| backend::fs::syscalls::open_tree(dirfd.as_fd(), path, flags))
+}
+
+/// `move_mount(from_dirfd, from_path, to_dirfd, to_path, flags)`—Moves a
+/// mount, or attaches a detached mount to the filesystem tree.
+///
+/// For the path-based `mount` with `MS_MOVE`, see [`mount_move`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://github.com/torvalds/linux/blob/master/fs/namespace.c
+/// [`mount_move`]: crate::fs::mount_move
+#[inline]
+#[doc(alias = "move_mount")]
+pub fn move_mount_at<FromFd: AsFd, FromPath: path::Arg, ToFd: AsFd, ToPath: path::Arg>(
+    from_dirfd: FromFd,
+    from_path: FromPath,
+    to_dirfd: ToFd,
+    to_path: ToPath,
+    flags: MoveMountFlags,
+) -> io::Result<()> {
+    from_path.into_with_c_str(|from_path| {
+        to_path.into_with_c_str(|to_path| {
+            backend::fs::syscalls::move_mount(
+                from_dirfd.as_fd(),
+                from_path,
+                to_dirfd.as_fd(),
+                to_path,
+                flags,
+            )
+        })
+    })
+}
+
+/// `mount_setattr(dirfd, path, flags, attr, sizeof(struct mount_attr))`—
+/// Changes the attributes of a mount.
+///
+/// With [`AtFlags::RECURSIVE`], this changes all the mounts beneath it too.
+/// This requires Linux 5.12 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: AtFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let raw = mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.map_or(0, |p| p.bits() as u64),
+        userns_fd: attr.userns_fd.map_or(0, |fd| fd.as_raw_fd() as u64),
+    };
+    path.into_with_c_str(|path| {
+        backend::fs::syscalls::mount_setattr(dirfd.as_fd(), path, flags, &raw)
+    })
+}
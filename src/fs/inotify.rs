@@ -0,0 +1,180 @@
+//! inotify support for working with inotifies
+
+use core::fmt;
+use core::mem::{align_of, size_of, MaybeUninit};
+use linux_raw_sys::general::inotify_event;
+
+use crate::backend::io::syscalls::read_uninit;
+use crate::fd::AsFd;
+use crate::ffi::CStr;
+use crate::io;
+
+pub use crate::backend::fs::inotify::{
+    inotify_add_watch, inotify_init, inotify_remove_watch, CreateFlags, ReadFlags, WatchFlags,
+};
+
+/// A reader of inotify events, which parses the events read from an inotify
+/// file descriptor into [`InotifyEvent`]s without allocating.
+///
+/// Like [`RawDir`], this does not handle growing the buffer. The buffer must
+/// be large enough to hold at least one event, including its file name, or
+/// reading fails with [`io::Errno::INVAL`]; a buffer of
+/// `size_of::<inotify_event>() + NAME_MAX + 1` bytes is always enough.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::mem::MaybeUninit;
+/// use rustix::fd::AsFd;
+/// use rustix::fs::inotify::{self, CreateFlags, Reader, WatchFlags};
+///
+/// let inot = inotify::inotify_init(CreateFlags::CLOEXEC).unwrap();
+/// inotify::inotify_add_watch(
+///     inot.as_fd(),
+///     "/tmp",
+///     WatchFlags::CREATE | WatchFlags::DELETE,
+/// )
+/// .unwrap();
+///
+/// let mut buf = [MaybeUninit::uninit(); 4096];
+/// let mut reader = Reader::new(&inot, &mut buf);
+/// loop {
+///     let event = reader.next().unwrap();
+///     dbg!(&event);
+/// }
+/// ```
+///
+/// [`RawDir`]: crate::fs::RawDir
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+    initialized: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new reader from the given inotify file descriptor and buffer.
+    ///
+    /// Note: the buffer size may be trimmed to accommodate alignment
+    /// requirements.
+    pub fn new(fd: Fd, buf: &'buf mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            fd,
+            buf: {
+                let offset = buf.as_ptr().align_offset(align_of::<inotify_event>());
+                if offset < buf.len() {
+                    &mut buf[offset..]
+                } else {
+                    &mut []
+                }
+            },
+            initialized: 0,
+            offset: 0,
+        }
+    }
+}
+
+/// An inotify event, read by a [`Reader`].
+pub struct InotifyEvent<'a> {
+    wd: i32,
+    events: ReadFlags,
+    cookie: u32,
+    file_name: Option<&'a CStr>,
+}
+
+impl<'a> InotifyEvent<'a> {
+    /// Returns the watch descriptor of the watch this event is for.
+    ///
+    /// This is `-1` for [`ReadFlags::Q_OVERFLOW`] events.
+    #[inline]
+    pub fn wd(&self) -> i32 {
+        self.wd
+    }
+
+    /// Returns the events that occurred.
+    #[inline]
+    #[doc(alias = "mask")]
+    pub fn events(&self) -> ReadFlags {
+        self.events
+    }
+
+    /// Returns the cookie which connects the [`ReadFlags::MOVED_FROM`] and
+    /// [`ReadFlags::MOVED_TO`] events of a rename, or zero.
+    #[inline]
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+
+    /// Returns the name of the file within a watched directory that this
+    /// event is for, or `None` if the event is for the watched file or
+    /// directory itself.
+    #[inline]
+    #[doc(alias = "name")]
+    pub fn file_name(&self) -> Option<&CStr> {
+        self.file_name
+    }
+}
+
+impl<'a> fmt::Debug for InotifyEvent<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("InotifyEvent");
+        f.field("wd", &self.wd());
+        f.field("events", &self.events());
+        f.field("cookie", &self.cookie());
+        f.field("file_name", &self.file_name());
+        f.finish()
+    }
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Read the next inotify event.
+    ///
+    /// If no events are buffered, this reads more from the file descriptor,
+    /// which blocks until events are available, or fails with
+    /// [`io::Errno::AGAIN`] if the file descriptor is non-blocking.
+    #[allow(unsafe_code)]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<InotifyEvent<'_>> {
+        if self.is_buffer_empty() {
+            match read_uninit(self.fd.as_fd(), self.buf) {
+                Ok(0) => return Err(io::Errno::INVAL),
+                Ok(bytes_read) => {
+                    self.initialized = bytes_read;
+                    self.offset = 0;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let event_ptr = self.buf[self.offset..].as_ptr();
+        // SAFETY:
+        // - This data is initialized by the check above.
+        //   - Assumption: the kernel will not give us partial structs.
+        // - Assumption: the kernel pads names so that each event is aligned.
+        // - The starting pointer is aligned (performed in Reader::new)
+        let event = unsafe { &*event_ptr.cast::<inotify_event>() };
+
+        self.offset += size_of::<inotify_event>() + event.len as usize;
+
+        Ok(InotifyEvent {
+            wd: event.wd,
+            events: ReadFlags::from_bits_truncate(event.mask),
+            cookie: event.cookie,
+            file_name: if event.len > 0 {
+                // SAFETY: The kernel guarantees a NUL-terminated string,
+                // padded with NULs to `len` bytes.
+                Some(unsafe { CStr::from_ptr(event.name.as_ptr().cast()) })
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Returns true if the internal buffer is empty and will be refilled when
+    /// calling [`next`].
+    ///
+    /// [`next`]: Self::next
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.initialized
+    }
+}
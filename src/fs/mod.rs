@@ -19,6 +19,8 @@ mod dir;
     target_os = "redox",
 )))]
 mod fadvise;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod fanotify;
 pub(crate) mod fcntl;
 #[cfg(apple)]
 mod fcntl_apple;
@@ -26,8 +28,12 @@ mod fcntl_apple;
 mod fcopyfile;
 pub(crate) mod fd;
 mod file_type;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fsopen;
 #[cfg(apple)]
 mod getpath;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod inotify;
 #[cfg(not(any(solarish, target_os = "haiku", target_os = "redox", target_os = "wasi")))]
 mod makedev;
 #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
@@ -47,8 +53,6 @@ mod sync;
 #[cfg(any(apple, target_os = "android", target_os = "linux"))]
 mod xattr;
 
-#[cfg(any(target_os = "android", target_os = "linux"))]
-pub use crate::backend::fs::inotify;
 pub use abs::*;
 #[cfg(not(target_os = "redox"))]
 pub use at::*;
@@ -75,6 +79,8 @@ pub use fcntl_apple::{fcntl_fullfsync, fcntl_rdadvise};
 pub use fcopyfile::*;
 pub use fd::*;
 pub use file_type::FileType;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use fsopen::*;
 #[cfg(apple)]
 pub use getpath::getpath;
 #[cfg(not(any(solarish, target_os = "haiku", target_os = "redox", target_os = "wasi")))]
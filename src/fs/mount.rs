@@ -131,13 +131,17 @@ pub fn change_mount<Target: path::Arg>(
 
 /// `mount(source, target, NULL, MS_MOVE, NULL)`
 ///
+/// For the file-descriptor-based `move_mount` system call, see
+/// [`move_mount_at`].
+///
 /// # References
 ///  - [Linux]
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+/// [`move_mount_at`]: crate::fs::move_mount_at
 #[inline]
 #[doc(alias = "mount")]
-pub fn move_mount<Source: path::Arg, Target: path::Arg>(
+pub fn mount_move<Source: path::Arg, Target: path::Arg>(
     source: Source,
     target: Target,
 ) -> io::Result<()> {
@@ -154,6 +158,21 @@ pub fn move_mount<Source: path::Arg, Target: path::Arg>(
     })
 }
 
+/// `mount(source, target, NULL, MS_MOVE, NULL)`
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+#[deprecated(note = "use `mount_move` instead")]
+#[inline]
+pub fn move_mount<Source: path::Arg, Target: path::Arg>(
+    source: Source,
+    target: Target,
+) -> io::Result<()> {
+    mount_move(source, target)
+}
+
 /// `umount2(target, flags)`
 ///
 /// # References
@@ -5,6 +5,12 @@
 //! `Result`, `OwnedFd`, `AsFd`, `RawFd`, and `*mut c_void` in place of plain
 //! integers.
 //!
+//! [`IoUring`] additionally manages the memory mappings of the submission and
+//! completion rings, so that users don't need to compute the ring layout from
+//! [`io_sqring_offsets`] and [`io_cqring_offsets`] themselves, and the types
+//! in [`opcode`] construct [`io_uring_sqe`]s with the fields each operation
+//! expects.
+//!
 //! # Safety
 //!
 //! io_uring operates on raw pointers and raw file descriptors. Rustix does not
@@ -21,12 +27,21 @@
 //! [io_uring header]: https://github.com/torvalds/linux/blob/master/include/uapi/linux/io_uring.h
 #![allow(unsafe_code)]
 
+pub mod opcode;
+mod probe;
+mod register;
+mod ring;
+
 use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
 use crate::{backend, io};
 use core::ffi::c_void;
 use core::ptr::null_mut;
 use linux_raw_sys::general as sys;
 
+pub use probe::{io_uring_probe, ProbeResult};
+pub use register::{BufRing, BufRingEntry, FixedFiles, RegisteredBuffers};
+pub use ring::{CompletionQueue, IoUring, SubmissionQueue};
+
 /// `io_uring_setup(entries, params)`—Setup a context for performing
 /// asynchronous I/O.
 ///
@@ -569,6 +584,10 @@ bitflags::bitflags! {
 
 bitflags::bitflags! {
     /// `IORING_FEAT_*` flags for use with [`io_uring_params`].
+    ///
+    /// The kernel reports the features it supports in the `features` field
+    /// of the [`io_uring_params`] passed to [`io_uring_setup`], which is
+    /// also available from [`IoUring::params`].
     #[derive(Default)]
     pub struct IoringFeatureFlags: u32 {
         /// `IORING_FEAT_CQE_SKIP`
@@ -609,6 +628,9 @@ bitflags::bitflags! {
 
         /// `IORING_FEAT_LINKED_FILE`
         const LINKED_FILE = sys::IORING_FEAT_LINKED_FILE;
+
+        /// `IORING_FEAT_REG_REG_RING` (since Linux 6.3)
+        const REG_REG_RING = sys::IORING_FEAT_REG_REG_RING;
     }
 }
 
@@ -1171,6 +1193,19 @@ pub struct iovec {
     pub iov_len: usize,
 }
 
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct msghdr {
+    pub msg_name: *mut c_void,
+    pub msg_namelen: i32,
+    pub msg_iov: *mut iovec,
+    pub msg_iovlen: usize,
+    pub msg_control: *mut c_void,
+    pub msg_controllen: usize,
+    pub msg_flags: u32,
+}
+
 #[allow(missing_docs)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default)]
@@ -1209,6 +1244,34 @@ pub struct io_uring_buf {
     pub resv: u16,
 }
 
+/// A timeout for [`opcode::Timeout`] and [`opcode::LinkTimeout`], with the
+/// layout of the kernel's `__kernel_timespec` on all targets.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[doc(alias = "__kernel_timespec")]
+pub struct Timespec {
+    /// Seconds.
+    pub tv_sec: i64,
+
+    /// Nanoseconds. Must be less than 1_000_000_000.
+    pub tv_nsec: i64,
+}
+
+impl Default for msghdr {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            msg_name: null_mut(),
+            msg_namelen: 0,
+            msg_iov: null_mut(),
+            msg_iovlen: 0,
+            msg_control: null_mut(),
+            msg_controllen: 0,
+            msg_flags: 0,
+        }
+    }
+}
+
 impl Default for ioprio_union {
     #[inline]
     fn default() -> Self {
@@ -1433,7 +1496,26 @@ fn io_uring_layouts() {
     check_struct!(io_uring_rsrc_update2, offset, resv, data, tags, nr, resv2);
     check_struct!(io_uring_getevents_arg, sigmask, sigmask_sz, pad, ts);
     check_struct!(iovec, iov_base, iov_len);
+    check_struct!(
+        msghdr,
+        msg_name,
+        msg_namelen,
+        msg_iov,
+        msg_iovlen,
+        msg_control,
+        msg_controllen,
+        msg_flags
+    );
     check_struct!(open_how, flags, mode, resolve);
     check_struct!(io_uring_buf_reg, ring_addr, ring_entries, bgid, pad, resv);
     check_struct!(io_uring_buf, addr, len, bid, resv);
+
+    check_renamed_type!(Timespec, __kernel_timespec);
+    assert_eq!(
+        (span_of!(Timespec, tv_sec), span_of!(Timespec, tv_nsec)),
+        (
+            span_of!(sys::__kernel_timespec, tv_sec),
+            span_of!(sys::__kernel_timespec, tv_nsec)
+        )
+    );
 }
@@ -0,0 +1,896 @@
+//! Typed constructors for [`io_uring_sqe`]s.
+//!
+//! Each type in this module corresponds to an [`IoringOp`], and knows which
+//! fields and unions of [`io_uring_sqe`] that operation uses. Call `build` to
+//! produce the entry, and push it with [`SubmissionQueue::push`].
+//!
+//! Constructing an entry is safe, however the entry refers to the buffers and
+//! file descriptors it was built from by raw pointer and raw file descriptor,
+//! so these must remain valid until the operation completes. This is why
+//! pushing an entry is `unsafe`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn main() -> rustix::io::Result<()> {
+//! use rustix::fd::AsFd;
+//! use rustix::io_uring::{io_uring_user_data, opcode, IoUring};
+//!
+//! let mut ring = IoUring::new(8)?;
+//! let (reader, writer) = rustix::io::pipe()?;
+//!
+//! let mut buf = [0_u8; 64];
+//! let sqe = opcode::Read::new(reader.as_fd(), &mut buf)
+//!     .user_data(io_uring_user_data::from_u64(42))
+//!     .build();
+//!
+//! // SAFETY: `reader` and `buf` outlive the operation.
+//! unsafe { ring.submission().push(&sqe)? };
+//! # drop(writer);
+//! ring.submit_and_wait(1)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`SubmissionQueue::push`]: super::SubmissionQueue::push
+
+use super::{
+    io_uring_ptr, io_uring_sqe, io_uring_user_data, msghdr, open_how, IoringAcceptFlags,
+    IoringMsgringCmds, IoringMsgringFlags, IoringOp, IoringRecvFlags, IoringSendFlags,
+    IoringSqeFlags, IoringTimeoutFlags, SpliceFlags, Timespec, IORING_FILE_INDEX_ALLOC,
+};
+use crate::fd::{AsRawFd, BorrowedFd};
+use crate::ffi::CStr;
+use crate::fs::{AtFlags, StatxFlags};
+use crate::io::{IoSlice, IoSliceMut, ReadWriteFlags};
+use crate::net::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrStorage, SocketFlags};
+use core::ffi::c_void;
+use core::mem::{size_of, MaybeUninit};
+
+/// Defines the methods common to all the entry constructors.
+macro_rules! sqe_common {
+    ($name:ident) => {
+        impl $name {
+            /// Set the `user_data` field, which is passed through to the
+            /// operation's completion queue entry.
+            #[inline]
+            pub fn user_data(mut self, user_data: io_uring_user_data) -> Self {
+                self.sqe.user_data = user_data;
+                self
+            }
+
+            /// Add the given `IOSQE_*` flags.
+            #[inline]
+            pub fn flags(mut self, flags: IoringSqeFlags) -> Self {
+                self.sqe.flags |= flags;
+                self
+            }
+
+            /// Set the credentials to use for the operation, as registered
+            /// with `IORING_REGISTER_PERSONALITY`.
+            #[inline]
+            pub fn personality(mut self, personality: u16) -> Self {
+                self.sqe.personality = personality;
+                self
+            }
+
+            /// Produce the submission queue entry.
+            #[inline]
+            pub fn build(self) -> io_uring_sqe {
+                self.sqe
+            }
+        }
+
+        impl From<$name> for io_uring_sqe {
+            #[inline]
+            fn from(op: $name) -> Self {
+                op.build()
+            }
+        }
+    };
+}
+
+/// Return a new entry with the given opcode and file descriptor, and all
+/// other fields zeroed.
+#[inline]
+fn new_sqe(opcode: IoringOp, fd: i32) -> io_uring_sqe {
+    io_uring_sqe {
+        opcode,
+        fd,
+        ..Default::default()
+    }
+}
+
+/// Convert a pointer to an `io_uring_ptr`.
+#[inline]
+fn ptr<T>(ptr: *const T) -> io_uring_ptr {
+    io_uring_ptr::from(ptr as *mut c_void)
+}
+
+/// Encode the fixed file slot `file_index` for `splice_fd_in_or_file_index`,
+/// where zero means "don't use a fixed file", so real slots are stored as one
+/// more than their index.
+///
+/// # Panics
+///
+/// Panics if `file_index` is `u32::MAX - 1` or more, because one more than
+/// that is either [`IORING_FILE_INDEX_ALLOC`] or doesn't fit.
+#[inline]
+fn fixed_file_slot(file_index: u32) -> u32 {
+    assert!(
+        file_index < IORING_FILE_INDEX_ALLOC as u32 - 1,
+        "fixed file index out of range"
+    );
+    file_index + 1
+}
+
+/// Encode `file_index` like [`fixed_file_slot`], except that
+/// [`IORING_FILE_INDEX_ALLOC`] is passed through as is.
+#[inline]
+fn target_fixed_file(file_index: u32) -> u32 {
+    if file_index == IORING_FILE_INDEX_ALLOC as u32 {
+        file_index
+    } else {
+        fixed_file_slot(file_index)
+    }
+}
+
+/// The file an operation acts on: either a file descriptor, or an entry in
+/// the ring's fixed file table.
+#[derive(Debug, Clone, Copy)]
+pub enum Target<'fd> {
+    /// A file descriptor.
+    Fd(BorrowedFd<'fd>),
+
+    /// An index into the fixed file table, as registered with
+    /// [`FixedFiles`], for which `IOSQE_FIXED_FILE` is set.
+    ///
+    /// [`FixedFiles`]: super::FixedFiles
+    Fixed(u32),
+}
+
+impl<'fd> From<BorrowedFd<'fd>> for Target<'fd> {
+    #[inline]
+    fn from(fd: BorrowedFd<'fd>) -> Self {
+        Self::Fd(fd)
+    }
+}
+
+/// Return a new entry with the given opcode and target, and all other fields
+/// zeroed.
+#[inline]
+fn target_sqe(opcode: IoringOp, target: Target<'_>) -> io_uring_sqe {
+    match target {
+        Target::Fd(fd) => new_sqe(opcode, fd.as_raw_fd()),
+        Target::Fixed(index) => {
+            let mut sqe = new_sqe(opcode, index as i32);
+            sqe.flags = IoringSqeFlags::FIXED_FILE;
+            sqe
+        }
+    }
+}
+
+/// `IORING_OP_NOP`—Do nothing.
+#[derive(Clone, Copy)]
+pub struct Nop {
+    sqe: io_uring_sqe,
+}
+
+impl Nop {
+    /// Construct a new `Nop`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sqe: new_sqe(IoringOp::Nop, -1),
+        }
+    }
+}
+
+impl Default for Nop {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+sqe_common!(Nop);
+
+/// `IORING_OP_READ`—Read from a file descriptor, like [`pread`].
+///
+/// By default, this reads from the file's current position.
+///
+/// [`pread`]: crate::io::pread
+#[derive(Clone, Copy)]
+pub struct Read {
+    sqe: io_uring_sqe,
+}
+
+impl Read {
+    /// Construct a new `Read` which reads into `buf`.
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, buf: &mut [u8]) -> Self {
+        let mut sqe = target_sqe(IoringOp::Read, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(buf.as_mut_ptr());
+        sqe.len.len = buf.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        Self { sqe }
+    }
+
+    /// Construct a new `Read` which reads up to `len` bytes into a buffer
+    /// selected by the kernel from the provided buffer group `buf_group`.
+    ///
+    /// The index of the selected buffer is reported in the completion's
+    /// flags, above [`IORING_CQE_BUFFER_SHIFT`].
+    ///
+    /// [`IORING_CQE_BUFFER_SHIFT`]: super::IORING_CQE_BUFFER_SHIFT
+    #[inline]
+    pub fn buffer_select<'fd>(fd: impl Into<Target<'fd>>, len: u32, buf_group: u16) -> Self {
+        let mut sqe = target_sqe(IoringOp::Read, fd.into());
+        sqe.flags |= IoringSqeFlags::BUFFER_SELECT;
+        sqe.len.len = len;
+        sqe.off_or_addr2.off = u64::MAX;
+        sqe.buf.buf_group = buf_group;
+        Self { sqe }
+    }
+
+    /// Read from the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Read);
+
+/// `IORING_OP_WRITE`—Write to a file descriptor, like [`pwrite`].
+///
+/// By default, this writes at the file's current position.
+///
+/// [`pwrite`]: crate::io::pwrite
+#[derive(Clone, Copy)]
+pub struct Write {
+    sqe: io_uring_sqe,
+}
+
+impl Write {
+    /// Construct a new `Write` which writes the contents of `buf`.
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, buf: &[u8]) -> Self {
+        let mut sqe = target_sqe(IoringOp::Write, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(buf.as_ptr());
+        sqe.len.len = buf.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        Self { sqe }
+    }
+
+    /// Write at the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Write);
+
+/// `IORING_OP_READV`—Read from a file descriptor into multiple buffers, like
+/// [`preadv`].
+///
+/// By default, this reads from the file's current position.
+///
+/// [`preadv`]: crate::io::preadv
+#[derive(Clone, Copy)]
+pub struct Readv {
+    sqe: io_uring_sqe,
+}
+
+impl Readv {
+    /// Construct a new `Readv` which reads into `bufs`.
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, bufs: &mut [IoSliceMut<'_>]) -> Self {
+        let mut sqe = target_sqe(IoringOp::Readv, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(bufs.as_mut_ptr());
+        sqe.len.len = bufs.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        Self { sqe }
+    }
+
+    /// Read from the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Readv);
+
+/// `IORING_OP_WRITEV`—Write to a file descriptor from multiple buffers, like
+/// [`pwritev`].
+///
+/// By default, this writes at the file's current position.
+///
+/// [`pwritev`]: crate::io::pwritev
+#[derive(Clone, Copy)]
+pub struct Writev {
+    sqe: io_uring_sqe,
+}
+
+impl Writev {
+    /// Construct a new `Writev` which writes the contents of `bufs`.
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, bufs: &[IoSlice<'_>]) -> Self {
+        let mut sqe = target_sqe(IoringOp::Writev, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(bufs.as_ptr());
+        sqe.len.len = bufs.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        Self { sqe }
+    }
+
+    /// Write at the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Writev);
+
+/// `IORING_OP_READ_FIXED`—Read from a file descriptor into a registered
+/// buffer.
+///
+/// By default, this reads from the file's current position.
+#[derive(Clone, Copy)]
+pub struct ReadFixed {
+    sqe: io_uring_sqe,
+}
+
+impl ReadFixed {
+    /// Construct a new `ReadFixed` which reads into `buf`, which must lie
+    /// within the buffer registered at `buf_index` with
+    /// [`RegisteredBuffers`].
+    ///
+    /// [`RegisteredBuffers`]: super::RegisteredBuffers
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, buf: &mut [u8], buf_index: u16) -> Self {
+        let mut sqe = target_sqe(IoringOp::ReadFixed, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(buf.as_mut_ptr());
+        sqe.len.len = buf.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        sqe.buf.buf_index = buf_index;
+        Self { sqe }
+    }
+
+    /// Read from the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(ReadFixed);
+
+/// `IORING_OP_WRITE_FIXED`—Write to a file descriptor from a registered
+/// buffer.
+///
+/// By default, this writes at the file's current position.
+#[derive(Clone, Copy)]
+pub struct WriteFixed {
+    sqe: io_uring_sqe,
+}
+
+impl WriteFixed {
+    /// Construct a new `WriteFixed` which writes the contents of `buf`, which
+    /// must lie within the buffer registered at `buf_index` with
+    /// [`RegisteredBuffers`].
+    ///
+    /// [`RegisteredBuffers`]: super::RegisteredBuffers
+    #[inline]
+    pub fn new<'fd>(fd: impl Into<Target<'fd>>, buf: &[u8], buf_index: u16) -> Self {
+        let mut sqe = target_sqe(IoringOp::WriteFixed, fd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(buf.as_ptr());
+        sqe.len.len = buf.len() as u32;
+        sqe.off_or_addr2.off = u64::MAX;
+        sqe.buf.buf_index = buf_index;
+        Self { sqe }
+    }
+
+    /// Write at the given offset instead of the current position.
+    #[inline]
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.sqe.off_or_addr2.off = offset;
+        self
+    }
+
+    /// Set the `RWF_*` flags.
+    #[inline]
+    pub fn rw_flags(mut self, flags: ReadWriteFlags) -> Self {
+        self.sqe.op_flags.rw_flags = flags;
+        self
+    }
+}
+
+sqe_common!(WriteFixed);
+
+/// `IORING_OP_ACCEPT`—Accept a new connection on a socket, like
+/// [`accept_with`].
+///
+/// [`accept_with`]: crate::net::accept_with
+#[derive(Clone, Copy)]
+pub struct Accept {
+    sqe: io_uring_sqe,
+}
+
+impl Accept {
+    /// Construct a new `Accept`.
+    #[inline]
+    pub fn new<'fd>(sockfd: impl Into<Target<'fd>>) -> Self {
+        Self {
+            sqe: target_sqe(IoringOp::Accept, sockfd.into()),
+        }
+    }
+
+    /// Store the address of the peer in `addr`, and its length in `addrlen`.
+    ///
+    /// `addrlen` must be initialized to the size of `addr`. Once the
+    /// operation completes, the address can be decoded with
+    /// [`SocketAddrAny::read`].
+    #[inline]
+    pub fn peer_addr(
+        mut self,
+        addr: &mut MaybeUninit<SocketAddrStorage>,
+        addrlen: &mut u32,
+    ) -> Self {
+        self.sqe.addr_or_splice_off_in.addr = ptr(addr.as_mut_ptr());
+        self.sqe.off_or_addr2.addr2 = ptr(addrlen);
+        self
+    }
+
+    /// Set the `SOCK_*` flags for the accepted socket.
+    #[inline]
+    pub fn socket_flags(mut self, flags: SocketFlags) -> Self {
+        self.sqe.op_flags.accept_flags = flags;
+        self
+    }
+
+    /// Set the `IORING_ACCEPT_*` flags.
+    #[inline]
+    pub fn accept_flags(mut self, flags: IoringAcceptFlags) -> Self {
+        self.sqe.ioprio.accept_flags = flags;
+        self
+    }
+
+    /// Install the accepted socket directly into the fixed file table at
+    /// `file_index`, instead of allocating a file descriptor.
+    ///
+    /// Use [`IORING_FILE_INDEX_ALLOC`] to have the kernel pick a free slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_index` is `u32::MAX - 1`, which can't be encoded.
+    ///
+    /// [`IORING_FILE_INDEX_ALLOC`]: super::IORING_FILE_INDEX_ALLOC
+    #[inline]
+    pub fn file_index(mut self, file_index: u32) -> Self {
+        self.sqe.splice_fd_in_or_file_index.file_index = target_fixed_file(file_index);
+        self
+    }
+}
+
+sqe_common!(Accept);
+
+/// `IORING_OP_CONNECT`—Initiate a connection on a socket, like [`connect`].
+///
+/// [`connect`]: crate::net::connect_any
+#[derive(Clone, Copy)]
+pub struct Connect {
+    sqe: io_uring_sqe,
+}
+
+impl Connect {
+    /// Construct a new `Connect`, which connects to `addr`.
+    ///
+    /// The encoded address is written to `storage`, which must remain valid
+    /// until the operation completes.
+    #[inline]
+    pub fn new<'fd>(
+        sockfd: impl Into<Target<'fd>>,
+        addr: &SocketAddrAny,
+        storage: &mut MaybeUninit<SocketAddrStorage>,
+    ) -> Self {
+        // SAFETY: `storage` is valid memory for a `SocketAddrStorage`, which
+        // is large enough to hold any encoded socket address.
+        let len = unsafe { addr.write(storage.as_mut_ptr()) };
+
+        let mut sqe = target_sqe(IoringOp::Connect, sockfd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(storage.as_ptr());
+        sqe.off_or_addr2.off = len as u64;
+        Self { sqe }
+    }
+}
+
+sqe_common!(Connect);
+
+/// `IORING_OP_SENDMSG`—Send a message on a socket, like `sendmsg`.
+#[derive(Clone, Copy)]
+pub struct SendMsg {
+    sqe: io_uring_sqe,
+}
+
+impl SendMsg {
+    /// Construct a new `SendMsg`, which sends the message described by `msg`.
+    #[inline]
+    pub fn new<'fd>(sockfd: impl Into<Target<'fd>>, msg: &msghdr) -> Self {
+        let mut sqe = target_sqe(IoringOp::Sendmsg, sockfd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(msg);
+        sqe.len.len = 1;
+        Self { sqe }
+    }
+
+    /// Set the `MSG_*` flags.
+    #[inline]
+    pub fn send_flags(mut self, flags: SendFlags) -> Self {
+        self.sqe.op_flags.send_flags = flags;
+        self
+    }
+
+    /// Set the `IORING_RECVSEND_*` flags.
+    #[inline]
+    pub fn ioring_send_flags(mut self, flags: IoringSendFlags) -> Self {
+        self.sqe.ioprio.send_flags = flags;
+        self
+    }
+}
+
+sqe_common!(SendMsg);
+
+/// `IORING_OP_RECVMSG`—Receive a message from a socket, like [`recvmsg`].
+///
+/// [`recvmsg`]: crate::net::recvmsg
+#[derive(Clone, Copy)]
+pub struct RecvMsg {
+    sqe: io_uring_sqe,
+}
+
+impl RecvMsg {
+    /// Construct a new `RecvMsg`, which receives into the buffers described
+    /// by `msg`.
+    #[inline]
+    pub fn new<'fd>(sockfd: impl Into<Target<'fd>>, msg: &mut msghdr) -> Self {
+        let mut sqe = target_sqe(IoringOp::Recvmsg, sockfd.into());
+        sqe.addr_or_splice_off_in.addr = ptr(msg);
+        sqe.len.len = 1;
+        Self { sqe }
+    }
+
+    /// Set the `MSG_*` flags.
+    #[inline]
+    pub fn recv_flags(mut self, flags: RecvFlags) -> Self {
+        self.sqe.op_flags.recv_flags = flags;
+        self
+    }
+
+    /// Set the `IORING_RECV*` flags.
+    #[inline]
+    pub fn ioring_recv_flags(mut self, flags: IoringRecvFlags) -> Self {
+        self.sqe.ioprio.recv_flags = flags;
+        self
+    }
+
+    /// Receive into a buffer selected by the kernel from the provided buffer
+    /// group `buf_group`, instead of the buffers described by the `msghdr`.
+    #[inline]
+    pub fn buf_group(mut self, buf_group: u16) -> Self {
+        self.sqe.flags |= IoringSqeFlags::BUFFER_SELECT;
+        self.sqe.buf.buf_group = buf_group;
+        self
+    }
+}
+
+sqe_common!(RecvMsg);
+
+/// `IORING_OP_OPENAT2`—Open a file, like [`openat2`].
+///
+/// [`openat2`]: crate::fs::openat2
+#[derive(Clone, Copy)]
+pub struct OpenAt2 {
+    sqe: io_uring_sqe,
+}
+
+impl OpenAt2 {
+    /// Construct a new `OpenAt2`, which opens `path` relative to `dirfd`.
+    #[inline]
+    pub fn new(dirfd: BorrowedFd<'_>, path: &CStr, how: &open_how) -> Self {
+        let mut sqe = new_sqe(IoringOp::Openat2, dirfd.as_raw_fd());
+        sqe.addr_or_splice_off_in.addr = ptr(path.as_ptr());
+        sqe.len.len = size_of::<open_how>() as u32;
+        sqe.off_or_addr2.addr2 = ptr(how);
+        Self { sqe }
+    }
+
+    /// Install the opened file directly into the fixed file table at
+    /// `file_index`, instead of allocating a file descriptor.
+    ///
+    /// Use [`IORING_FILE_INDEX_ALLOC`] to have the kernel pick a free slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_index` is `u32::MAX - 1`, which can't be encoded.
+    ///
+    /// [`IORING_FILE_INDEX_ALLOC`]: super::IORING_FILE_INDEX_ALLOC
+    #[inline]
+    pub fn file_index(mut self, file_index: u32) -> Self {
+        self.sqe.splice_fd_in_or_file_index.file_index = target_fixed_file(file_index);
+        self
+    }
+}
+
+sqe_common!(OpenAt2);
+
+/// `IORING_OP_STATX`—Query file metadata, like [`statx`].
+///
+/// [`statx`]: crate::fs::statx
+#[derive(Clone, Copy)]
+pub struct Statx {
+    sqe: io_uring_sqe,
+}
+
+impl Statx {
+    /// Construct a new `Statx`, which queries the fields in `mask` for `path`
+    /// relative to `dirfd` and writes them to `statxbuf`.
+    #[inline]
+    pub fn new(
+        dirfd: BorrowedFd<'_>,
+        path: &CStr,
+        flags: AtFlags,
+        mask: StatxFlags,
+        statxbuf: &mut MaybeUninit<crate::fs::Statx>,
+    ) -> Self {
+        let mut sqe = new_sqe(IoringOp::Statx, dirfd.as_raw_fd());
+        sqe.addr_or_splice_off_in.addr = ptr(path.as_ptr());
+        sqe.len.len = mask.bits();
+        sqe.off_or_addr2.addr2 = ptr(statxbuf.as_mut_ptr());
+        sqe.op_flags.statx_flags = flags;
+        Self { sqe }
+    }
+}
+
+sqe_common!(Statx);
+
+/// `IORING_OP_SPLICE`—Move data between file descriptors, like [`splice`].
+///
+/// [`splice`]: crate::io::splice
+#[derive(Clone, Copy)]
+pub struct Splice {
+    sqe: io_uring_sqe,
+}
+
+impl Splice {
+    /// Construct a new `Splice`, which moves up to `len` bytes from `fd_in`
+    /// to `fd_out`.
+    ///
+    /// An offset of `None` uses the file's current position; it must be
+    /// `None` for pipes.
+    #[inline]
+    pub fn new(
+        fd_in: BorrowedFd<'_>,
+        off_in: Option<u64>,
+        fd_out: BorrowedFd<'_>,
+        off_out: Option<u64>,
+        len: u32,
+    ) -> Self {
+        let mut sqe = new_sqe(IoringOp::Splice, fd_out.as_raw_fd());
+        sqe.splice_fd_in_or_file_index.splice_fd_in = fd_in.as_raw_fd();
+        sqe.addr_or_splice_off_in.splice_off_in = off_in.unwrap_or(u64::MAX);
+        sqe.off_or_addr2.off = off_out.unwrap_or(u64::MAX);
+        sqe.len.len = len;
+        Self { sqe }
+    }
+
+    /// Set the `SPLICE_F_*` flags.
+    #[inline]
+    pub fn splice_flags(mut self, flags: SpliceFlags) -> Self {
+        self.sqe.op_flags.splice_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Splice);
+
+/// `IORING_OP_TIMEOUT`—Complete after a timeout, or after a number of other
+/// completions.
+#[derive(Clone, Copy)]
+pub struct Timeout {
+    sqe: io_uring_sqe,
+}
+
+impl Timeout {
+    /// Construct a new `Timeout`, which completes after `timeout`.
+    #[inline]
+    pub fn new(timeout: &Timespec) -> Self {
+        let mut sqe = new_sqe(IoringOp::Timeout, -1);
+        sqe.addr_or_splice_off_in.addr = ptr(timeout);
+        sqe.len.len = 1;
+        Self { sqe }
+    }
+
+    /// Also complete once `count` other completions have been posted.
+    #[inline]
+    pub fn count(mut self, count: u32) -> Self {
+        self.sqe.off_or_addr2.off = count.into();
+        self
+    }
+
+    /// Set the `IORING_TIMEOUT_*` flags.
+    #[inline]
+    pub fn timeout_flags(mut self, flags: IoringTimeoutFlags) -> Self {
+        self.sqe.op_flags.timeout_flags = flags;
+        self
+    }
+}
+
+sqe_common!(Timeout);
+
+/// `IORING_OP_LINK_TIMEOUT`—Cancel the previous linked operation if it
+/// doesn't complete before a timeout.
+///
+/// The previous entry must have the [`IoringSqeFlags::IO_LINK`] flag set.
+#[derive(Clone, Copy)]
+pub struct LinkTimeout {
+    sqe: io_uring_sqe,
+}
+
+impl LinkTimeout {
+    /// Construct a new `LinkTimeout`, which expires after `timeout`.
+    #[inline]
+    pub fn new(timeout: &Timespec) -> Self {
+        let mut sqe = new_sqe(IoringOp::LinkTimeout, -1);
+        sqe.addr_or_splice_off_in.addr = ptr(timeout);
+        sqe.len.len = 1;
+        Self { sqe }
+    }
+
+    /// Set the `IORING_TIMEOUT_*` flags.
+    #[inline]
+    pub fn timeout_flags(mut self, flags: IoringTimeoutFlags) -> Self {
+        self.sqe.op_flags.timeout_flags = flags;
+        self
+    }
+}
+
+sqe_common!(LinkTimeout);
+
+/// `IORING_OP_CLOSE`—Close a file descriptor, like `close`.
+#[derive(Clone, Copy)]
+pub struct Close {
+    sqe: io_uring_sqe,
+}
+
+impl Close {
+    /// Construct a new `Close`, which closes `fd`.
+    ///
+    /// This takes a `BorrowedFd` because the file descriptor isn't closed
+    /// until the operation completes; the caller must ensure that it isn't
+    /// used or closed again after that.
+    #[inline]
+    pub fn new(fd: BorrowedFd<'_>) -> Self {
+        Self {
+            sqe: new_sqe(IoringOp::Close, fd.as_raw_fd()),
+        }
+    }
+
+    /// Construct a new `Close`, which clears the slot `file_index` in the
+    /// fixed file table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_index` is `u32::MAX - 1` or more, which can't be
+    /// encoded.
+    #[inline]
+    pub fn fixed(file_index: u32) -> Self {
+        let mut sqe = new_sqe(IoringOp::Close, 0);
+        sqe.splice_fd_in_or_file_index.file_index = fixed_file_slot(file_index);
+        Self { sqe }
+    }
+}
+
+sqe_common!(Close);
+
+/// `IORING_OP_PROVIDE_BUFFERS`—Provide buffers for operations that select
+/// their buffer with [`IoringSqeFlags::BUFFER_SELECT`].
+#[derive(Clone, Copy)]
+pub struct ProvideBuffers {
+    sqe: io_uring_sqe,
+}
+
+impl ProvideBuffers {
+    /// Construct a new `ProvideBuffers`, which divides `bufs` into buffers of
+    /// `buf_len` bytes each, and adds them to the buffer group `buf_group`,
+    /// with buffer IDs starting at `bid`.
+    ///
+    /// Any bytes at the end of `bufs` not making up a whole buffer are
+    /// unused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf_len` is zero.
+    #[inline]
+    pub fn new(bufs: &mut [u8], buf_len: u32, buf_group: u16, bid: u16) -> Self {
+        let nbufs = bufs.len() / buf_len as usize;
+
+        let mut sqe = new_sqe(IoringOp::ProvideBuffers, nbufs as i32);
+        sqe.addr_or_splice_off_in.addr = ptr(bufs.as_mut_ptr());
+        sqe.len.len = buf_len;
+        sqe.off_or_addr2.off = bid.into();
+        sqe.buf.buf_group = buf_group;
+        Self { sqe }
+    }
+}
+
+sqe_common!(ProvideBuffers);
+
+/// `IORING_OP_MSG_RING`—Post a completion to another io_uring.
+#[derive(Clone, Copy)]
+pub struct MsgRing {
+    sqe: io_uring_sqe,
+}
+
+impl MsgRing {
+    /// Construct a new `MsgRing`, which posts a completion with the given
+    /// `res` and `user_data` to the ring `ring_fd`.
+    #[inline]
+    pub fn new(ring_fd: BorrowedFd<'_>, res: i32, user_data: io_uring_user_data) -> Self {
+        let mut sqe = new_sqe(IoringOp::MsgRing, ring_fd.as_raw_fd());
+        sqe.addr_or_splice_off_in.msgring_cmd = IoringMsgringCmds::Data;
+        sqe.len.len = res as u32;
+        sqe.off_or_addr2.user_data = user_data;
+        Self { sqe }
+    }
+
+    /// Set the `IORING_MSG_RING_*` flags.
+    #[inline]
+    pub fn msg_ring_flags(mut self, flags: IoringMsgringFlags) -> Self {
+        self.sqe.op_flags.msg_ring_flags = flags;
+        self
+    }
+}
+
+sqe_common!(MsgRing);
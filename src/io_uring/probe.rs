@@ -0,0 +1,159 @@
+//! `io_uring_probe` and `ProbeResult`.
+
+use super::{io_uring_register, IoringOp, IoringOpFlags, IoringRegisterOp};
+use crate::fd::AsFd;
+use crate::io;
+use core::fmt;
+
+/// The maximum number of operations the kernel can report in a probe.
+const PROBE_OPS: usize = 256;
+
+/// `io_uring_probe_op`, with `op` as a raw integer, since the kernel may
+/// report operations that [`IoringOp`] doesn't know about yet.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct RawProbeOp {
+    op: u8,
+    resv: u8,
+    flags: u16,
+    resv2: u32,
+}
+
+/// `io_uring_probe`, with space for the maximum number of operations.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct RawProbe {
+    last_op: u8,
+    ops_len: u8,
+    resv: u16,
+    resv2: [u32; 3],
+    ops: [RawProbeOp; PROBE_OPS],
+}
+
+/// The set of io_uring operations supported by the running kernel, as
+/// returned by [`io_uring_probe`].
+#[derive(Clone)]
+pub struct ProbeResult {
+    raw: RawProbe,
+}
+
+impl ProbeResult {
+    /// Return `true` if the kernel supports `op`.
+    #[inline]
+    pub fn is_supported(&self, op: IoringOp) -> bool {
+        let index = op as usize;
+        index < usize::from(self.raw.ops_len)
+            && IoringOpFlags::from_bits_truncate(self.raw.ops[index].flags)
+                .contains(IoringOpFlags::SUPPORTED)
+    }
+
+    /// Return the raw value of the last operation the kernel knows about.
+    ///
+    /// This may be newer than any [`IoringOp`] known to rustix.
+    #[inline]
+    pub fn last_op(&self) -> u8 {
+        self.raw.last_op
+    }
+}
+
+impl fmt::Debug for ProbeResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ops = &self.raw.ops[..usize::from(self.raw.ops_len)];
+        f.debug_struct("ProbeResult")
+            .field("last_op", &self.raw.last_op)
+            .field("supported", &SupportedOps(ops))
+            .finish()
+    }
+}
+
+/// Format the raw values of the supported operations.
+struct SupportedOps<'a>(&'a [RawProbeOp]);
+
+impl<'a> fmt::Debug for SupportedOps<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.0
+                    .iter()
+                    .filter(|op| {
+                        IoringOpFlags::from_bits_truncate(op.flags)
+                            .contains(IoringOpFlags::SUPPORTED)
+                    })
+                    .map(|op| op.op),
+            )
+            .finish()
+    }
+}
+
+/// `io_uring_register(fd, IORING_REGISTER_PROBE, probe, 256)`—Query which
+/// io_uring operations the running kernel supports.
+///
+/// This requires Linux 5.6 or later; older kernels fail with
+/// [`io::Errno::INVAL`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::io_uring::{io_uring_probe, IoUring, IoringOp};
+///
+/// let ring = IoUring::new(1)?;
+/// let probe = io_uring_probe(&ring)?;
+/// if probe.is_supported(IoringOp::Openat2) {
+///     // Open files through the ring.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+pub fn io_uring_probe<Fd: AsFd>(fd: Fd) -> io::Result<ProbeResult> {
+    let mut raw = RawProbe {
+        last_op: 0,
+        ops_len: 0,
+        resv: 0,
+        resv2: [0; 3],
+        ops: [RawProbeOp {
+            op: 0,
+            resv: 0,
+            flags: 0,
+            resv2: 0,
+        }; PROBE_OPS],
+    };
+
+    // SAFETY: `raw` is a zeroed `io_uring_probe` with space for `PROBE_OPS`
+    // entries, and its fields are plain integers, so any values the kernel
+    // writes are valid.
+    unsafe {
+        io_uring_register(
+            fd,
+            IoringRegisterOp::RegisterProbe,
+            (&mut raw as *mut RawProbe).cast(),
+            PROBE_OPS as u32,
+        )?;
+    }
+
+    Ok(ProbeResult { raw })
+}
+
+#[test]
+fn probe_layouts() {
+    use super::{io_uring_probe, io_uring_probe_op};
+    use core::mem::{align_of, size_of};
+
+    assert_eq!(
+        (size_of::<RawProbeOp>(), align_of::<RawProbeOp>()),
+        (
+            size_of::<io_uring_probe_op>(),
+            align_of::<io_uring_probe_op>()
+        )
+    );
+    assert_eq!(
+        size_of::<RawProbe>(),
+        size_of::<io_uring_probe>() + PROBE_OPS * size_of::<io_uring_probe_op>()
+    );
+    assert_eq!(align_of::<RawProbe>(), align_of::<io_uring_probe>());
+}
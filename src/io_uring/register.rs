@@ -0,0 +1,483 @@
+//! `RegisteredBuffers`, `FixedFiles`, and `BufRing`, owned registrations of
+//! io_uring resources.
+//!
+//! Each of these holds a duplicate of the io_uring file descriptor, so that
+//! it can unregister its resources when dropped without borrowing the
+//! [`IoUring`] it was registered with.
+//!
+//! [`IoUring`]: super::IoUring
+
+use super::ring::Mmap;
+use super::{
+    io_uring_buf, io_uring_buf_reg, io_uring_cqe, io_uring_register, io_uring_rsrc_register,
+    io_uring_rsrc_update2, IoringCqeFlags, IoringRegisterOp, IoringRsrcFlags,
+    IORING_CQE_BUFFER_SHIFT,
+};
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd};
+use crate::io::{self, fcntl_dupfd_cloexec, IoSliceMut};
+use core::fmt;
+use core::mem::size_of;
+use core::ops::{Deref, DerefMut};
+use core::ptr::null;
+use core::slice;
+use core::sync::atomic::{AtomicU16, Ordering};
+
+/// Buffers registered with `IORING_REGISTER_BUFFERS`, for use with
+/// [`opcode::ReadFixed`] and [`opcode::WriteFixed`].
+///
+/// The buffers are borrowed for as long as they're registered, and are
+/// unregistered when this is dropped.
+///
+/// [`opcode::ReadFixed`]: super::opcode::ReadFixed
+/// [`opcode::WriteFixed`]: super::opcode::WriteFixed
+pub struct RegisteredBuffers<'buf> {
+    ring: OwnedFd,
+    bufs: &'buf mut [IoSliceMut<'buf>],
+}
+
+impl<'buf> RegisteredBuffers<'buf> {
+    /// `io_uring_register(ring, IORING_REGISTER_BUFFERS, bufs, bufs.len())`—
+    /// Register `bufs` as fixed buffers.
+    ///
+    /// The index of each buffer in `bufs` is its `buf_index`.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+    pub fn new<Fd: AsFd>(ring: Fd, bufs: &'buf mut [IoSliceMut<'buf>]) -> io::Result<Self> {
+        let ring = fcntl_dupfd_cloexec(ring, 0)?;
+
+        // SAFETY: `IoSliceMut` is ABI-compatible with `iovec`, and the
+        // buffers are borrowed until they're unregistered in `drop`.
+        unsafe {
+            io_uring_register(
+                &ring,
+                IoringRegisterOp::RegisterBuffers,
+                bufs.as_ptr().cast(),
+                bufs.len() as u32,
+            )?;
+        }
+
+        Ok(Self { ring, bufs })
+    }
+
+    /// Return the registered buffers.
+    ///
+    /// The kernel may write to these buffers while fixed-buffer operations
+    /// are in flight, so they should only be accessed once those operations
+    /// have completed.
+    #[inline]
+    pub fn buffers(&mut self) -> &mut [IoSliceMut<'buf>] {
+        self.bufs
+    }
+}
+
+impl<'buf> Drop for RegisteredBuffers<'buf> {
+    fn drop(&mut self) {
+        // SAFETY: `IORING_UNREGISTER_BUFFERS` takes no argument.
+        unsafe {
+            let _ = io_uring_register(&self.ring, IoringRegisterOp::UnregisterBuffers, null(), 0);
+        }
+    }
+}
+
+impl<'buf> fmt::Debug for RegisteredBuffers<'buf> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredBuffers")
+            .field("ring", &self.ring)
+            .field("len", &self.bufs.len())
+            .finish()
+    }
+}
+
+/// A table of files registered with `IORING_REGISTER_FILES`, for use with
+/// [`opcode::Target::Fixed`].
+///
+/// The kernel holds its own references to the registered files, so they
+/// don't need to remain open. The table is unregistered when this is dropped.
+///
+/// [`opcode::Target::Fixed`]: super::opcode::Target::Fixed
+#[derive(Debug)]
+pub struct FixedFiles {
+    ring: OwnedFd,
+    len: u32,
+}
+
+impl FixedFiles {
+    /// `io_uring_register(ring, IORING_REGISTER_FILES, fds, fds.len())`—
+    /// Register `fds` as fixed files.
+    ///
+    /// The index of each file descriptor in `fds` is its fixed file index.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+    pub fn new<Fd: AsFd>(ring: Fd, fds: &[BorrowedFd<'_>]) -> io::Result<Self> {
+        let ring = fcntl_dupfd_cloexec(ring, 0)?;
+
+        // SAFETY: `BorrowedFd` is `repr(transparent)` over `RawFd`, and the
+        // kernel takes its own references to the files.
+        unsafe {
+            io_uring_register(
+                &ring,
+                IoringRegisterOp::RegisterFiles,
+                fds.as_ptr().cast(),
+                fds.len() as u32,
+            )?;
+        }
+
+        Ok(Self {
+            ring,
+            len: fds.len() as u32,
+        })
+    }
+
+    /// `io_uring_register(ring, IORING_REGISTER_FILES2, ...)`—Register a
+    /// table of `len` empty slots, to be filled in with
+    /// [`FixedFiles::update`] or by operations that install files directly.
+    ///
+    /// This requires Linux 5.19 or later.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+    pub fn sparse<Fd: AsFd>(ring: Fd, len: u32) -> io::Result<Self> {
+        let ring = fcntl_dupfd_cloexec(ring, 0)?;
+
+        let reg = io_uring_rsrc_register {
+            nr: len,
+            flags: IoringRsrcFlags::REGISTER_SPARSE,
+            ..Default::default()
+        };
+
+        // SAFETY: `reg` is a valid `io_uring_rsrc_register` with no data.
+        unsafe {
+            io_uring_register(
+                &ring,
+                IoringRegisterOp::RegisterFiles2,
+                (&reg as *const io_uring_rsrc_register).cast(),
+                size_of::<io_uring_rsrc_register>() as u32,
+            )?;
+        }
+
+        Ok(Self { ring, len })
+    }
+
+    /// Return the number of slots in the table.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Return `true` if the table has no slots.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `io_uring_register(ring, IORING_REGISTER_FILES_UPDATE2, ...)`—Replace
+    /// the slots starting at `offset` with `fds`.
+    ///
+    /// `None` entries clear their slot. Returns the number of slots updated.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+    pub fn update(&self, offset: u32, fds: &[Option<BorrowedFd<'_>>]) -> io::Result<u32> {
+        // Convert to raw file descriptors in chunks, to avoid allocating.
+        const CHUNK: usize = 32;
+        let mut raw = [-1 as RawFd; CHUNK];
+        let mut updated = 0;
+
+        for chunk in fds.chunks(CHUNK) {
+            for (raw, fd) in raw.iter_mut().zip(chunk) {
+                *raw = fd.map_or(-1, |fd| fd.as_raw_fd());
+            }
+
+            let update = io_uring_rsrc_update2 {
+                offset: offset.checked_add(updated).ok_or(io::Errno::INVAL)?,
+                data: raw.as_ptr() as usize as u64,
+                nr: chunk.len() as u32,
+                ..Default::default()
+            };
+
+            // SAFETY: `update` points to `nr` valid raw file descriptors,
+            // and the kernel takes its own references to the files.
+            let n = unsafe {
+                io_uring_register(
+                    &self.ring,
+                    IoringRegisterOp::RegisterFilesUpdate2,
+                    (&update as *const io_uring_rsrc_update2).cast(),
+                    size_of::<io_uring_rsrc_update2>() as u32,
+                )?
+            };
+            updated += n;
+            if n as usize != chunk.len() {
+                break;
+            }
+        }
+
+        Ok(updated)
+    }
+}
+
+impl Drop for FixedFiles {
+    fn drop(&mut self) {
+        // SAFETY: `IORING_UNREGISTER_FILES` takes no argument.
+        unsafe {
+            let _ = io_uring_register(&self.ring, IoringRegisterOp::UnregisterFiles, null(), 0);
+        }
+    }
+}
+
+/// A provided buffer ring registered with `IORING_REGISTER_PBUF_RING`.
+///
+/// This owns a ring of `entries` buffers of `buf_len` bytes each, in the
+/// buffer group `bgid`. Operations which select a buffer from the group take
+/// one from the ring, and report its ID in their completion; pass the
+/// completion to [`BufRing::get`] to access the data, and the buffer is
+/// returned to the ring when the resulting [`BufRingEntry`] is dropped.
+///
+/// This requires Linux 5.19 or later.
+pub struct BufRing {
+    ring: OwnedFd,
+    bgid: u16,
+    entries: u16,
+    buf_len: u32,
+    tail: u16,
+    ring_map: Mmap,
+    bufs_map: Mmap,
+}
+
+// SAFETY: The ring and buffer memory is owned by the `BufRing`, and all
+// mutation of it goes through `&mut self`.
+unsafe impl Send for BufRing {}
+// SAFETY: Methods taking `&self` don't access the ring or buffer memory.
+unsafe impl Sync for BufRing {}
+
+impl BufRing {
+    /// Register a new provided buffer ring with buffer group ID `bgid`,
+    /// containing `entries` buffers of `buf_len` bytes each.
+    ///
+    /// `entries` must be a power of two, no greater than 32768.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+    pub fn new<Fd: AsFd>(ring: Fd, bgid: u16, entries: u16, buf_len: u32) -> io::Result<Self> {
+        if !entries.is_power_of_two() || entries > 32768 || buf_len == 0 {
+            return Err(io::Errno::INVAL);
+        }
+        let ring = fcntl_dupfd_cloexec(ring, 0)?;
+
+        let ring_map = Mmap::anonymous(usize::from(entries) * size_of::<io_uring_buf>())?;
+        let bufs_map = Mmap::anonymous(usize::from(entries) * buf_len as usize)?;
+
+        let reg = io_uring_buf_reg {
+            ring_addr: ring_map.ptr as usize as u64,
+            ring_entries: entries.into(),
+            bgid,
+            ..Default::default()
+        };
+
+        // SAFETY: `reg` describes a page-aligned ring of `entries` buffers
+        // that stays mapped until it's unregistered in `drop`.
+        unsafe {
+            io_uring_register(
+                &ring,
+                IoringRegisterOp::RegisterPbufRing,
+                (&reg as *const io_uring_buf_reg).cast(),
+                1,
+            )?;
+        }
+
+        let mut buf_ring = Self {
+            ring,
+            bgid,
+            entries,
+            buf_len,
+            tail: 0,
+            ring_map,
+            bufs_map,
+        };
+        for bid in 0..entries {
+            buf_ring.push(bid);
+        }
+        buf_ring.publish();
+        Ok(buf_ring)
+    }
+
+    /// Return the buffer group ID.
+    #[inline]
+    pub fn bgid(&self) -> u16 {
+        self.bgid
+    }
+
+    /// Return the number of buffers in the ring.
+    #[inline]
+    pub fn entries(&self) -> u16 {
+        self.entries
+    }
+
+    /// Return the length of each buffer.
+    #[inline]
+    pub fn buf_len(&self) -> u32 {
+        self.buf_len
+    }
+
+    /// Return the buffer selected by the operation which produced `cqe`.
+    ///
+    /// Returns `None` if the completion doesn't have the
+    /// [`IoringCqeFlags::BUFFER`] flag set.
+    ///
+    /// # Safety
+    ///
+    /// `cqe` must be the completion of an operation which selected a buffer
+    /// from this ring, and must not have been passed to `get` before, since
+    /// the buffer is returned to the kernel when the entry is dropped.
+    pub unsafe fn get(&mut self, cqe: &io_uring_cqe) -> Option<BufRingEntry<'_>> {
+        if !cqe.flags.contains(IoringCqeFlags::BUFFER) {
+            return None;
+        }
+        let bid = (cqe.flags.bits() >> IORING_CQE_BUFFER_SHIFT) as u16;
+        let len = if cqe.res > 0 { cqe.res as usize } else { 0 };
+        debug_assert!(bid < self.entries && len <= self.buf_len as usize);
+
+        Some(BufRingEntry {
+            buf_ring: self,
+            bid,
+            len,
+        })
+    }
+
+    /// Return a pointer to the buffer with ID `bid`.
+    #[inline]
+    fn buf_ptr(&self, bid: u16) -> *mut u8 {
+        // SAFETY: `bid` is less than `entries`, so the pointer is within the
+        // buffer mapping.
+        unsafe {
+            self.bufs_map
+                .ptr
+                .cast::<u8>()
+                .add(usize::from(bid) * self.buf_len as usize)
+        }
+    }
+
+    /// Add the buffer with ID `bid` at the tail of the ring, without making it
+    /// visible to the kernel.
+    fn push(&mut self, bid: u16) {
+        let index = usize::from(self.tail & (self.entries - 1));
+
+        // SAFETY: `index` is within the ring. Write the fields individually,
+        // because the `resv` field of the first entry holds the ring's tail.
+        unsafe {
+            let buf = self.ring_map.ptr.cast::<io_uring_buf>().add(index);
+            (*buf).addr = self.buf_ptr(bid) as usize as u64;
+            (*buf).len = self.buf_len;
+            (*buf).bid = bid;
+        }
+        self.tail = self.tail.wrapping_add(1);
+    }
+
+    /// Make pushed buffers visible to the kernel.
+    fn publish(&mut self) {
+        // SAFETY: The tail overlays the `resv` field of the first entry. The
+        // release store orders our entry writes before the kernel observes
+        // the tail.
+        unsafe {
+            let tail = &(*self.ring_map.ptr.cast::<io_uring_buf>()).resv;
+            (*(tail as *const u16).cast::<AtomicU16>()).store(self.tail, Ordering::Release);
+        }
+    }
+}
+
+impl Drop for BufRing {
+    fn drop(&mut self) {
+        let reg = io_uring_buf_reg {
+            bgid: self.bgid,
+            ..Default::default()
+        };
+
+        // SAFETY: `reg` identifies this ring's buffer group. The mappings are
+        // unmapped after this, when the fields are dropped.
+        unsafe {
+            let _ = io_uring_register(
+                &self.ring,
+                IoringRegisterOp::UnregisterPbufRing,
+                (&reg as *const io_uring_buf_reg).cast(),
+                1,
+            );
+        }
+    }
+}
+
+impl fmt::Debug for BufRing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufRing")
+            .field("ring", &self.ring)
+            .field("bgid", &self.bgid)
+            .field("entries", &self.entries)
+            .field("buf_len", &self.buf_len)
+            .finish()
+    }
+}
+
+/// A buffer taken from a [`BufRing`] by a completed operation.
+///
+/// This dereferences to the data the operation wrote into the buffer, and
+/// returns the buffer to the ring when dropped.
+pub struct BufRingEntry<'a> {
+    buf_ring: &'a mut BufRing,
+    bid: u16,
+    len: usize,
+}
+
+impl<'a> BufRingEntry<'a> {
+    /// Return the buffer ID.
+    #[inline]
+    pub fn bid(&self) -> u16 {
+        self.bid
+    }
+}
+
+impl<'a> Deref for BufRingEntry<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        // SAFETY: The kernel has handed this buffer back to us, and has
+        // written `len` bytes of it.
+        unsafe { slice::from_raw_parts(self.buf_ring.buf_ptr(self.bid), self.len) }
+    }
+}
+
+impl<'a> DerefMut for BufRingEntry<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: The kernel has handed this buffer back to us, and has
+        // written `len` bytes of it.
+        unsafe { slice::from_raw_parts_mut(self.buf_ring.buf_ptr(self.bid), self.len) }
+    }
+}
+
+impl<'a> Drop for BufRingEntry<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        self.buf_ring.push(self.bid);
+        self.buf_ring.publish();
+    }
+}
+
+impl<'a> fmt::Debug for BufRingEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufRingEntry")
+            .field("bid", &self.bid)
+            .field("len", &self.len)
+            .finish()
+    }
+}
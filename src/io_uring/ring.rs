@@ -0,0 +1,606 @@
+//! `IoUring`, an owned io_uring instance with mapped submission and
+//! completion rings.
+
+use super::{
+    io_uring_cqe, io_uring_enter, io_uring_params, io_uring_setup, io_uring_sqe, IoringCqFlags,
+    IoringEnterFlags, IoringFeatureFlags, IoringSetupFlags, IoringSqFlags, IORING_OFF_CQ_RING,
+    IORING_OFF_SQES, IORING_OFF_SQ_RING,
+};
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::io;
+use crate::mm::{mmap, mmap_anonymous, munmap, MapFlags, ProtFlags};
+use core::ffi::c_void;
+use core::fmt;
+use core::mem::size_of;
+use core::ptr::{null, null_mut};
+use core::sync::atomic::{fence, AtomicU32, Ordering};
+
+/// An owned io_uring instance.
+///
+/// This owns the io_uring file descriptor along with memory mappings of its
+/// submission queue, completion queue, and submission queue entry array, and
+/// unmaps them when dropped.
+///
+/// Entries are pushed with [`SubmissionQueue::push`], submitted to the kernel
+/// with [`IoUring::submit`] or [`IoUring::submit_and_wait`], and completions
+/// are consumed by iterating over a [`CompletionQueue`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::io_uring::{io_uring_sqe, IoUring, IoringOp};
+///
+/// let mut ring = IoUring::new(8)?;
+///
+/// let mut sqe = io_uring_sqe::default();
+/// sqe.opcode = IoringOp::Nop;
+///
+/// // SAFETY: A `Nop` doesn't reference any memory or file descriptors.
+/// unsafe { ring.submission().push(&sqe)? };
+/// ring.submit_and_wait(1)?;
+///
+/// for cqe in ring.completion() {
+///     assert_eq!(cqe.res, 0);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct IoUring {
+    sq: RawSubmissionQueue,
+    cq: RawCompletionQueue,
+    params: io_uring_params,
+    // The mappings are only held so that they're unmapped on drop; they're
+    // accessed through the pointers in `sq` and `cq`.
+    _sqes_map: Mmap,
+    _cq_map: Option<Mmap>,
+    _sq_map: Mmap,
+    fd: OwnedFd,
+}
+
+// SAFETY: The ring memory is owned by the `IoUring`, and all mutation of it
+// goes through `&mut self`, or through atomic accesses to fields that the
+// kernel also accesses concurrently.
+unsafe impl Send for IoUring {}
+// SAFETY: Methods taking `&self` only perform atomic loads and `io_uring_enter`.
+unsafe impl Sync for IoUring {}
+
+impl IoUring {
+    /// Create a new io_uring instance with at least `entries` submission
+    /// queue entries and default parameters.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_setup.2.en
+    #[inline]
+    pub fn new(entries: u32) -> io::Result<Self> {
+        Self::with_params(entries, io_uring_params::default())
+    }
+
+    /// Create a new io_uring instance with at least `entries` submission
+    /// queue entries, using the given parameters.
+    ///
+    /// The `flags`, `sq_thread_cpu`, `sq_thread_idle`, `cq_entries`, and
+    /// `wq_fd` fields of `params` are passed to the kernel. The parameters
+    /// as filled in by the kernel are available from [`IoUring::params`].
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_setup.2.en
+    pub fn with_params(entries: u32, mut params: io_uring_params) -> io::Result<Self> {
+        let fd = io_uring_setup(entries, &mut params)?;
+
+        let sqe_shift = if params.flags.contains(IoringSetupFlags::SQE128) {
+            1
+        } else {
+            0
+        };
+        let cqe_shift = if params.flags.contains(IoringSetupFlags::CQE32) {
+            1
+        } else {
+            0
+        };
+
+        let mut sq_len =
+            params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+        let mut cq_len = params.cq_off.cqes as usize
+            + ((params.cq_entries as usize) << cqe_shift) * size_of::<io_uring_cqe>();
+        let sqes_len = ((params.sq_entries as usize) << sqe_shift) * size_of::<io_uring_sqe>();
+
+        // With `IORING_FEAT_SINGLE_MMAP`, the submission and completion rings
+        // share a single mapping, which must be large enough for both.
+        let single_mmap = params.features.contains(IoringFeatureFlags::SINGLE_MMAP);
+        if single_mmap {
+            sq_len = sq_len.max(cq_len);
+            cq_len = sq_len;
+        }
+
+        let sq_map = Mmap::new(&fd, sq_len, IORING_OFF_SQ_RING)?;
+        let cq_map = if single_mmap {
+            None
+        } else {
+            Some(Mmap::new(&fd, cq_len, IORING_OFF_CQ_RING)?)
+        };
+        let sqes_map = Mmap::new(&fd, sqes_len, IORING_OFF_SQES)?;
+
+        // SAFETY: The offsets are provided by the kernel, and point within
+        // the mappings we just created, which are sized according to the
+        // kernel's `io_uring_params`.
+        unsafe {
+            let sq_ptr = sq_map.ptr.cast::<u8>();
+            let cq_ptr = match &cq_map {
+                Some(cq_map) => cq_map.ptr.cast::<u8>(),
+                None => sq_ptr,
+            };
+            let off = &params.sq_off;
+            let sq = RawSubmissionQueue {
+                head: sq_ptr.add(off.head as usize).cast(),
+                tail: sq_ptr.add(off.tail as usize).cast(),
+                ring_mask: *sq_ptr.add(off.ring_mask as usize).cast::<u32>(),
+                ring_entries: *sq_ptr.add(off.ring_entries as usize).cast::<u32>(),
+                flags: sq_ptr.add(off.flags as usize).cast(),
+                dropped: sq_ptr.add(off.dropped as usize).cast(),
+                sqes: sqes_map.ptr.cast(),
+                sqe_shift,
+            };
+
+            // Use an identity mapping from the index array to the entries,
+            // so that entries can be filled in directly in ring order.
+            let array = sq_ptr.add(off.array as usize).cast::<u32>();
+            for i in 0..sq.ring_entries {
+                array.add(i as usize).write(i);
+            }
+
+            let off = &params.cq_off;
+            let cq = RawCompletionQueue {
+                head: cq_ptr.add(off.head as usize).cast(),
+                tail: cq_ptr.add(off.tail as usize).cast(),
+                ring_mask: *cq_ptr.add(off.ring_mask as usize).cast::<u32>(),
+                ring_entries: *cq_ptr.add(off.ring_entries as usize).cast::<u32>(),
+                overflow: cq_ptr.add(off.overflow as usize).cast(),
+                flags: if off.flags == 0 {
+                    null()
+                } else {
+                    cq_ptr.add(off.flags as usize).cast()
+                },
+                cqes: cq_ptr.add(off.cqes as usize).cast(),
+                cqe_shift,
+            };
+
+            Ok(Self {
+                sq,
+                cq,
+                params,
+                _sqes_map: sqes_map,
+                _cq_map: cq_map,
+                _sq_map: sq_map,
+                fd,
+            })
+        }
+    }
+
+    /// Return the parameters of this io_uring instance, as filled in by the
+    /// kernel.
+    #[inline]
+    pub fn params(&self) -> &io_uring_params {
+        &self.params
+    }
+
+    /// Return the submission queue.
+    #[inline]
+    pub fn submission(&mut self) -> SubmissionQueue<'_> {
+        SubmissionQueue::new(&self.sq)
+    }
+
+    /// Return the completion queue.
+    #[inline]
+    pub fn completion(&mut self) -> CompletionQueue<'_> {
+        CompletionQueue::new(&self.cq)
+    }
+
+    /// Return both the submission queue and the completion queue, so that
+    /// they may be used at the same time.
+    #[inline]
+    pub fn split(&mut self) -> (SubmissionQueue<'_>, CompletionQueue<'_>) {
+        (
+            SubmissionQueue::new(&self.sq),
+            CompletionQueue::new(&self.cq),
+        )
+    }
+
+    /// Submit all pushed submission queue entries to the kernel, without
+    /// waiting for any completions.
+    ///
+    /// Returns the number of entries submitted.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_enter.2.en
+    #[inline]
+    pub fn submit(&self) -> io::Result<u32> {
+        self.submit_and_wait(0)
+    }
+
+    /// Submit all pushed submission queue entries to the kernel, and wait
+    /// for at least `want` completions.
+    ///
+    /// Returns the number of entries submitted.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man.archlinux.org/man/io_uring_enter.2.en
+    pub fn submit_and_wait(&self, want: u32) -> io::Result<u32> {
+        let to_submit = self.sq.pending();
+        let mut flags = IoringEnterFlags::empty();
+
+        let sq_flags = self.sq.flags();
+        if want > 0 || sq_flags.intersects(IoringSqFlags::CQ_OVERFLOW | IoringSqFlags::TASKRUN) {
+            flags |= IoringEnterFlags::GETEVENTS;
+        }
+
+        if self.params.flags.contains(IoringSetupFlags::SQPOLL) {
+            // The kernel thread consumes the submission queue on its own, so
+            // only enter the kernel if it needs to be woken up. Use a full
+            // fence so that our tail update is ordered before the load of the
+            // flags, which the kernel orders the other way.
+            fence(Ordering::SeqCst);
+            if self.sq.flags().contains(IoringSqFlags::NEED_WAKEUP) {
+                flags |= IoringEnterFlags::SQ_WAKEUP;
+            } else if flags.is_empty() {
+                return Ok(to_submit);
+            }
+        }
+
+        // SAFETY: We don't pass an extended argument. The entries being
+        // submitted were pushed with `SubmissionQueue::push`, whose callers
+        // are responsible for the validity of the resources they reference.
+        unsafe { io_uring_enter(&self.fd, to_submit, want, flags, null(), 0) }
+    }
+}
+
+impl AsFd for IoUring {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl fmt::Debug for IoUring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IoUring")
+            .field("fd", &self.fd)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+/// The submission queue of an [`IoUring`].
+///
+/// Pushed entries become visible to the kernel when the queue is
+/// [synchronized] or dropped.
+///
+/// [synchronized]: SubmissionQueue::sync
+pub struct SubmissionQueue<'ring> {
+    raw: &'ring RawSubmissionQueue,
+    head: u32,
+    tail: u32,
+}
+
+impl<'ring> SubmissionQueue<'ring> {
+    fn new(raw: &'ring RawSubmissionQueue) -> Self {
+        // SAFETY: `head` and `tail` point into the mapped ring. We're the only
+        // writer of `tail`, so a relaxed load is sufficient for it.
+        unsafe {
+            Self {
+                raw,
+                head: (*raw.head).load(Ordering::Acquire),
+                tail: (*raw.tail).load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// Push an entry onto the submission queue.
+    ///
+    /// Returns `Err(io::Errno::AGAIN)` if the queue is full; call
+    /// [`IoUring::submit`] to make room.
+    ///
+    /// # Safety
+    ///
+    /// The kernel will access any buffers, file descriptors, and other
+    /// resources referenced by `sqe` until its completion is reaped. Users are
+    /// responsible for ensuring that these remain valid until then.
+    pub unsafe fn push(&mut self, sqe: &io_uring_sqe) -> io::Result<()> {
+        if self.is_full() {
+            return Err(io::Errno::AGAIN);
+        }
+
+        let index = (self.tail & self.raw.ring_mask) as usize;
+        self.raw.sqes.add(index << self.raw.sqe_shift).write(*sqe);
+        self.tail = self.tail.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Publish pushed entries to the kernel, and reload the kernel's position
+    /// in the queue.
+    #[inline]
+    pub fn sync(&mut self) {
+        // SAFETY: `head` and `tail` point into the mapped ring. The release
+        // store orders our entry writes before the kernel observes the tail.
+        unsafe {
+            (*self.raw.tail).store(self.tail, Ordering::Release);
+            self.head = (*self.raw.head).load(Ordering::Acquire);
+        }
+    }
+
+    /// Return the number of entries the queue can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.raw.ring_entries as usize
+    }
+
+    /// Return the number of entries in the queue that the kernel hasn't
+    /// consumed yet.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tail.wrapping_sub(self.head) as usize
+    }
+
+    /// Return `true` if the queue contains no unconsumed entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return `true` if the queue has no room for more entries.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Return the number of invalid entries the kernel has dropped.
+    #[inline]
+    pub fn dropped(&self) -> u32 {
+        // SAFETY: `dropped` points into the mapped ring.
+        unsafe { (*self.raw.dropped).load(Ordering::Acquire) }
+    }
+
+    /// Return the `IORING_SQ_*` flags set by the kernel.
+    #[inline]
+    pub fn flags(&self) -> IoringSqFlags {
+        self.raw.flags()
+    }
+}
+
+impl<'ring> Drop for SubmissionQueue<'ring> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `tail` points into the mapped ring.
+        unsafe { (*self.raw.tail).store(self.tail, Ordering::Release) }
+    }
+}
+
+impl<'ring> fmt::Debug for SubmissionQueue<'ring> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmissionQueue")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// The completion queue of an [`IoUring`].
+///
+/// This is an iterator over completion queue entries. Consumed entries are
+/// released back to the kernel when the queue is [synchronized] or dropped.
+///
+/// [synchronized]: CompletionQueue::sync
+pub struct CompletionQueue<'ring> {
+    raw: &'ring RawCompletionQueue,
+    head: u32,
+    tail: u32,
+}
+
+impl<'ring> CompletionQueue<'ring> {
+    fn new(raw: &'ring RawCompletionQueue) -> Self {
+        // SAFETY: `head` and `tail` point into the mapped ring. We're the only
+        // writer of `head`, so a relaxed load is sufficient for it.
+        unsafe {
+            Self {
+                raw,
+                head: (*raw.head).load(Ordering::Relaxed),
+                tail: (*raw.tail).load(Ordering::Acquire),
+            }
+        }
+    }
+
+    /// Release consumed entries to the kernel, and load any new entries the
+    /// kernel has posted.
+    #[inline]
+    pub fn sync(&mut self) {
+        // SAFETY: `head` and `tail` point into the mapped ring. The release
+        // store orders our entry reads before the kernel reuses the slots.
+        unsafe {
+            (*self.raw.head).store(self.head, Ordering::Release);
+            self.tail = (*self.raw.tail).load(Ordering::Acquire);
+        }
+    }
+
+    /// Return the number of entries the queue can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.raw.ring_entries as usize
+    }
+
+    /// Return the number of entries available to be consumed.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tail.wrapping_sub(self.head) as usize
+    }
+
+    /// Return `true` if there are no entries available to be consumed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the number of completions the kernel couldn't post because the
+    /// queue was full.
+    #[inline]
+    pub fn overflow(&self) -> u32 {
+        // SAFETY: `overflow` points into the mapped ring.
+        unsafe { (*self.raw.overflow).load(Ordering::Acquire) }
+    }
+
+    /// Return the `IORING_CQ_*` flags.
+    #[inline]
+    pub fn flags(&self) -> IoringCqFlags {
+        if self.raw.flags.is_null() {
+            return IoringCqFlags::empty();
+        }
+        // SAFETY: `flags` is non-null and points into the mapped ring.
+        IoringCqFlags::from_bits_truncate(unsafe { (*self.raw.flags).load(Ordering::Acquire) })
+    }
+}
+
+impl<'ring> Iterator for CompletionQueue<'ring> {
+    type Item = io_uring_cqe;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        let index = (self.head & self.raw.ring_mask) as usize;
+        // SAFETY: The index is masked to be within the ring, and the acquire
+        // load of the tail ensures the kernel's write of the entry is visible.
+        let cqe = unsafe { self.raw.cqes.add(index << self.raw.cqe_shift).read() };
+        self.head = self.head.wrapping_add(1);
+        Some(cqe)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<'ring> ExactSizeIterator for CompletionQueue<'ring> {}
+
+impl<'ring> Drop for CompletionQueue<'ring> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `head` points into the mapped ring.
+        unsafe { (*self.raw.head).store(self.head, Ordering::Release) }
+    }
+}
+
+impl<'ring> fmt::Debug for CompletionQueue<'ring> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompletionQueue")
+            .field("head", &self.head)
+            .field("tail", &self.tail)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// Pointers into the mapped submission queue ring.
+struct RawSubmissionQueue {
+    head: *const AtomicU32,
+    tail: *const AtomicU32,
+    ring_mask: u32,
+    ring_entries: u32,
+    flags: *const AtomicU32,
+    dropped: *const AtomicU32,
+    sqes: *mut io_uring_sqe,
+    sqe_shift: u32,
+}
+
+impl RawSubmissionQueue {
+    /// Return the number of published entries the kernel hasn't consumed.
+    #[inline]
+    fn pending(&self) -> u32 {
+        // SAFETY: `head` and `tail` point into the mapped ring.
+        unsafe {
+            let tail = (*self.tail).load(Ordering::Relaxed);
+            let head = (*self.head).load(Ordering::Acquire);
+            tail.wrapping_sub(head)
+        }
+    }
+
+    #[inline]
+    fn flags(&self) -> IoringSqFlags {
+        // SAFETY: `flags` points into the mapped ring.
+        IoringSqFlags::from_bits_truncate(unsafe { (*self.flags).load(Ordering::Acquire) })
+    }
+}
+
+/// Pointers into the mapped completion queue ring.
+struct RawCompletionQueue {
+    head: *const AtomicU32,
+    tail: *const AtomicU32,
+    ring_mask: u32,
+    ring_entries: u32,
+    overflow: *const AtomicU32,
+    flags: *const AtomicU32,
+    cqes: *const io_uring_cqe,
+    cqe_shift: u32,
+}
+
+/// A shared memory mapping of part of an io_uring instance, or of memory
+/// registered with one.
+pub(super) struct Mmap {
+    pub(super) ptr: *mut c_void,
+    len: usize,
+}
+
+impl Mmap {
+    fn new(fd: &OwnedFd, len: usize, offset: u64) -> io::Result<Self> {
+        // SAFETY: We're creating a new mapping, at an address chosen by the
+        // kernel, so we don't clobber any existing memory.
+        let ptr = unsafe {
+            mmap(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED | MapFlags::POPULATE,
+                fd,
+                offset,
+            )?
+        };
+        Ok(Self { ptr, len })
+    }
+
+    /// Create a zeroed private mapping, for memory the kernel reads from,
+    /// such as a provided buffer ring.
+    pub(super) fn anonymous(len: usize) -> io::Result<Self> {
+        // SAFETY: We're creating a new mapping, at an address chosen by the
+        // kernel, so we don't clobber any existing memory.
+        let ptr = unsafe {
+            mmap_anonymous(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::PRIVATE | MapFlags::POPULATE,
+            )?
+        };
+        Ok(Self { ptr, len })
+    }
+}
+
+impl Drop for Mmap {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: We own this mapping, and nothing borrows from it once its
+        // owner is dropped.
+        unsafe {
+            let _ = munmap(self.ptr, self.len);
+        }
+    }
+}
@@ -0,0 +1,425 @@
+//! Linux NUMA memory policy functions.
+//!
+//! # Safety
+//!
+//! These functions pass raw pointers and node masks to the kernel. `mbind`
+//! operates on a raw address range.
+#![allow(unsafe_code)]
+
+use crate::backend::c::{c_int, c_uint, c_ulong, c_void};
+use crate::process::Pid;
+use crate::{backend, io};
+use core::mem::size_of;
+use core::ptr::{null, null_mut};
+
+const MPOL_MODE_FLAGS: u32 = (1 << 15) | (1 << 14) | (1 << 13);
+
+const MPOL_F_NODE: c_uint = 1 << 0;
+const MPOL_F_ADDR: c_uint = 1 << 1;
+const MPOL_F_MEMS_ALLOWED: c_uint = 1 << 2;
+
+const BITS_PER_LONG: usize = 8 * size_of::<c_ulong>();
+
+/// `MPOL_*` constants for use with [`mbind`], [`set_mempolicy`], and
+/// [`get_mempolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum MemPolicyMode {
+    /// `MPOL_DEFAULT`—Use the policy of the enclosing scope; for the
+    /// calling thread, allocate on the node of the CPU which triggers the
+    /// allocation.
+    Default = 0,
+    /// `MPOL_PREFERRED`—Allocate on the given node if possible.
+    Preferred = 1,
+    /// `MPOL_BIND`—Allocate only on the given nodes.
+    Bind = 2,
+    /// `MPOL_INTERLEAVE`—Interleave page allocations across the given
+    /// nodes.
+    Interleave = 3,
+    /// `MPOL_LOCAL`—Allocate on the node of the CPU which triggers the
+    /// allocation (since Linux 3.8).
+    Local = 4,
+    /// `MPOL_PREFERRED_MANY`—Allocate on any of the given nodes if possible
+    /// (since Linux 5.15).
+    PreferredMany = 5,
+    /// `MPOL_WEIGHTED_INTERLEAVE`—Interleave page allocations across the
+    /// given nodes according to their weights (since Linux 6.9).
+    WeightedInterleave = 6,
+}
+
+impl MemPolicyMode {
+    /// Convert a raw `MPOL_*` value, without any mode flags, to a
+    /// `MemPolicyMode`.
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Default),
+            1 => Some(Self::Preferred),
+            2 => Some(Self::Bind),
+            3 => Some(Self::Interleave),
+            4 => Some(Self::Local),
+            5 => Some(Self::PreferredMany),
+            6 => Some(Self::WeightedInterleave),
+            _ => None,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_F_*` mode flags for use with [`MemPolicyMode`].
+    pub struct MemPolicyFlags: u32 {
+        /// `MPOL_F_NUMA_BALANCING` (since Linux 5.12)
+        const NUMA_BALANCING = 1 << 13;
+        /// `MPOL_F_RELATIVE_NODES`
+        const RELATIVE_NODES = 1 << 14;
+        /// `MPOL_F_STATIC_NODES`
+        const STATIC_NODES = 1 << 15;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_MF_*` flags for use with [`mbind`].
+    pub struct MbindFlags: u32 {
+        /// `MPOL_MF_STRICT`
+        const STRICT = 1 << 0;
+        /// `MPOL_MF_MOVE`
+        const MOVE = 1 << 1;
+        /// `MPOL_MF_MOVE_ALL`
+        const MOVE_ALL = 1 << 2;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_MF_*` flags for use with [`move_pages`].
+    pub struct MovePagesFlags: u32 {
+        /// `MPOL_MF_MOVE`
+        const MOVE = 1 << 1;
+        /// `MPOL_MF_MOVE_ALL`
+        const MOVE_ALL = 1 << 2;
+    }
+}
+
+/// `NodeMask` represents a bit-mask of NUMA nodes.
+///
+/// `NodeMask`s are used by [`mbind`], [`set_mempolicy`], and
+/// [`migrate_pages`], for example.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NodeMask {
+    bits: [c_ulong; NodeMask::MAX_NODE / BITS_PER_LONG],
+}
+
+impl NodeMask {
+    /// The maximum number of nodes in a `NodeMask`.
+    pub const MAX_NODE: usize = 1024;
+
+    /// Create a new and empty `NodeMask`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; Self::MAX_NODE / BITS_PER_LONG],
+        }
+    }
+
+    /// Test to see if a node is in the `NodeMask`.
+    ///
+    /// `node` is the node id to test.
+    #[inline]
+    pub fn is_set(&self, node: usize) -> bool {
+        let (idx, offset) = (node / BITS_PER_LONG, node % BITS_PER_LONG);
+        (self.bits[idx] & (1 << offset)) != 0
+    }
+
+    /// Add a node to `NodeMask`.
+    ///
+    /// `node` is the node id to add.
+    #[inline]
+    pub fn set(&mut self, node: usize) {
+        let (idx, offset) = (node / BITS_PER_LONG, node % BITS_PER_LONG);
+        self.bits[idx] |= 1 << offset;
+    }
+
+    /// Remove a node from `NodeMask`.
+    ///
+    /// `node` is the node id to remove.
+    #[inline]
+    pub fn unset(&mut self, node: usize) {
+        let (idx, offset) = (node / BITS_PER_LONG, node % BITS_PER_LONG);
+        self.bits[idx] &= !(1 << offset);
+    }
+
+    /// Count the number of nodes set in the `NodeMask`.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.bits.iter().fold(0, |acc, i| acc + i.count_ones())
+    }
+
+    /// Zeroes the `NodeMask`.
+    #[inline]
+    pub fn clear(&mut self) {
+        for element in &mut self.bits {
+            *element = 0;
+        }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const c_ulong {
+        self.bits.as_ptr()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut c_ulong {
+        self.bits.as_mut_ptr()
+    }
+
+    /// The `maxnode` argument to pass with a `NodeMask`.
+    ///
+    /// The kernel ignores the last bit of `maxnode` bits, so pass one more
+    /// than the number of bits, as libnuma does.
+    #[inline]
+    const fn maxnode() -> c_ulong {
+        (Self::MAX_NODE + 1) as c_ulong
+    }
+}
+
+impl Default for NodeMask {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A NUMA memory policy, as returned by [`get_mempolicy`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MemPolicy {
+    /// The policy mode.
+    pub mode: MemPolicyMode,
+
+    /// The mode flags.
+    pub flags: MemPolicyFlags,
+
+    /// The nodes the policy applies to.
+    pub nodes: NodeMask,
+}
+
+/// `mbind(addr, len, mode | flags, nodes, maxnode, mbind_flags)`—Set the
+/// NUMA memory policy for a range of memory.
+///
+/// # Safety
+///
+/// `addr` must be a valid pointer to memory that is appropriate to call
+/// `mbind` on. With [`MbindFlags::MOVE`] or [`MbindFlags::MOVE_ALL`], the
+/// pages in the range may be migrated to other nodes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mbind.2.html
+#[inline]
+pub unsafe fn mbind(
+    addr: *mut c_void,
+    len: usize,
+    mode: MemPolicyMode,
+    flags: MemPolicyFlags,
+    nodes: &NodeMask,
+    mbind_flags: MbindFlags,
+) -> io::Result<()> {
+    backend::mm::syscalls::mbind(
+        addr,
+        len,
+        mode as c_uint | flags.bits(),
+        nodes.as_ptr(),
+        NodeMask::maxnode(),
+        mbind_flags.bits(),
+    )
+}
+
+/// `set_mempolicy(mode | flags, nodes, maxnode)`—Set the NUMA memory policy
+/// of the calling thread.
+///
+/// For [`MemPolicyMode::Default`] and [`MemPolicyMode::Local`], `nodes` must
+/// be empty.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_mempolicy.2.html
+#[inline]
+pub fn set_mempolicy(
+    mode: MemPolicyMode,
+    flags: MemPolicyFlags,
+    nodes: &NodeMask,
+) -> io::Result<()> {
+    unsafe {
+        backend::mm::syscalls::set_mempolicy(
+            (mode as c_uint | flags.bits()) as c_int,
+            nodes.as_ptr(),
+            NodeMask::maxnode(),
+        )
+    }
+}
+
+/// `get_mempolicy(&mode, &nodes, maxnode, NULL, 0)`—Return the NUMA memory
+/// policy of the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+pub fn get_mempolicy() -> io::Result<MemPolicy> {
+    _get_mempolicy(null_mut(), 0)
+}
+
+/// `get_mempolicy(&mode, &nodes, maxnode, addr, MPOL_F_ADDR)`—Return the
+/// NUMA memory policy for the memory at an address in the calling process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+#[doc(alias = "MPOL_F_ADDR")]
+pub fn get_mempolicy_addr(addr: *mut c_void) -> io::Result<MemPolicy> {
+    _get_mempolicy(addr, MPOL_F_ADDR)
+}
+
+fn _get_mempolicy(addr: *mut c_void, flags: c_uint) -> io::Result<MemPolicy> {
+    let mut mode: c_int = 0;
+    let mut nodes = NodeMask::new();
+    unsafe {
+        backend::mm::syscalls::get_mempolicy(
+            &mut mode,
+            nodes.as_mut_ptr(),
+            NodeMask::maxnode(),
+            addr,
+            flags,
+        )?;
+    }
+    let mode = mode as u32;
+    Ok(MemPolicy {
+        mode: MemPolicyMode::from_raw(mode & !MPOL_MODE_FLAGS).ok_or(io::Errno::INVAL)?,
+        flags: MemPolicyFlags::from_bits_truncate(mode),
+        nodes,
+    })
+}
+
+/// `get_mempolicy(&node, NULL, 0, addr, MPOL_F_NODE | MPOL_F_ADDR)`—Return
+/// the NUMA node of the page at an address in the calling process.
+///
+/// If the page isn't allocated yet, this allocates it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+#[doc(alias = "MPOL_F_NODE")]
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // The kernel only looks `addr` up.
+pub fn get_mempolicy_node(addr: *mut c_void) -> io::Result<u32> {
+    let mut node: c_int = 0;
+    unsafe {
+        backend::mm::syscalls::get_mempolicy(
+            &mut node,
+            null_mut(),
+            0,
+            addr,
+            MPOL_F_NODE | MPOL_F_ADDR,
+        )?;
+    }
+    Ok(node as u32)
+}
+
+/// `get_mempolicy(NULL, &nodes, maxnode, NULL, MPOL_F_MEMS_ALLOWED)`—Return
+/// the nodes the calling thread may use in a memory policy.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+#[doc(alias = "MPOL_F_MEMS_ALLOWED")]
+pub fn get_mempolicy_mems_allowed() -> io::Result<NodeMask> {
+    let mut nodes = NodeMask::new();
+    unsafe {
+        backend::mm::syscalls::get_mempolicy(
+            null_mut(),
+            nodes.as_mut_ptr(),
+            NodeMask::maxnode(),
+            null_mut(),
+            MPOL_F_MEMS_ALLOWED,
+        )?;
+    }
+    Ok(nodes)
+}
+
+/// `move_pages(pid, count, pages, nodes, status, flags)`—Move pages of a
+/// process to other NUMA nodes, or query their nodes.
+///
+/// `pid` is the process to operate on. If `pid` is `None`, the calling
+/// process is used. `pages` holds the addresses of the pages in that
+/// process.
+///
+/// If `nodes` is `Some`, each page is moved to the corresponding node.
+/// Otherwise, no pages are moved. Either way, the node each page is on, or a
+/// negated `errno` value for the page, is stored in the corresponding element
+/// of `status`.
+///
+/// This returns the number of pages which couldn't be moved. The lengths of
+/// `pages`, `nodes`, and `status` must be equal; otherwise this fails with
+/// [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/move_pages.2.html
+#[inline]
+pub fn move_pages(
+    pid: Option<Pid>,
+    pages: &[usize],
+    nodes: Option<&[i32]>,
+    status: &mut [i32],
+    flags: MovePagesFlags,
+) -> io::Result<usize> {
+    if status.len() != pages.len() || nodes.map_or(false, |nodes| nodes.len() != pages.len()) {
+        return Err(io::Errno::INVAL);
+    }
+    unsafe {
+        backend::mm::syscalls::move_pages(
+            pid,
+            pages.len() as c_ulong,
+            pages.as_ptr().cast::<*const c_void>(),
+            nodes.map_or(null(), <[i32]>::as_ptr),
+            status.as_mut_ptr(),
+            flags.bits() as c_int,
+        )
+    }
+}
+
+/// `migrate_pages(pid, maxnode, old_nodes, new_nodes)`—Move all pages of a
+/// process from one set of NUMA nodes to another.
+///
+/// `pid` is the process to operate on. If `pid` is `None`, the calling
+/// process is used.
+///
+/// This returns the number of pages which couldn't be moved.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/migrate_pages.2.html
+#[inline]
+pub fn migrate_pages(
+    pid: Option<Pid>,
+    old_nodes: &NodeMask,
+    new_nodes: &NodeMask,
+) -> io::Result<usize> {
+    unsafe {
+        backend::mm::syscalls::migrate_pages(
+            pid,
+            NodeMask::maxnode(),
+            old_nodes.as_ptr(),
+            new_nodes.as_ptr(),
+        )
+    }
+}
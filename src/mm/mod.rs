@@ -2,14 +2,28 @@
 
 #[cfg(not(target_os = "redox"))]
 mod madvise;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
 mod mmap;
 mod msync;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+mod process_madvise;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod userfaultfd;
 
 #[cfg(not(target_os = "redox"))]
 pub use madvise::{madvise, Advice};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use mempolicy::{
+    get_mempolicy, get_mempolicy_addr, get_mempolicy_mems_allowed, get_mempolicy_node, mbind,
+    migrate_pages, move_pages, set_mempolicy, MbindFlags, MemPolicy, MemPolicyFlags, MemPolicyMode,
+    MovePagesFlags, NodeMask,
+};
 pub use mmap::*;
 pub use msync::{msync, MsyncFlags};
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+pub use process_madvise::{process_madvise, process_mrelease, MreleaseFlags, ProcessMadviseFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
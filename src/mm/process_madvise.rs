@@ -0,0 +1,68 @@
+//! The Linux `process_madvise` and `process_mrelease` functions.
+
+use crate::fd::AsFd;
+use crate::mm::Advice;
+use crate::process::RemoteIoVec;
+use crate::{backend, io};
+
+bitflags::bitflags! {
+    /// Flags for use with [`process_madvise`].
+    ///
+    /// No flags are currently defined.
+    pub struct ProcessMadviseFlags: backend::c::c_uint {}
+}
+
+bitflags::bitflags! {
+    /// Flags for use with [`process_mrelease`].
+    ///
+    /// No flags are currently defined.
+    pub struct MreleaseFlags: backend::c::c_uint {}
+}
+
+/// `process_madvise(pidfd, remote, advice, flags)`—Declares an expected
+/// access pattern for ranges of memory in another process.
+///
+/// `pidfd` is a file descriptor for the process, such as one returned by
+/// [`pidfd_open`]. The kernel only accepts some kinds of advice here,
+/// including [`Advice::WillNeed`], [`Advice::LinuxCold`], and
+/// [`Advice::LinuxPageOut`].
+///
+/// This returns the number of bytes advised, which may be less than the
+/// total length of `remote` if an error occurs partway through.
+///
+/// This requires Linux 5.10 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_madvise.2.html
+/// [`pidfd_open`]: crate::process::pidfd_open
+#[inline]
+pub fn process_madvise<Fd: AsFd>(
+    pidfd: Fd,
+    remote: &[RemoteIoVec],
+    advice: Advice,
+    flags: ProcessMadviseFlags,
+) -> io::Result<usize> {
+    backend::mm::syscalls::process_madvise(pidfd.as_fd(), remote, advice, flags)
+}
+
+/// `process_mrelease(pidfd, flags)`—Releases the memory of a process which
+/// is being killed.
+///
+/// `pidfd` is a file descriptor for the process, such as one returned by
+/// [`pidfd_open`]. The process must have been sent `SIGKILL`, and not yet
+/// have released its memory on its own; otherwise this fails with
+/// [`io::Errno::INVAL`].
+///
+/// This requires Linux 5.15 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_mrelease.2.html
+/// [`pidfd_open`]: crate::process::pidfd_open
+#[inline]
+pub fn process_mrelease<Fd: AsFd>(pidfd: Fd, flags: MreleaseFlags) -> io::Result<()> {
+    backend::mm::syscalls::process_mrelease(pidfd.as_fd(), flags)
+}
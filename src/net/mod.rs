@@ -11,6 +11,10 @@
 mod addr;
 #[cfg(not(feature = "std"))]
 mod ip;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod netlink;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod packet;
 mod send_recv;
 mod socket;
 mod socket_addr_any;
@@ -35,6 +39,13 @@ pub use {
     addr::{SocketAddr, SocketAddrV4, SocketAddrV6},
     ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope},
 };
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use {
+    netlink::SocketAddrNetlink,
+    packet::SocketAddrLink,
+    send_recv::{sendmsg_link, sendmsg_netlink, sendto_link, sendto_netlink},
+    socket::{bind_link, bind_netlink, connect_netlink},
+};
 #[cfg(unix)]
 pub use {
     send_recv::{sendmsg_unix, sendto_unix},
@@ -0,0 +1,463 @@
+//! Netlink sockets.
+//!
+//! This module defines [`SocketAddrNetlink`], the protocol numbers to pass to
+//! [`socket`] along with [`AddressFamily::NETLINK`], and utilities for
+//! encoding and decoding netlink messages.
+//!
+//! A netlink datagram holds one or more messages, each consisting of a
+//! `struct nlmsghdr` followed by a payload. The payload is usually a
+//! fixed-size header specific to the protocol, such as `struct ifinfomsg` for
+//! `RTM_GETLINK`, followed by a list of `struct nlattr` attributes.
+//! [`NetlinkMessageBuilder`] encodes messages, and [`NetlinkMessages`] and
+//! [`NetlinkAttrs`] decode them in place.
+//!
+//! Responses to dump requests are multipart: they may span several
+//! datagrams, and end with a message for which
+//! [`NetlinkMessage::is_done`] returns `true`.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [`socket`]: crate::net::socket
+//! [`AddressFamily::NETLINK`]: crate::net::AddressFamily::NETLINK
+//! [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+
+use crate::backend::c;
+use crate::io;
+use crate::net::Protocol;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::mem::size_of;
+
+pub use crate::backend::net::types::NetlinkFlags;
+
+/// `NETLINK_ROUTE`
+pub const ROUTE: Protocol = Protocol::from_raw(c::NETLINK_ROUTE as _);
+/// `NETLINK_USERSOCK`
+pub const USERSOCK: Protocol = Protocol::from_raw(c::NETLINK_USERSOCK as _);
+/// `NETLINK_SOCK_DIAG`
+pub const SOCK_DIAG: Protocol = Protocol::from_raw(c::NETLINK_SOCK_DIAG as _);
+/// `NETLINK_NFLOG`
+pub const NFLOG: Protocol = Protocol::from_raw(c::NETLINK_NFLOG as _);
+/// `NETLINK_XFRM`
+pub const XFRM: Protocol = Protocol::from_raw(c::NETLINK_XFRM as _);
+/// `NETLINK_SELINUX`
+pub const SELINUX: Protocol = Protocol::from_raw(c::NETLINK_SELINUX as _);
+/// `NETLINK_ISCSI`
+pub const ISCSI: Protocol = Protocol::from_raw(c::NETLINK_ISCSI as _);
+/// `NETLINK_AUDIT`
+pub const AUDIT: Protocol = Protocol::from_raw(c::NETLINK_AUDIT as _);
+/// `NETLINK_FIB_LOOKUP`
+pub const FIB_LOOKUP: Protocol = Protocol::from_raw(c::NETLINK_FIB_LOOKUP as _);
+/// `NETLINK_CONNECTOR`
+pub const CONNECTOR: Protocol = Protocol::from_raw(c::NETLINK_CONNECTOR as _);
+/// `NETLINK_NETFILTER`
+pub const NETFILTER: Protocol = Protocol::from_raw(c::NETLINK_NETFILTER as _);
+/// `NETLINK_DNRTMSG`
+pub const DNRTMSG: Protocol = Protocol::from_raw(c::NETLINK_DNRTMSG as _);
+/// `NETLINK_KOBJECT_UEVENT`
+pub const KOBJECT_UEVENT: Protocol = Protocol::from_raw(c::NETLINK_KOBJECT_UEVENT as _);
+/// `NETLINK_GENERIC`
+pub const GENERIC: Protocol = Protocol::from_raw(c::NETLINK_GENERIC as _);
+/// `NETLINK_SCSITRANSPORT`
+pub const SCSITRANSPORT: Protocol = Protocol::from_raw(c::NETLINK_SCSITRANSPORT as _);
+/// `NETLINK_ECRYPTFS`
+pub const ECRYPTFS: Protocol = Protocol::from_raw(c::NETLINK_ECRYPTFS as _);
+/// `NETLINK_RDMA`
+pub const RDMA: Protocol = Protocol::from_raw(c::NETLINK_RDMA as _);
+/// `NETLINK_CRYPTO`
+pub const CRYPTO: Protocol = Protocol::from_raw(c::NETLINK_CRYPTO as _);
+/// `NETLINK_SMC`
+pub const SMC: Protocol = Protocol::from_raw(c::NETLINK_SMC as _);
+
+/// `NLMSG_NOOP`—The message type of a message to be ignored.
+pub const MSG_NOOP: u16 = c::NLMSG_NOOP as u16;
+/// `NLMSG_ERROR`—The message type of an error or acknowledgment.
+pub const MSG_ERROR: u16 = c::NLMSG_ERROR as u16;
+/// `NLMSG_DONE`—The message type of the end of a multipart message.
+pub const MSG_DONE: u16 = c::NLMSG_DONE as u16;
+/// `NLMSG_OVERRUN`—The message type of a notification of lost data.
+pub const MSG_OVERRUN: u16 = c::NLMSG_OVERRUN as u16;
+
+/// `NLMSG_ALIGNTO` and `NLA_ALIGNTO`
+const ALIGNTO: usize = 4;
+
+/// The size of a `struct nlmsghdr`.
+const HDRLEN: usize = size_of::<c::nlmsghdr>();
+
+/// The size of a `struct nlattr`.
+const ATTR_HDRLEN: usize = size_of::<c::nlattr>();
+
+/// `NLMSG_ALIGN(len)` and `NLA_ALIGN(len)`
+#[inline]
+const fn align(len: usize) -> usize {
+    (len + ALIGNTO - 1) & !(ALIGNTO - 1)
+}
+
+#[inline]
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+
+#[inline]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+/// Pad `buf` with zeros up to the netlink alignment.
+#[inline]
+fn pad(buf: &mut Vec<u8>) {
+    buf.resize(align(buf.len()), 0);
+}
+
+/// `struct sockaddr_nl`
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[doc(alias = "sockaddr_nl")]
+pub struct SocketAddrNetlink {
+    pid: u32,
+    groups: u32,
+}
+
+impl SocketAddrNetlink {
+    /// Construct a new netlink address.
+    ///
+    /// A `pid` of 0 refers to the kernel when sending, and asks the kernel to
+    /// assign a port ID when binding. `groups` is a bitmask of the multicast
+    /// groups to join.
+    #[inline]
+    pub const fn new(pid: u32, groups: u32) -> Self {
+        Self { pid, groups }
+    }
+
+    /// Return the port ID.
+    #[inline]
+    pub const fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Set the port ID.
+    #[inline]
+    pub fn set_pid(&mut self, pid: u32) {
+        self.pid = pid;
+    }
+
+    /// Return the multicast groups mask.
+    #[inline]
+    pub const fn groups(&self) -> u32 {
+        self.groups
+    }
+
+    /// Set the multicast groups mask.
+    #[inline]
+    pub fn set_groups(&mut self, groups: u32) {
+        self.groups = groups;
+    }
+}
+
+/// A netlink message, decoded in place from a buffer.
+#[derive(Clone, Copy, Debug)]
+pub struct NetlinkMessage<'a> {
+    message_type: u16,
+    flags: NetlinkFlags,
+    seq: u32,
+    pid: u32,
+    payload: &'a [u8],
+}
+
+impl<'a> NetlinkMessage<'a> {
+    /// Return the message type, such as [`MSG_DONE`] or a protocol-specific
+    /// type such as `RTM_NEWLINK`.
+    #[inline]
+    pub fn message_type(&self) -> u16 {
+        self.message_type
+    }
+
+    /// Return the message flags.
+    #[inline]
+    pub fn flags(&self) -> NetlinkFlags {
+        self.flags
+    }
+
+    /// Return the sequence number.
+    #[inline]
+    pub fn seq(&self) -> u32 {
+        self.seq
+    }
+
+    /// Return the port ID of the sender.
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the payload, following the message header.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Test whether this message ends a multipart message.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.message_type == MSG_DONE
+    }
+
+    /// Test whether this message is an acknowledgment, which is an
+    /// `NLMSG_ERROR` message with an error code of 0.
+    ///
+    /// [`NetlinkMessages`] reports `NLMSG_ERROR` messages with a nonzero
+    /// error code as errors, so any `NLMSG_ERROR` message it yields is an
+    /// acknowledgment.
+    #[inline]
+    pub fn is_ack(&self) -> bool {
+        self.message_type == MSG_ERROR
+    }
+
+    /// Return an iterator over the attributes in the payload, following a
+    /// protocol-specific header of `header_len` bytes.
+    #[inline]
+    pub fn attrs(&self, header_len: usize) -> NetlinkAttrs<'a> {
+        NetlinkAttrs::new(self.payload.get(align(header_len)..).unwrap_or(&[]))
+    }
+}
+
+/// An iterator over the netlink messages in a buffer, such as a buffer
+/// filled by [`recv`].
+///
+/// `NLMSG_ERROR` messages with a nonzero error code are yielded as the
+/// corresponding [`io::Errno`], and iteration continues after them. A
+/// malformed message is yielded as [`io::Errno::INVAL`], and ends the
+/// iteration.
+///
+/// [`recv`]: crate::net::recv
+#[derive(Clone, Debug)]
+pub struct NetlinkMessages<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> NetlinkMessages<'a> {
+    /// Construct a new iterator over the messages in `buf`.
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn parse(&mut self) -> io::Result<NetlinkMessage<'a>> {
+        let buf = self.buf;
+        self.buf = &[];
+        if buf.len() < HDRLEN {
+            return Err(io::Errno::INVAL);
+        }
+        let len = read_u32(buf, 0) as usize;
+        if len < HDRLEN || len > buf.len() {
+            return Err(io::Errno::INVAL);
+        }
+        self.buf = buf.get(align(len)..).unwrap_or(&[]);
+
+        let message = NetlinkMessage {
+            message_type: read_u16(buf, 4),
+            flags: NetlinkFlags::from_bits_truncate(read_u16(buf, 6)),
+            seq: read_u32(buf, 8),
+            pid: read_u32(buf, 12),
+            payload: &buf[HDRLEN..len],
+        };
+
+        if message.message_type == MSG_ERROR {
+            // The payload is a `struct nlmsgerr`, starting with a negated
+            // errno value.
+            if message.payload.len() < size_of::<i32>() {
+                return Err(io::Errno::INVAL);
+            }
+            let error = read_u32(message.payload, 0) as i32;
+            if error != 0 {
+                return Err(io::Errno::from_raw_os_error(error.wrapping_neg()));
+            }
+        }
+
+        Ok(message)
+    }
+}
+
+impl<'a> Iterator for NetlinkMessages<'a> {
+    type Item = io::Result<NetlinkMessage<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        Some(self.parse())
+    }
+}
+
+/// A netlink attribute, decoded in place from a buffer.
+#[derive(Clone, Copy, Debug)]
+pub struct NetlinkAttr<'a> {
+    attr_type: u16,
+    payload: &'a [u8],
+}
+
+impl<'a> NetlinkAttr<'a> {
+    /// Return the attribute type, without the `NLA_F_NESTED` and
+    /// `NLA_F_NET_BYTEORDER` flags.
+    #[inline]
+    pub fn attr_type(&self) -> u16 {
+        self.attr_type & !((c::NLA_F_NESTED | c::NLA_F_NET_BYTEORDER) as u16)
+    }
+
+    /// Test whether the `NLA_F_NESTED` flag is set.
+    #[inline]
+    pub fn is_nested(&self) -> bool {
+        self.attr_type & c::NLA_F_NESTED as u16 != 0
+    }
+
+    /// Test whether the `NLA_F_NET_BYTEORDER` flag is set.
+    #[inline]
+    pub fn is_net_byteorder(&self) -> bool {
+        self.attr_type & c::NLA_F_NET_BYTEORDER as u16 != 0
+    }
+
+    /// Return the payload, following the attribute header.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Return an iterator over the attributes nested in the payload.
+    #[inline]
+    pub fn nested(&self) -> NetlinkAttrs<'a> {
+        NetlinkAttrs::new(self.payload)
+    }
+}
+
+/// An iterator over the netlink attributes in a buffer.
+///
+/// A malformed attribute is yielded as [`io::Errno::INVAL`], and ends the
+/// iteration.
+#[derive(Clone, Debug)]
+pub struct NetlinkAttrs<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> NetlinkAttrs<'a> {
+    /// Construct a new iterator over the attributes in `buf`.
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for NetlinkAttrs<'a> {
+    type Item = io::Result<NetlinkAttr<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = self.buf;
+        if buf.is_empty() {
+            return None;
+        }
+        self.buf = &[];
+        if buf.len() < ATTR_HDRLEN {
+            return Some(Err(io::Errno::INVAL));
+        }
+        let len = read_u16(buf, 0) as usize;
+        if len < ATTR_HDRLEN || len > buf.len() {
+            return Some(Err(io::Errno::INVAL));
+        }
+        self.buf = buf.get(align(len)..).unwrap_or(&[]);
+        Some(Ok(NetlinkAttr {
+            attr_type: read_u16(buf, 2),
+            payload: &buf[ATTR_HDRLEN..len],
+        }))
+    }
+}
+
+/// A builder for a netlink message, which appends the message to a buffer.
+///
+/// The length in the message header is kept up to date as the payload is
+/// added, so several messages may be appended to the same buffer and sent
+/// together.
+pub struct NetlinkMessageBuilder<'a> {
+    buf: &'a mut Vec<u8>,
+    start: usize,
+}
+
+/// The position of a nested attribute started by
+/// [`NetlinkMessageBuilder::begin_nested`].
+#[derive(Debug)]
+#[must_use]
+pub struct NestedAttr {
+    start: usize,
+}
+
+impl<'a> NetlinkMessageBuilder<'a> {
+    /// Begin a message with the given type, flags, and sequence number at the
+    /// end of `buf`.
+    ///
+    /// The port ID in the header is 0, which the kernel fills in for
+    /// messages it receives.
+    pub fn new(buf: &'a mut Vec<u8>, message_type: u16, flags: NetlinkFlags, seq: u32) -> Self {
+        pad(buf);
+        let start = buf.len();
+        buf.extend_from_slice(&(HDRLEN as u32).to_ne_bytes());
+        buf.extend_from_slice(&message_type.to_ne_bytes());
+        buf.extend_from_slice(&flags.bits().to_ne_bytes());
+        buf.extend_from_slice(&seq.to_ne_bytes());
+        buf.extend_from_slice(&0_u32.to_ne_bytes());
+        Self { buf, start }
+    }
+
+    /// Append raw bytes to the payload, such as a protocol-specific header.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        pad(self.buf);
+        self.buf.extend_from_slice(bytes);
+        self.update_len();
+    }
+
+    /// Append an attribute with the given type and payload.
+    pub fn push_attr(&mut self, attr_type: u16, payload: &[u8]) {
+        pad(self.buf);
+        let len: u16 = (ATTR_HDRLEN + payload.len())
+            .try_into()
+            .expect("netlink attribute size overflow");
+        self.buf.extend_from_slice(&len.to_ne_bytes());
+        self.buf.extend_from_slice(&attr_type.to_ne_bytes());
+        self.buf.extend_from_slice(payload);
+        self.update_len();
+    }
+
+    /// Begin an attribute with the given type which contains nested
+    /// attributes. The attributes pushed until the corresponding call to
+    /// [`end_nested`] are nested inside it.
+    ///
+    /// This sets the `NLA_F_NESTED` flag in the attribute type.
+    ///
+    /// [`end_nested`]: Self::end_nested
+    pub fn begin_nested(&mut self, attr_type: u16) -> NestedAttr {
+        pad(self.buf);
+        let start = self.buf.len();
+        self.push_attr(attr_type | c::NLA_F_NESTED as u16, &[]);
+        NestedAttr { start }
+    }
+
+    /// End a nested attribute started by [`begin_nested`].
+    ///
+    /// [`begin_nested`]: Self::begin_nested
+    pub fn end_nested(&mut self, nested: NestedAttr) {
+        let len: u16 = (self.buf.len() - nested.start)
+            .try_into()
+            .expect("netlink attribute size overflow");
+        self.buf[nested.start..nested.start + 2].copy_from_slice(&len.to_ne_bytes());
+    }
+
+    /// Finish the message, and return the encoded message.
+    pub fn finish(self) -> &'a [u8] {
+        let Self { buf, start } = self;
+        &buf[start..]
+    }
+
+    fn update_len(&mut self) {
+        let len: u32 = (self.buf.len() - self.start)
+            .try_into()
+            .expect("netlink message size overflow");
+        self.buf[self.start..self.start + 4].copy_from_slice(&len.to_ne_bytes());
+    }
+}
@@ -0,0 +1,195 @@
+//! `AF_PACKET` sockets.
+//!
+//! This module defines [`SocketAddrLink`], the protocol numbers to pass to
+//! [`socket`] along with [`AddressFamily::PACKET`], and, with the `mm`
+//! feature, [`PacketRxRing`], a reader for a `TPACKET_V3` receive ring.
+//!
+//! # References
+//!  - [Linux]
+//!  - [Linux `PACKET_MMAP`]
+//!
+//! [`socket`]: crate::net::socket
+//! [`AddressFamily::PACKET`]: crate::net::AddressFamily::PACKET
+//! [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+//! [Linux `PACKET_MMAP`]: https://www.kernel.org/doc/html/latest/networking/packet_mmap.html
+
+use crate::backend::c;
+use crate::io;
+use crate::net::Protocol;
+
+#[cfg(feature = "mm")]
+mod ring;
+
+pub use crate::backend::net::types::{
+    PacketFanoutFlags, PacketFanoutMode, PacketMembership, PacketType, PacketVersion,
+};
+#[cfg(feature = "mm")]
+pub use ring::{Packet, PacketBlock, PacketRxRing, Packets};
+
+/// `htons(ETH_P_ALL)`—Every protocol.
+pub const ALL: Protocol = Protocol::from_raw((c::ETH_P_ALL as u16).to_be() as _);
+/// `htons(ETH_P_IP)`—IPv4.
+pub const IP: Protocol = Protocol::from_raw((c::ETH_P_IP as u16).to_be() as _);
+/// `htons(ETH_P_ARP)`—ARP.
+pub const ARP: Protocol = Protocol::from_raw((c::ETH_P_ARP as u16).to_be() as _);
+/// `htons(ETH_P_IPV6)`—IPv6.
+pub const IPV6: Protocol = Protocol::from_raw((c::ETH_P_IPV6 as u16).to_be() as _);
+
+/// `struct sockaddr_ll`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[doc(alias = "sockaddr_ll")]
+pub struct SocketAddrLink {
+    /// The protocol, in network byte order.
+    protocol: u16,
+    ifindex: u32,
+    hatype: u16,
+    pkttype: u8,
+    addr_len: u8,
+    addr: [u8; 8],
+}
+
+impl SocketAddrLink {
+    /// Construct a new link-layer address for the interface with index
+    /// `ifindex`, with no physical-layer address.
+    ///
+    /// `protocol` is the same value passed to [`socket`], such as [`ALL`].
+    /// An `ifindex` of 0 matches any interface when binding.
+    ///
+    /// [`socket`]: crate::net::socket
+    #[inline]
+    pub const fn new(protocol: Protocol, ifindex: u32) -> Self {
+        Self {
+            protocol: protocol.as_raw() as u16,
+            ifindex,
+            hatype: 0,
+            pkttype: PacketType::HOST.as_raw(),
+            addr_len: 0,
+            addr: [0; 8],
+        }
+    }
+
+    /// Return the protocol.
+    #[inline]
+    pub const fn protocol(&self) -> Protocol {
+        Protocol::from_raw(self.protocol as _)
+    }
+
+    /// Set the protocol.
+    #[inline]
+    pub fn set_protocol(&mut self, protocol: Protocol) {
+        self.protocol = protocol.as_raw() as u16;
+    }
+
+    /// Return the interface index.
+    #[inline]
+    pub const fn ifindex(&self) -> u32 {
+        self.ifindex
+    }
+
+    /// Set the interface index.
+    #[inline]
+    pub fn set_ifindex(&mut self, ifindex: u32) {
+        self.ifindex = ifindex;
+    }
+
+    /// Return the `ARPHRD_*` hardware type, in addresses returned by the
+    /// kernel.
+    #[inline]
+    pub const fn hatype(&self) -> u16 {
+        self.hatype
+    }
+
+    /// Return the packet type, in addresses returned by the kernel.
+    #[inline]
+    pub const fn pkttype(&self) -> PacketType {
+        PacketType::from_raw(self.pkttype)
+    }
+
+    /// Return the physical-layer address.
+    #[inline]
+    pub fn addr(&self) -> &[u8] {
+        &self.addr[..self.addr_len as usize]
+    }
+
+    /// Set the physical-layer address, which is used as the destination
+    /// when sending.
+    ///
+    /// This fails with [`io::Errno::INVAL`] if `addr` is longer than 8 bytes.
+    #[inline]
+    pub fn set_addr(&mut self, addr: &[u8]) -> io::Result<()> {
+        let addr_len = addr.len();
+        if addr_len > self.addr.len() {
+            return Err(io::Errno::INVAL);
+        }
+        self.addr[..addr_len].copy_from_slice(addr);
+        for byte in &mut self.addr[addr_len..] {
+            *byte = 0;
+        }
+        self.addr_len = addr_len as u8;
+        Ok(())
+    }
+
+    /// Construct an address from the raw fields of a `struct sockaddr_ll`.
+    #[inline]
+    pub(crate) fn from_raw(
+        protocol: u16,
+        ifindex: i32,
+        hatype: u16,
+        pkttype: u8,
+        addr_len: u8,
+        addr: [u8; 8],
+    ) -> Self {
+        Self {
+            protocol,
+            ifindex: ifindex as u32,
+            hatype,
+            pkttype,
+            addr_len: addr_len.min(8),
+            addr,
+        }
+    }
+
+    /// Return the raw value of the protocol, in network byte order.
+    #[inline]
+    pub(crate) fn raw_protocol(&self) -> u16 {
+        self.protocol
+    }
+
+    /// Return the raw length and bytes of the physical-layer address.
+    #[inline]
+    pub(crate) fn raw_addr(&self) -> (u8, [u8; 8]) {
+        (self.addr_len, self.addr)
+    }
+}
+
+/// `struct tpacket_req3`—The layout of a ring, for use with
+/// [`set_packet_rx_ring`], [`set_packet_tx_ring`], and [`PacketRxRing`].
+///
+/// `block_size` must be a multiple of the page size, and `frame_size` and
+/// `frame_nr` must be consistent with `block_size` and `block_nr`, even
+/// though `TPACKET_V3` frames have variable sizes.
+///
+/// [`set_packet_rx_ring`]: crate::net::sockopt::set_packet_rx_ring
+/// [`set_packet_tx_ring`]: crate::net::sockopt::set_packet_tx_ring
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[doc(alias = "tpacket_req3")]
+pub struct TpacketReq3 {
+    /// `tp_block_size`—The size of each block.
+    pub block_size: u32,
+    /// `tp_block_nr`—The number of blocks.
+    pub block_nr: u32,
+    /// `tp_frame_size`—The nominal size of each frame.
+    pub frame_size: u32,
+    /// `tp_frame_nr`—The nominal number of frames.
+    pub frame_nr: u32,
+    /// `tp_retire_blk_tov`—The timeout in milliseconds after which a block
+    /// which isn't full is handed to user space, or 0 to let the kernel
+    /// choose.
+    pub retire_blk_tov: u32,
+    /// `tp_sizeof_priv`—The size of a private area at the start of each
+    /// block.
+    pub sizeof_priv: u32,
+    /// `tp_feature_req_word`—Feature request flags, such as
+    /// `TP_FT_REQ_FILL_RXHASH`.
+    pub feature_req_word: u32,
+}
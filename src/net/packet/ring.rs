@@ -0,0 +1,350 @@
+//! A reader for `TPACKET_V3` receive rings.
+
+#![allow(unsafe_code)]
+
+use super::{PacketVersion, TpacketReq3};
+use crate::backend::c;
+use crate::fd::AsFd;
+use crate::io;
+use crate::mm::{mmap, munmap, MapFlags, ProtFlags};
+use crate::net::sockopt::{set_packet_rx_ring, set_packet_version};
+use core::convert::TryInto;
+use core::ffi::c_void;
+use core::ptr::null_mut;
+use core::slice;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+// Offsets of fields in `struct tpacket_block_desc`, which begins with
+// `version` and `offset_to_priv` followed by a `struct tpacket_hdr_v1`.
+const BLOCK_STATUS: usize = 8;
+const BLOCK_NUM_PKTS: usize = 12;
+const BLOCK_OFFSET_TO_FIRST_PKT: usize = 16;
+const BLOCK_SEQ_NUM: usize = 24;
+
+/// The offset of the part of a block after `block_status`. This part isn't
+/// written by the kernel while the block is owned by user space.
+const BLOCK_BODY: usize = BLOCK_STATUS + 4;
+
+// Offsets of fields in `struct tpacket3_hdr`.
+const FRAME_NEXT_OFFSET: usize = 0;
+const FRAME_SEC: usize = 4;
+const FRAME_NSEC: usize = 8;
+const FRAME_SNAPLEN: usize = 12;
+const FRAME_LEN: usize = 16;
+const FRAME_STATUS: usize = 20;
+const FRAME_MAC: usize = 24;
+const FRAME_NET: usize = 26;
+const FRAME_RXHASH: usize = 28;
+const FRAME_VLAN_TCI: usize = 32;
+const FRAME_VLAN_TPID: usize = 36;
+const FRAME_HDRLEN: usize = 48;
+
+#[inline]
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_ne_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+
+#[inline]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+/// A memory-mapped `TPACKET_V3` receive ring.
+///
+/// The kernel fills blocks of packets in order, and hands each one to user
+/// space when it's full or its timeout expires. [`next_block`] returns the
+/// next block if it's ready, and the block is handed back to the kernel when
+/// the returned [`PacketBlock`] is dropped. To wait for a block, [`poll`]
+/// the socket for [`PollFlags::IN`].
+///
+/// [`next_block`]: Self::next_block
+/// [`poll`]: crate::io::poll
+/// [`PollFlags::IN`]: crate::io::PollFlags::IN
+pub struct PacketRxRing {
+    ptr: *mut c_void,
+    block_size: usize,
+    block_nr: usize,
+    next: usize,
+}
+
+// SAFETY: The ring is only accessed through `&mut self`, and the kernel
+// doesn't care which thread reads it.
+unsafe impl Send for PacketRxRing {}
+
+impl PacketRxRing {
+    /// Set up a `TPACKET_V3` receive ring on an `AF_PACKET` socket with the
+    /// layout in `req`, and map it into memory.
+    ///
+    /// This sets [`PacketVersion::V3`] with [`set_packet_version`] and
+    /// creates the ring with [`set_packet_rx_ring`]. A socket can only have
+    /// one receive ring.
+    ///
+    /// [`set_packet_version`]: crate::net::sockopt::set_packet_version
+    /// [`set_packet_rx_ring`]: crate::net::sockopt::set_packet_rx_ring
+    pub fn new<Fd: AsFd>(socket: Fd, req: &TpacketReq3) -> io::Result<Self> {
+        let socket = socket.as_fd();
+        let block_size = req.block_size as usize;
+        let block_nr = req.block_nr as usize;
+        // The kernel treats a ring with no blocks as a request to remove the
+        // ring, and requires blocks to be larger than their headers.
+        if block_nr == 0 || block_size < BLOCK_BODY {
+            return Err(io::Errno::INVAL);
+        }
+        let len = block_size.checked_mul(block_nr).ok_or(io::Errno::INVAL)?;
+
+        set_packet_version(socket, PacketVersion::V3)?;
+        set_packet_rx_ring(socket, req)?;
+
+        // SAFETY: We map the ring the kernel just allocated at an address of
+        // the kernel's choosing, and own the mapping until `drop`.
+        let ptr = unsafe {
+            mmap(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED,
+                socket,
+                0,
+            )?
+        };
+
+        Ok(Self {
+            ptr,
+            block_size,
+            block_nr,
+            next: 0,
+        })
+    }
+
+    /// Return the next block, if the kernel has handed it to user space.
+    pub fn next_block(&mut self) -> Option<PacketBlock<'_>> {
+        // SAFETY: `self.next` is less than `self.block_nr`, so the block is
+        // within the mapping. Blocks are page-aligned, so `block_status` is
+        // aligned for an `AtomicU32`, and the kernel accesses it atomically
+        // too.
+        let block = unsafe { self.ptr.cast::<u8>().add(self.next * self.block_size) };
+        let status = unsafe { &*block.add(BLOCK_STATUS).cast::<AtomicU32>() };
+        if status.load(Ordering::Acquire) & c::TP_STATUS_USER == 0 {
+            return None;
+        }
+
+        // SAFETY: The kernel doesn't write to a block while it's owned by
+        // user space, and `PacketBlock` hands it back when dropped. The body
+        // doesn't overlap `block_status`.
+        let body =
+            unsafe { slice::from_raw_parts(block.add(BLOCK_BODY), self.block_size - BLOCK_BODY) };
+
+        self.next = (self.next + 1) % self.block_nr;
+        Some(PacketBlock { status, body })
+    }
+}
+
+impl Drop for PacketRxRing {
+    fn drop(&mut self) {
+        // SAFETY: We own the mapping, and no blocks are borrowed.
+        unsafe {
+            let _ = munmap(self.ptr, self.block_size * self.block_nr);
+        }
+    }
+}
+
+/// A block of packets in a [`PacketRxRing`], which is handed back to the
+/// kernel when dropped.
+pub struct PacketBlock<'ring> {
+    status: &'ring AtomicU32,
+    body: &'ring [u8],
+}
+
+impl<'ring> PacketBlock<'ring> {
+    /// Return the number of packets in the block.
+    #[inline]
+    pub fn num_packets(&self) -> u32 {
+        read_u32(self.body, BLOCK_NUM_PKTS - BLOCK_BODY)
+    }
+
+    /// Return the sequence number of the block.
+    #[inline]
+    pub fn seq_num(&self) -> u64 {
+        let offset = BLOCK_SEQ_NUM - BLOCK_BODY;
+        u64::from_ne_bytes(self.body[offset..offset + 8].try_into().unwrap())
+    }
+
+    /// Test whether the block was handed to user space because its timeout
+    /// expired, rather than because it was full.
+    #[inline]
+    pub fn timed_out(&self) -> bool {
+        self.status.load(Ordering::Relaxed) & c::TP_STATUS_BLK_TMO != 0
+    }
+
+    /// Return an iterator over the packets in the block.
+    #[inline]
+    pub fn packets(&self) -> Packets<'_> {
+        Packets {
+            body: self.body,
+            offset: read_u32(self.body, BLOCK_OFFSET_TO_FIRST_PKT - BLOCK_BODY) as usize,
+            remaining: self.num_packets(),
+        }
+    }
+}
+
+impl<'ring> Drop for PacketBlock<'ring> {
+    fn drop(&mut self) {
+        self.status.store(c::TP_STATUS_KERNEL, Ordering::Release);
+    }
+}
+
+/// An iterator over the packets in a [`PacketBlock`].
+///
+/// Iteration stops early if a frame header is inconsistent with the block.
+#[derive(Clone)]
+pub struct Packets<'block> {
+    body: &'block [u8],
+    offset: usize,
+    remaining: u32,
+}
+
+impl<'block> Iterator for Packets<'block> {
+    type Item = Packet<'block>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining;
+        if remaining == 0 {
+            return None;
+        }
+        self.remaining = 0;
+
+        // Offsets in frame headers are relative to the start of the block.
+        let start = self.offset.checked_sub(BLOCK_BODY)?;
+        let frame = self.body.get(start..)?;
+        let packet = Packet::parse(frame)?;
+
+        let next_offset = read_u32(frame, FRAME_NEXT_OFFSET) as usize;
+        if next_offset != 0 {
+            if let Some(offset) = self.offset.checked_add(next_offset) {
+                self.offset = offset;
+                self.remaining = remaining - 1;
+            }
+        }
+        Some(packet)
+    }
+}
+
+/// A packet in a [`PacketBlock`], described by a `struct tpacket3_hdr`.
+#[derive(Clone, Copy, Debug)]
+pub struct Packet<'a> {
+    data: &'a [u8],
+    len: u32,
+    status: u32,
+    sec: u32,
+    nsec: u32,
+    network_offset: u16,
+    rxhash: u32,
+    vlan_tci: u32,
+    vlan_tpid: u16,
+}
+
+impl<'a> Packet<'a> {
+    /// Decode a frame starting with a `struct tpacket3_hdr`, checking that the
+    /// captured data is within `frame`.
+    fn parse(frame: &'a [u8]) -> Option<Self> {
+        if frame.len() < FRAME_HDRLEN {
+            return None;
+        }
+        let mac = read_u16(frame, FRAME_MAC);
+        let snaplen = read_u32(frame, FRAME_SNAPLEN) as usize;
+        let start = mac as usize;
+        let data = frame.get(start..start.checked_add(snaplen)?)?;
+        Some(Self {
+            data,
+            len: read_u32(frame, FRAME_LEN),
+            status: read_u32(frame, FRAME_STATUS),
+            sec: read_u32(frame, FRAME_SEC),
+            nsec: read_u32(frame, FRAME_NSEC),
+            network_offset: read_u16(frame, FRAME_NET).saturating_sub(mac),
+            rxhash: read_u32(frame, FRAME_RXHASH),
+            vlan_tci: read_u32(frame, FRAME_VLAN_TCI),
+            vlan_tpid: read_u16(frame, FRAME_VLAN_TPID),
+        })
+    }
+
+    /// Return the captured data, starting at the link-layer header.
+    #[inline]
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Return the original length of the packet, which is greater than the
+    /// length of [`data`] if the packet was truncated.
+    ///
+    /// [`data`]: Self::data
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Test whether the original packet was empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Test whether the captured data is shorter than the original packet.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.data.len() < self.len as usize
+    }
+
+    /// Return the offset of the network-layer header within [`data`].
+    ///
+    /// [`data`]: Self::data
+    #[inline]
+    pub fn network_offset(&self) -> usize {
+        self.network_offset as usize
+    }
+
+    /// Return the seconds part of the receive timestamp.
+    #[inline]
+    pub fn sec(&self) -> u32 {
+        self.sec
+    }
+
+    /// Return the nanoseconds part of the receive timestamp.
+    #[inline]
+    pub fn nsec(&self) -> u32 {
+        self.nsec
+    }
+
+    /// Return the flow hash, if `TP_FT_REQ_FILL_RXHASH` was requested.
+    #[inline]
+    pub fn rxhash(&self) -> u32 {
+        self.rxhash
+    }
+
+    /// Return the VLAN tag control information and protocol identifier, if
+    /// the packet had a VLAN tag which was stripped.
+    #[inline]
+    pub fn vlan(&self) -> Option<(u16, Option<u16>)> {
+        if self.status & c::TP_STATUS_VLAN_VALID == 0 {
+            return None;
+        }
+        let tpid = if self.status & c::TP_STATUS_VLAN_TPID_VALID != 0 {
+            Some(self.vlan_tpid)
+        } else {
+            None
+        };
+        Some((self.vlan_tci as u16, tpid))
+    }
+
+    /// Test whether the kernel reported that the checksum is valid.
+    #[inline]
+    pub fn is_csum_valid(&self) -> bool {
+        self.status & c::TP_STATUS_CSUM_VALID != 0
+    }
+
+    /// Test whether packets have been dropped since the socket's statistics
+    /// were last read with `PACKET_STATISTICS`.
+    #[inline]
+    pub fn is_losing(&self) -> bool {
+        self.status & c::TP_STATUS_LOSING != 0
+    }
+}
@@ -0,0 +1,264 @@
+//! [`sendmmsg`] and [`recvmmsg`] functions.
+
+#![allow(unsafe_code)]
+
+use crate::backend::time::types::Timespec;
+use crate::backend::{self, c};
+use crate::fd::AsFd;
+use crate::io::{self, IoSlice, IoSliceMut};
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use super::{RecvAncillaryBuffer, RecvFlags, SendAncillaryBuffer, SendFlags};
+use crate::net::SocketAddrAny;
+
+/// The `msg_len` of a [`RecvMMsgHdr`] which hasn't been filled in by
+/// [`recvmmsg`]. The kernel never receives this many bytes at once.
+const NOT_RECEIVED: u32 = u32::MAX;
+
+/// Storage for the encoded socket address of a message, for use with
+/// [`MMsgHdr::new_with_addr`] and [`RecvMMsgHdr::new`].
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[doc(alias = "sockaddr_storage")]
+pub struct MMsgAddr(MaybeUninit<c::sockaddr_storage>);
+
+impl MMsgAddr {
+    /// Create new, uninitialized address storage.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+}
+
+impl Default for MMsgAddr {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for MMsgAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MMsgAddr").finish()
+    }
+}
+
+/// A message to send with [`sendmmsg`].
+///
+/// This has the same layout as `struct mmsghdr`, so a slice of them is passed
+/// to the kernel directly.
+#[repr(transparent)]
+#[doc(alias = "mmsghdr")]
+pub struct MMsgHdr<'a> {
+    raw: c::mmsghdr,
+
+    /// Phantom data for the lifetimes of the buffers `raw` points to.
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> MMsgHdr<'a> {
+    /// Create a message to send on a connected socket.
+    #[inline]
+    pub fn new(iov: &'a [IoSlice<'a>], control: &'a mut SendAncillaryBuffer<'_, '_, '_>) -> Self {
+        Self {
+            raw: backend::net::msghdr::send_mmsghdr(None, iov, control),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a message to send to a specific address, which is encoded into
+    /// `storage`.
+    pub fn new_with_addr(
+        addr: &SocketAddrAny,
+        storage: &'a mut MMsgAddr,
+        iov: &'a [IoSlice<'a>],
+        control: &'a mut SendAncillaryBuffer<'_, '_, '_>,
+    ) -> Self {
+        // SAFETY: `sockaddr_storage` is large enough for any address.
+        let len = unsafe { addr.write(storage.0.as_mut_ptr().cast()) };
+        Self {
+            raw: backend::net::msghdr::send_mmsghdr(Some((&storage.0, len)), iov, control),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Return the number of bytes sent, after a successful [`sendmmsg`]
+    /// call which included this message.
+    #[inline]
+    pub fn bytes_sent(&self) -> usize {
+        self.raw.msg_len as usize
+    }
+}
+
+impl<'a> core::fmt::Debug for MMsgHdr<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MMsgHdr")
+            .field("bytes_sent", &self.bytes_sent())
+            .finish()
+    }
+}
+
+/// A buffer to receive a message into with [`recvmmsg`].
+///
+/// This has the same layout as `struct mmsghdr`, so a slice of them is passed
+/// to the kernel directly.
+///
+/// Each `RecvMMsgHdr` is meant to be filled in by a single [`recvmmsg`]
+/// call; the kernel shrinks the address and ancillary buffer lengths to the
+/// lengths it received.
+#[repr(transparent)]
+#[doc(alias = "mmsghdr")]
+pub struct RecvMMsgHdr<'a> {
+    raw: c::mmsghdr,
+
+    /// Phantom data for the lifetimes of the buffers `raw` points to.
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> RecvMMsgHdr<'a> {
+    /// Create a buffer to receive a message into, with the source address
+    /// written to `name` and ancillary messages to `control`.
+    ///
+    /// After a successful [`recvmmsg`] call which filled in this message,
+    /// ancillary messages can be read with [`RecvMMsgHdr::ancillary`].
+    #[inline]
+    pub fn new(
+        name: &'a mut MMsgAddr,
+        iov: &'a mut [IoSliceMut<'_>],
+        control: &'a mut [u8],
+    ) -> Self {
+        let mut raw = backend::net::msghdr::recv_mmsghdr(&mut name.0, iov, control);
+        raw.msg_len = NOT_RECEIVED as _;
+        Self {
+            raw,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Return whether a [`recvmmsg`] call has filled in this message.
+    #[inline]
+    fn is_received(&self) -> bool {
+        self.raw.msg_len as u32 != NOT_RECEIVED
+    }
+
+    /// Return the number of bytes received.
+    #[inline]
+    pub fn bytes_received(&self) -> usize {
+        if self.is_received() {
+            self.raw.msg_len as usize
+        } else {
+            0
+        }
+    }
+
+    /// Return the flags received.
+    #[inline]
+    pub fn flags(&self) -> RecvFlags {
+        if self.is_received() {
+            RecvFlags::from_bits_truncate(self.raw.msg_hdr.msg_flags as _)
+        } else {
+            RecvFlags::empty()
+        }
+    }
+
+    /// Return the address of the socket the message was received from, if
+    /// any.
+    #[inline]
+    pub fn address(&self) -> Option<SocketAddrAny> {
+        if self.is_received() {
+            // SAFETY: The kernel wrote the address and its length.
+            unsafe { backend::net::msghdr::recv_mmsghdr_address(&self.raw) }
+        } else {
+            None
+        }
+    }
+
+    /// Return the ancillary messages received.
+    ///
+    /// The messages are moved out of this `RecvMMsgHdr`, so calling this
+    /// again returns an empty buffer.
+    pub fn ancillary(&mut self) -> RecvAncillaryBuffer<'_> {
+        let len = if self.is_received() {
+            self.raw.msg_hdr.msg_controllen as usize
+        } else {
+            0
+        };
+        self.raw.msg_hdr.msg_controllen = 0;
+
+        // SAFETY: `msg_control` points to the buffer passed to `new`, which
+        // we borrow mutably for `'a`, and the kernel wrote `len` bytes of
+        // messages into it.
+        unsafe {
+            let buffer = core::slice::from_raw_parts_mut(self.raw.msg_hdr.msg_control.cast(), len);
+            let mut control = RecvAncillaryBuffer::new(buffer);
+            control.set_control_len(len);
+            control
+        }
+    }
+}
+
+impl<'a> Drop for RecvMMsgHdr<'a> {
+    fn drop(&mut self) {
+        // Close any file descriptors received and not read.
+        drop(self.ancillary());
+    }
+}
+
+impl<'a> core::fmt::Debug for RecvMMsgHdr<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut s = f.debug_struct("RecvMMsgHdr");
+        s.field("bytes_received", &self.bytes_received());
+        s.field("flags", &self.flags());
+        // `SocketAddrAny` only implements `Debug` with "std".
+        #[cfg(feature = "std")]
+        s.field("address", &self.address());
+        s.finish()
+    }
+}
+
+/// `sendmmsg(msgs, flags)`—Sends multiple messages on a socket.
+///
+/// Returns the number of messages sent, which may be fewer than `msgs.len()`.
+/// The number of bytes sent for each is available from
+/// [`MMsgHdr::bytes_sent`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmmsg.2.html
+#[inline]
+pub fn sendmmsg(
+    socket: impl AsFd,
+    msgs: &mut [MMsgHdr<'_>],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmmsg(socket.as_fd(), msgs, flags)
+}
+
+/// `recvmmsg(msgs, flags, timeout)`—Receives multiple messages from a socket.
+///
+/// Returns the number of messages received, which may be fewer than
+/// `msgs.len()`. The results for each are available from the
+/// [`RecvMMsgHdr`] accessors.
+///
+/// The `timeout` is a `Timespec`, as exported by `rustix::time` and
+/// `rustix::fs`. It's only checked after each message is received, so this
+/// may block indefinitely if no messages arrive. Use
+/// [`RecvFlags::WAITFORONE`] or a socket timeout instead to limit how long
+/// this waits.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmmsg.2.html
+#[inline]
+pub fn recvmmsg(
+    socket: impl AsFd,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<Timespec>,
+) -> io::Result<usize> {
+    backend::net::syscalls::recvmmsg(socket.as_fd(), msgs, flags, timeout)
+}
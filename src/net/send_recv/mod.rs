@@ -3,11 +3,15 @@
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
 use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 
 pub use backend::net::send_recv::{RecvFlags, SendFlags};
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mmsg;
 #[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
 mod msg;
 
@@ -20,6 +24,11 @@ pub use msg::{
 #[cfg(unix)]
 pub use msg::sendmsg_unix;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use mmsg::{recvmmsg, sendmmsg, MMsgAddr, MMsgHdr, RecvMMsgHdr};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use msg::{sendmsg_link, sendmsg_netlink, Ipv4PacketInfo, Ipv6PacketInfo, UCred};
+
 /// `recv(fd, buf, flags)`—Reads data from a socket.
 ///
 /// # References
@@ -213,6 +222,10 @@ fn _sendto_any(
         SocketAddrAny::V6(v6) => backend::net::syscalls::sendto_v6(fd, buf, flags, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::sendto_unix(fd, buf, flags, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(nl) => backend::net::syscalls::sendto_netlink(fd, buf, flags, nl),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(ll) => backend::net::syscalls::sendto_link(fd, buf, flags, ll),
     }
 }
 
@@ -330,3 +343,43 @@ pub fn sendto_unix<Fd: AsFd>(
 ) -> io::Result<usize> {
     backend::net::syscalls::sendto_unix(fd.as_fd(), buf, flags, addr)
 }
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_nl))`—Writes data to
+/// a socket to a specific netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_netlink<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_netlink(fd.as_fd(), buf, flags, addr)
+}
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_ll))`—Writes data to
+/// an `AF_PACKET` socket to a specific interface and physical-layer address.
+///
+/// # References
+///  - [Linux]
+///  - [Linux `packet`]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_link<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_link(fd.as_fd(), buf, flags, addr)
+}
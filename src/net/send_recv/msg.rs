@@ -10,10 +10,20 @@ use core::convert::{TryFrom, TryInto};
 use core::marker::PhantomData;
 use core::mem::{self, size_of, size_of_val};
 use core::ptr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use core::time::Duration;
 
 use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{Ipv4Addr, Ipv6Addr};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{Gid, Pid, RawGid, RawPid, RawUid, Uid};
 
 /// Macro for defining the amount of space used by CMSGs.
+///
+/// This takes a message type and a count, as in `cmsg_space!(ScmRights(1))`,
+/// or a parenthesized list of them, as in
+/// `cmsg_space!((ScmRights(1), ScmCredentials(1)))`.
 #[macro_export]
 macro_rules! cmsg_space {
     // Base Rules
@@ -22,11 +32,54 @@ macro_rules! cmsg_space {
             $len * ::core::mem::size_of::<$crate::fd::BorrowedFd<'static>>(),
         )
     };
+    (ScmCredentials($len:expr)) => {
+        // `struct ucred` is a `pid_t`, a `uid_t`, and a `gid_t`.
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<[u32; 3]>())
+    };
+    (ScmPidfd($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<$crate::fd::BorrowedFd<'static>>(),
+        )
+    };
+    (IpPktinfo($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<$crate::net::Ipv4PacketInfo>(),
+        )
+    };
+    (Ipv6Pktinfo($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<$crate::net::Ipv6PacketInfo>(),
+        )
+    };
+    (IpTtl($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<i32>())
+    };
+    (Ipv6HopLimit($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<i32>())
+    };
+    (IpTos($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<i32>())
+    };
+    (Ipv6Tclass($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<i32>())
+    };
+    (ScmTimestampns($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<[i64; 2]>())
+    };
+    (ScmTimestamping($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<[[i64; 2]; 3]>())
+    };
+    (UdpGro($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<i32>())
+    };
+    (UdpSegment($len:expr)) => {
+        $crate::net::__cmsg_space($len * ::core::mem::size_of::<u16>())
+    };
 
     // Combo Rules
-    (($($($x:tt)*),+)) => {
+    (($($name:ident($len:expr)),+ $(,)?)) => {
         $(
-            cmsg_space!($($x)*) +
+            $crate::cmsg_space!($name($len)) +
         )+
         0
     };
@@ -37,12 +90,184 @@ pub fn __cmsg_space(len: usize) -> usize {
     unsafe { c::CMSG_SPACE(len.try_into().expect("CMSG_SPACE size overflow")) as usize }
 }
 
+/// `struct ucred`—UNIX credentials of a process, for use with
+/// [`SendAncillaryMessage::ScmCredentials`] and
+/// [`RecvAncillaryMessage::ScmCredentials`], and returned by
+/// [`get_socket_peercred`].
+///
+/// [`get_socket_peercred`]: crate::net::sockopt::get_socket_peercred
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "ucred")]
+pub struct UCred {
+    /// The process ID, or `None` if the process isn't visible in the PID
+    /// namespace of the receiver.
+    pub pid: Option<Pid>,
+
+    /// The user ID.
+    pub uid: Uid,
+
+    /// The group ID.
+    pub gid: Gid,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl UCred {
+    /// Convert from a `struct ucred`.
+    #[inline]
+    pub(crate) fn from_raw(raw: c::ucred) -> Self {
+        unsafe {
+            Self {
+                pid: Pid::from_raw(raw.pid as RawPid),
+                uid: Uid::from_raw(raw.uid as RawUid),
+                gid: Gid::from_raw(raw.gid as RawGid),
+            }
+        }
+    }
+
+    /// Convert to a `struct ucred`.
+    #[inline]
+    pub(crate) fn as_raw(&self) -> c::ucred {
+        c::ucred {
+            pid: Pid::as_raw(self.pid) as _,
+            uid: self.uid.as_raw() as _,
+            gid: self.gid.as_raw() as _,
+        }
+    }
+}
+
+/// `struct in_pktinfo`—IPv4 packet information, for use with
+/// [`SendAncillaryMessage::IpPktinfo`] and
+/// [`RecvAncillaryMessage::IpPktinfo`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[doc(alias = "in_pktinfo")]
+pub struct Ipv4PacketInfo {
+    /// The index of the interface the packet was received on, or the
+    /// interface to send it on, or 0 for any interface.
+    pub ifindex: u32,
+
+    /// The local address. When sending, this selects the source address of
+    /// the packet; when receiving, it's the address the packet was routed
+    /// to.
+    pub local_addr: Ipv4Addr,
+
+    /// The destination address in the packet header. This is ignored when
+    /// sending.
+    pub dst_addr: Ipv4Addr,
+}
+
+/// `struct in6_pktinfo`—IPv6 packet information, for use with
+/// [`SendAncillaryMessage::Ipv6Pktinfo`] and
+/// [`RecvAncillaryMessage::Ipv6Pktinfo`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[doc(alias = "in6_pktinfo")]
+pub struct Ipv6PacketInfo {
+    /// The destination address of a received packet, or the source address
+    /// to send a packet from, or the unspecified address to let the kernel
+    /// choose.
+    pub addr: Ipv6Addr,
+
+    /// The index of the interface the packet was received on, or the
+    /// interface to send it on, or 0 for any interface.
+    pub ifindex: u32,
+}
+
+/// The layout of `struct in_pktinfo`, with the addresses in network byte
+/// order.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawIpv4PacketInfo {
+    ifindex: c::c_int,
+    spec_dst: [u8; 4],
+    addr: [u8; 4],
+}
+
+/// The layout of `struct in6_pktinfo`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RawIpv6PacketInfo {
+    addr: [u8; 16],
+    ifindex: c::c_int,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl Ipv4PacketInfo {
+    #[inline]
+    fn from_raw(raw: RawIpv4PacketInfo) -> Self {
+        Self {
+            ifindex: raw.ifindex as u32,
+            local_addr: Ipv4Addr::from(raw.spec_dst),
+            dst_addr: Ipv4Addr::from(raw.addr),
+        }
+    }
+
+    #[inline]
+    fn as_raw(&self) -> RawIpv4PacketInfo {
+        RawIpv4PacketInfo {
+            ifindex: self.ifindex as c::c_int,
+            spec_dst: self.local_addr.octets(),
+            addr: self.dst_addr.octets(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl Ipv6PacketInfo {
+    #[inline]
+    fn from_raw(raw: RawIpv6PacketInfo) -> Self {
+        Self {
+            addr: Ipv6Addr::from(raw.addr),
+            ifindex: raw.ifindex as u32,
+        }
+    }
+
+    #[inline]
+    fn as_raw(&self) -> RawIpv6PacketInfo {
+        RawIpv6PacketInfo {
+            addr: self.addr.octets(),
+            ifindex: self.ifindex as c::c_int,
+        }
+    }
+}
+
 /// Ancillary message for [`sendmsg_noaddr`], [`sendmsg_v4`], [`sendmsg_v6`],
 /// [`sendmsg_unix`], and [`sendmsg_any`].
 #[non_exhaustive]
 pub enum SendAncillaryMessage<'slice, 'fd> {
     /// Send file descriptors.
     ScmRights(&'slice [BorrowedFd<'fd>]),
+    /// Send process credentials.
+    ///
+    /// Unless the sender has the appropriate capabilities, these must be the
+    /// sender's own PID, and one of its user and group IDs.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "SCM_CREDENTIALS")]
+    ScmCredentials(UCred),
+    /// Select the source address and outgoing interface of an IPv4 packet.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktinfo(Ipv4PacketInfo),
+    /// Select the source address and outgoing interface of an IPv6 packet.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6Pktinfo(Ipv6PacketInfo),
+    /// Split a UDP datagram into segments of the given size using generic
+    /// segmentation offload (since Linux 4.18).
+    ///
+    /// This overrides [`set_udp_segment`] for one call.
+    ///
+    /// [`set_udp_segment`]: crate::net::sockopt::set_udp_segment
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "UDP_SEGMENT")]
+    UdpSegment(u16),
 }
 
 impl SendAncillaryMessage<'_, '_> {
@@ -52,6 +277,14 @@ impl SendAncillaryMessage<'_, '_> {
     pub fn size(&self) -> usize {
         let total_bytes = match self {
             Self::ScmRights(slice) => size_of_val(*slice),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::ScmCredentials(_) => size_of::<c::ucred>(),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::IpPktinfo(_) => size_of::<RawIpv4PacketInfo>(),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Ipv6Pktinfo(_) => size_of::<RawIpv6PacketInfo>(),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::UdpSegment(_) => size_of::<u16>(),
         };
 
         unsafe {
@@ -69,6 +302,112 @@ impl SendAncillaryMessage<'_, '_> {
 pub enum RecvAncillaryMessage<'a> {
     /// Received file descriptors.
     ScmRights(AncillaryIter<'a, OwnedFd>),
+    /// Received process credentials.
+    ///
+    /// These are sent by the kernel if the receiving socket has
+    /// [`set_socket_passcred`] enabled.
+    ///
+    /// [`set_socket_passcred`]: crate::net::sockopt::set_socket_passcred
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "SCM_CREDENTIALS")]
+    ScmCredentials(UCred),
+    /// Received file descriptor for the sending process (since Linux 6.5).
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_socket_passpidfd`] enabled.
+    ///
+    /// [`set_socket_passpidfd`]: crate::net::sockopt::set_socket_passpidfd
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "SCM_PIDFD")]
+    ScmPidfd(OwnedFd),
+    /// Received IPv4 packet information.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ip_pktinfo`] enabled.
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktinfo(Ipv4PacketInfo),
+    /// Received IPv6 packet information.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ipv6_recvpktinfo`] enabled.
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6Pktinfo(Ipv6PacketInfo),
+    /// Received IPv4 time-to-live.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ip_recvttl`] enabled.
+    ///
+    /// [`set_ip_recvttl`]: crate::net::sockopt::set_ip_recvttl
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IP_TTL")]
+    IpTtl(u8),
+    /// Received IPv6 hop limit.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ipv6_recvhoplimit`] enabled.
+    ///
+    /// [`set_ipv6_recvhoplimit`]: crate::net::sockopt::set_ipv6_recvhoplimit
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IPV6_HOPLIMIT")]
+    Ipv6HopLimit(u8),
+    /// Received IPv4 type-of-service byte, including the ECN bits.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ip_recvtos`] enabled.
+    ///
+    /// [`set_ip_recvtos`]: crate::net::sockopt::set_ip_recvtos
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IP_TOS")]
+    IpTos(u8),
+    /// Received IPv6 traffic class, including the ECN bits.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_ipv6_recvtclass`] enabled.
+    ///
+    /// [`set_ipv6_recvtclass`]: crate::net::sockopt::set_ipv6_recvtclass
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "IPV6_TCLASS")]
+    Ipv6Tclass(u8),
+    /// Received software timestamp, as a duration since the Unix epoch.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_socket_timestampns`] enabled.
+    ///
+    /// [`set_socket_timestampns`]: crate::net::sockopt::set_socket_timestampns
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "SO_TIMESTAMPNS")]
+    #[doc(alias = "SCM_TIMESTAMPNS")]
+    ScmTimestampns(Duration),
+    /// Received timestamps, as durations since the Unix epoch.
+    ///
+    /// The first element holds a software timestamp, and the third holds a
+    /// raw hardware timestamp. The second is unused. Timestamps that weren't
+    /// requested are zero.
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_socket_timestamping`] enabled.
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "SO_TIMESTAMPING")]
+    #[doc(alias = "SCM_TIMESTAMPING")]
+    ScmTimestamping([Duration; 3]),
+    /// Received segment size of a datagram coalesced by generic receive
+    /// offload (since Linux 5.0).
+    ///
+    /// This is sent by the kernel if the receiving socket has
+    /// [`set_udp_gro`] enabled.
+    ///
+    /// [`set_udp_gro`]: crate::net::sockopt::set_udp_gro
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "UDP_GRO")]
+    UdpGro(u16),
 }
 
 /// Buffer for sending ancillary messages.
@@ -135,6 +474,31 @@ impl<'buf, 'slice, 'fd> SendAncillaryBuffer<'buf, 'slice, 'fd> {
                 };
                 self.push_ancillary(fds_bytes, c::SOL_SOCKET as _, c::SCM_RIGHTS as _)
             }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            SendAncillaryMessage::ScmCredentials(ucred) => {
+                let ucred = ucred.as_raw();
+                let ucred_bytes = unsafe {
+                    core::slice::from_raw_parts(
+                        (&ucred as *const c::ucred).cast::<u8>(),
+                        size_of::<c::ucred>(),
+                    )
+                };
+                self.push_ancillary(ucred_bytes, c::SOL_SOCKET as _, c::SCM_CREDENTIALS as _)
+            }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            SendAncillaryMessage::IpPktinfo(info) => {
+                let info = info.as_raw();
+                self.push_ancillary(as_bytes(&info), c::SOL_IP as _, c::IP_PKTINFO as _)
+            }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            SendAncillaryMessage::Ipv6Pktinfo(info) => {
+                let info = info.as_raw();
+                self.push_ancillary(as_bytes(&info), c::SOL_IPV6 as _, c::IPV6_PKTINFO as _)
+            }
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            SendAncillaryMessage::UdpSegment(size) => {
+                self.push_ancillary(as_bytes(&size), c::SOL_UDP as _, c::UDP_SEGMENT as _)
+            }
         }
     }
 
@@ -307,12 +671,107 @@ impl<'buf> AncillaryDrain<'buf> {
 
                     Some(RecvAncillaryMessage::ScmRights(fds))
                 }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_SOCKET, c::SCM_CREDENTIALS) => {
+                    if payload_len >= size_of::<c::ucred>() {
+                        let ucred = ptr::read_unaligned(payload.as_ptr().cast::<c::ucred>());
+                        Some(RecvAncillaryMessage::ScmCredentials(UCred::from_raw(ucred)))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_SOCKET, c::SCM_PIDFD) => {
+                    // Like `ScmRights`, this consumes the file descriptor.
+                    let mut fds = AncillaryIter::<OwnedFd>::new(payload);
+                    fds.next().map(RecvAncillaryMessage::ScmPidfd)
+                }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IP, c::IP_PKTINFO) => read_payload(payload)
+                    .map(|info| RecvAncillaryMessage::IpPktinfo(Ipv4PacketInfo::from_raw(info))),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IPV6, c::IPV6_PKTINFO) => read_payload(payload)
+                    .map(|info| RecvAncillaryMessage::Ipv6Pktinfo(Ipv6PacketInfo::from_raw(info))),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IP, c::IP_TTL) => read_payload::<c::c_int>(payload)
+                    .map(|ttl| RecvAncillaryMessage::IpTtl(ttl as u8)),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IPV6, c::IPV6_HOPLIMIT) => read_payload::<c::c_int>(payload)
+                    .map(|hops| RecvAncillaryMessage::Ipv6HopLimit(hops as u8)),
+                // Unlike the others, `IP_TOS` is a single byte.
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IP, c::IP_TOS) => {
+                    payload.first().map(|tos| RecvAncillaryMessage::IpTos(*tos))
+                }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_IPV6, c::IPV6_TCLASS) => read_payload::<c::c_int>(payload)
+                    .map(|tclass| RecvAncillaryMessage::Ipv6Tclass(tclass as u8)),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPNS_NEW) | (c::SOL_SOCKET, c::SO_TIMESTAMPNS_OLD) => {
+                    let mut ts = [Duration::new(0, 0)];
+                    read_timespecs(payload, &mut ts)
+                        .map(|()| RecvAncillaryMessage::ScmTimestampns(ts[0]))
+                }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPING_NEW)
+                | (c::SOL_SOCKET, c::SO_TIMESTAMPING_OLD) => {
+                    let mut ts = [Duration::new(0, 0); 3];
+                    read_timespecs(payload, &mut ts)
+                        .map(|()| RecvAncillaryMessage::ScmTimestamping(ts))
+                }
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                (c::SOL_UDP, c::UDP_GRO) => read_payload::<c::c_int>(payload)
+                    .map(|size| RecvAncillaryMessage::UdpGro(size as u16)),
                 _ => None,
             }
         }
     }
 }
 
+/// View a plain-data value as bytes, for use as a message payload.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn as_bytes<T: Copy>(value: &T) -> &[u8] {
+    unsafe { core::slice::from_raw_parts((value as *const T).cast::<u8>(), size_of::<T>()) }
+}
+
+/// Read a plain-data value from a message payload, if it's large enough.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn read_payload<T: Copy>(payload: &[u8]) -> Option<T> {
+    if payload.len() >= size_of::<T>() {
+        Some(unsafe { ptr::read_unaligned(payload.as_ptr().cast::<T>()) })
+    } else {
+        None
+    }
+}
+
+/// Read an array of `struct timespec`s from a message payload.
+///
+/// The `_OLD` timestamp messages use the platform `struct timespec`, which
+/// has 32-bit fields on 32-bit platforms, while the `_NEW` ones always use
+/// 64-bit fields, so determine the layout from the payload length.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn read_timespecs(payload: &[u8], out: &mut [Duration]) -> Option<()> {
+    let elem_len = payload.len() / out.len();
+    if elem_len != 16 && elem_len != 8 {
+        return None;
+    }
+    for (chunk, out) in payload.chunks_exact(elem_len).zip(out.iter_mut()) {
+        let (secs, nanos) = match elem_len {
+            16 => {
+                let [secs, nanos] = read_payload::<[i64; 2]>(chunk)?;
+                (secs, nanos)
+            }
+            _ => {
+                let [secs, nanos] = read_payload::<[i32; 2]>(chunk)?;
+                (secs.into(), nanos.into())
+            }
+        };
+        // Reject negative times, which `Duration` can't represent.
+        *out = Duration::new(secs.try_into().ok()?, nanos.try_into().ok()?);
+    }
+    Some(())
+}
+
 impl<'buf> Iterator for AncillaryDrain<'buf> {
     type Item = RecvAncillaryMessage<'buf>;
 
@@ -497,6 +956,44 @@ pub fn sendmsg_unix(
     backend::net::syscalls::sendmsg_unix(socket.as_fd(), addr, iov, control, flags)
 }
 
+/// `sendmsg(msghdr)`—Sends a message on a socket to a specific netlink
+/// address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub fn sendmsg_netlink(
+    socket: impl AsFd,
+    addr: &super::SocketAddrNetlink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+}
+
+/// `sendmsg(msghdr)`—Sends a message on an `AF_PACKET` socket to a specific
+/// interface and physical-layer address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub fn sendmsg_link(
+    socket: impl AsFd,
+    addr: &super::SocketAddrLink,
+    iov: &[io::IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_link(socket.as_fd(), addr, iov, control, flags)
+}
+
 /// `sendmsg(msghdr)`—Sends a message on a socket to a specific address.
 ///
 /// # References
@@ -537,6 +1034,14 @@ pub fn sendmsg_any(
         Some(SocketAddrAny::Unix(addr)) => {
             backend::net::syscalls::sendmsg_unix(socket.as_fd(), addr, iov, control, flags)
         }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        Some(SocketAddrAny::Netlink(addr)) => {
+            backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        Some(SocketAddrAny::Link(addr)) => {
+            backend::net::syscalls::sendmsg_link(socket.as_fd(), addr, iov, control, flags)
+        }
     }
 }
 
@@ -1,5 +1,7 @@
 use crate::fd::OwnedFd;
 use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 
@@ -172,6 +174,10 @@ fn _bind_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()> {
         SocketAddrAny::V6(v6) => backend::net::syscalls::bind_v6(sockfd, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::bind_unix(sockfd, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(nl) => backend::net::syscalls::bind_netlink(sockfd, nl),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(ll) => backend::net::syscalls::bind_link(sockfd, ll),
     }
 }
 
@@ -275,6 +281,36 @@ pub fn bind_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<()>
     backend::net::syscalls::bind_unix(sockfd.as_fd(), addr)
 }
 
+/// `bind(sockfd, addr, sizeof(struct sockaddr_nl))`—Binds a socket to a
+/// netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bind.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::bind_netlink(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_ll))`—Binds an `AF_PACKET`
+/// socket to a protocol and interface.
+///
+/// # References
+///  - [Linux]
+///  - [Linux `packet`]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bind.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_link<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrLink) -> io::Result<()> {
+    backend::net::syscalls::bind_link(sockfd.as_fd(), addr)
+}
+
 /// `connect(sockfd, addr)`—Initiates a connection to an IP address.
 ///
 /// # References
@@ -349,6 +385,10 @@ fn _connect_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()>
         SocketAddrAny::V6(v6) => backend::net::syscalls::connect_v6(sockfd, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::connect_unix(sockfd, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(nl) => backend::net::syscalls::connect_netlink(sockfd, nl),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(ll) => backend::net::syscalls::connect_link(sockfd, ll),
     }
 }
 
@@ -452,6 +492,20 @@ pub fn connect_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<(
     backend::net::syscalls::connect_unix(sockfd.as_fd(), addr)
 }
 
+/// `connect(sockfd, addr, sizeof(struct sockaddr_nl))`—Initiates a
+/// connection to a netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/connect.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::connect_netlink(sockfd.as_fd(), addr)
+}
+
 /// `listen(fd, backlog)`—Enables listening for incoming connections.
 ///
 /// # References
@@ -12,6 +12,8 @@
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
 use crate::net::{AddressFamily, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 use crate::{backend, io};
 #[cfg(feature = "std")]
 use core::fmt;
@@ -30,6 +32,12 @@ pub enum SocketAddrAny {
     /// `struct sockaddr_un`
     #[cfg(unix)]
     Unix(SocketAddrUnix),
+    /// `struct sockaddr_nl`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Netlink(SocketAddrNetlink),
+    /// `struct sockaddr_ll`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Link(SocketAddrLink),
 }
 
 impl From<SocketAddrV4> for SocketAddrAny {
@@ -54,6 +62,22 @@ impl From<SocketAddrUnix> for SocketAddrAny {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<SocketAddrNetlink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrNetlink) -> Self {
+        Self::Netlink(from)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<SocketAddrLink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrLink) -> Self {
+        Self::Link(from)
+    }
+}
+
 impl SocketAddrAny {
     /// Return the address family of this socket address.
     #[inline]
@@ -63,6 +87,10 @@ impl SocketAddrAny {
             Self::V6(_) => AddressFamily::INET6,
             #[cfg(unix)]
             Self::Unix(_) => AddressFamily::UNIX,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Netlink(_) => AddressFamily::NETLINK,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Link(_) => AddressFamily::PACKET,
         }
     }
 
@@ -98,6 +126,10 @@ impl fmt::Debug for SocketAddrAny {
             Self::V6(v6) => v6.fmt(fmt),
             #[cfg(unix)]
             Self::Unix(unix) => unix.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Netlink(netlink) => netlink.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Link(link) => link.fmt(fmt),
         }
     }
 }
@@ -10,8 +10,18 @@ use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
 use crate::{backend, io};
 use backend::fd::AsFd;
 use core::time::Duration;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    crate::fd::OwnedFd,
+    crate::net::packet::{
+        PacketFanoutFlags, PacketFanoutMode, PacketMembership, PacketVersion, TpacketReq3,
+    },
+    crate::net::UCred,
+};
 
 pub use backend::net::types::Timeout;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use backend::net::types::TimestampingFlags;
 
 /// `getsockopt(fd, SOL_SOCKET, SO_TYPE)`—Returns the type of a socket.
 ///
@@ -271,6 +281,156 @@ pub fn get_socket_passcred<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_socket_passcred(fd.as_fd())
 }
 
+/// `getsockopt(fd, SOL_SOCKET, SO_PEERCRED)`—Returns the credentials of the
+/// peer of a Unix-domain socket.
+///
+/// These are the credentials of the process which called `connect`,
+/// `listen`, or `socketpair` for the peer socket, at the time of the call.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `unix`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PEERCRED")]
+pub fn get_socket_peercred<Fd: AsFd>(fd: Fd) -> io::Result<UCred> {
+    backend::net::syscalls::sockopt::get_socket_peercred(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_PASSPIDFD, passpidfd)`
+///
+/// This requires Linux 6.5 or later.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `unix`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PASSPIDFD")]
+pub fn set_socket_passpidfd<Fd: AsFd>(fd: Fd, passpidfd: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_passpidfd(fd.as_fd(), passpidfd)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PASSPIDFD)`
+///
+/// This requires Linux 6.5 or later.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `unix`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PASSPIDFD")]
+pub fn get_socket_passpidfd<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_socket_passpidfd(fd.as_fd())
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PEERPIDFD)`—Returns a pidfd for the peer
+/// of a Unix-domain socket.
+///
+/// The peer is the process identified by [`get_socket_peercred`]. The
+/// returned file descriptor has its close-on-exec flag set.
+///
+/// This requires Linux 6.5 or later.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `unix`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PEERPIDFD")]
+pub fn get_socket_peerpidfd<Fd: AsFd>(fd: Fd) -> io::Result<OwnedFd> {
+    backend::net::syscalls::sockopt::get_socket_peerpidfd(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS, timestampns)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::ScmTimestampns`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`RecvAncillaryMessage::ScmTimestampns`]: crate::net::RecvAncillaryMessage::ScmTimestampns
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn set_socket_timestampns<Fd: AsFd>(fd: Fd, timestampns: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timestampns(fd.as_fd(), timestampns)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::ScmTimestampns`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`RecvAncillaryMessage::ScmTimestampns`]: crate::net::RecvAncillaryMessage::ScmTimestampns
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn get_socket_timestampns<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_socket_timestampns(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPING, flags)`
+///
+/// When receive timestamps are generated and reported, received packets
+/// carry a [`RecvAncillaryMessage::ScmTimestamping`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`RecvAncillaryMessage::ScmTimestamping`]: crate::net::RecvAncillaryMessage::ScmTimestamping
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPING")]
+pub fn set_socket_timestamping<Fd: AsFd>(fd: Fd, flags: TimestampingFlags) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timestamping(fd.as_fd(), flags)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMPING)`
+///
+/// When receive timestamps are generated and reported, received packets
+/// carry a [`RecvAncillaryMessage::ScmTimestamping`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`RecvAncillaryMessage::ScmTimestamping`]: crate::net::RecvAncillaryMessage::ScmTimestamping
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPING")]
+pub fn get_socket_timestamping<Fd: AsFd>(fd: Fd) -> io::Result<TimestampingFlags> {
+    backend::net::syscalls::sockopt::get_socket_timestamping(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, id, timeout)`—Set the sending or receiving
 /// timeout.
 ///
@@ -789,6 +949,120 @@ pub fn get_ip_ttl<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
     backend::net::syscalls::sockopt::get_ip_ttl(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IP, IP_PKTINFO, pktinfo)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpPktinfo`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpPktinfo`]: crate::net::RecvAncillaryMessage::IpPktinfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn set_ip_pktinfo<Fd: AsFd>(fd: Fd, pktinfo: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_pktinfo(fd.as_fd(), pktinfo)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_PKTINFO)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpPktinfo`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpPktinfo`]: crate::net::RecvAncillaryMessage::IpPktinfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn get_ip_pktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ip_pktinfo(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_RECVTTL, recvttl)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpTtl`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpTtl`]: crate::net::RecvAncillaryMessage::IpTtl
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVTTL")]
+pub fn set_ip_recvttl<Fd: AsFd>(fd: Fd, recvttl: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_recvttl(fd.as_fd(), recvttl)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_RECVTTL)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpTtl`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpTtl`]: crate::net::RecvAncillaryMessage::IpTtl
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVTTL")]
+pub fn get_ip_recvttl<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ip_recvttl(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_RECVTOS, recvtos)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpTos`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpTos`]: crate::net::RecvAncillaryMessage::IpTos
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVTOS")]
+pub fn set_ip_recvtos<Fd: AsFd>(fd: Fd, recvtos: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_recvtos(fd.as_fd(), recvtos)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_RECVTOS)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::IpTos`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::IpTos`]: crate::net::RecvAncillaryMessage::IpTos
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVTOS")]
+pub fn get_ip_recvtos<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ip_recvtos(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IPV6, IPV6_V6ONLY, only_v6)`
 ///
 /// # References
@@ -881,6 +1155,120 @@ pub fn get_ipv6_v6only<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_ipv6_v6only(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO, recvpktinfo)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6Pktinfo`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6Pktinfo`]: crate::net::RecvAncillaryMessage::Ipv6Pktinfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn set_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd, recvpktinfo: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_recvpktinfo(fd.as_fd(), recvpktinfo)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6Pktinfo`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6Pktinfo`]: crate::net::RecvAncillaryMessage::Ipv6Pktinfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn get_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ipv6_recvpktinfo(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVHOPLIMIT, recvhoplimit)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6HopLimit`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6HopLimit`]: crate::net::RecvAncillaryMessage::Ipv6HopLimit
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVHOPLIMIT")]
+pub fn set_ipv6_recvhoplimit<Fd: AsFd>(fd: Fd, recvhoplimit: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_recvhoplimit(fd.as_fd(), recvhoplimit)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVHOPLIMIT)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6HopLimit`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6HopLimit`]: crate::net::RecvAncillaryMessage::Ipv6HopLimit
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVHOPLIMIT")]
+pub fn get_ipv6_recvhoplimit<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ipv6_recvhoplimit(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVTCLASS, recvtclass)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6Tclass`] message.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6Tclass`]: crate::net::RecvAncillaryMessage::Ipv6Tclass
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVTCLASS")]
+pub fn set_ipv6_recvtclass<Fd: AsFd>(fd: Fd, recvtclass: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_recvtclass(fd.as_fd(), recvtclass)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVTCLASS)`
+///
+/// When enabled, received packets carry a
+/// [`RecvAncillaryMessage::Ipv6Tclass`] message.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6Tclass`]: crate::net::RecvAncillaryMessage::Ipv6Tclass
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVTCLASS")]
+pub fn get_ipv6_recvtclass<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ipv6_recvtclass(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_MULTICAST_LOOP, multicast_loop)`
 ///
 /// # References
@@ -1614,3 +2002,246 @@ pub fn set_tcp_nodelay<Fd: AsFd>(fd: Fd, nodelay: bool) -> io::Result<()> {
 pub fn get_tcp_nodelay<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_tcp_nodelay(fd.as_fd())
 }
+
+/// `setsockopt(fd, IPPROTO_UDP, UDP_GRO, gro)`
+///
+/// When enabled, consecutive received datagrams may be coalesced into one,
+/// which carries a [`RecvAncillaryMessage::UdpGro`] message with the
+/// segment size.
+///
+/// This requires Linux 5.0 or later.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `udp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `udp`]: https://man7.org/linux/man-pages/man7/udp.7.html
+/// [`RecvAncillaryMessage::UdpGro`]: crate::net::RecvAncillaryMessage::UdpGro
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "UDP_GRO")]
+pub fn set_udp_gro<Fd: AsFd>(fd: Fd, gro: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_udp_gro(fd.as_fd(), gro)
+}
+
+/// `getsockopt(fd, IPPROTO_UDP, UDP_GRO)`
+///
+/// When enabled, consecutive received datagrams may be coalesced into one,
+/// which carries a [`RecvAncillaryMessage::UdpGro`] message with the
+/// segment size.
+///
+/// This requires Linux 5.0 or later.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `udp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `udp`]: https://man7.org/linux/man-pages/man7/udp.7.html
+/// [`RecvAncillaryMessage::UdpGro`]: crate::net::RecvAncillaryMessage::UdpGro
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "UDP_GRO")]
+pub fn get_udp_gro<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_udp_gro(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_UDP, UDP_SEGMENT, segment_size)`
+///
+/// When non-zero, sent datagrams are split into segments of this size using
+/// generic segmentation offload. This can be overridden for a single call
+/// with [`SendAncillaryMessage::UdpSegment`].
+///
+/// This requires Linux 4.18 or later.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `udp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `udp`]: https://man7.org/linux/man-pages/man7/udp.7.html
+/// [`SendAncillaryMessage::UdpSegment`]: crate::net::SendAncillaryMessage::UdpSegment
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "UDP_SEGMENT")]
+pub fn set_udp_segment<Fd: AsFd>(fd: Fd, segment_size: u16) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_udp_segment(fd.as_fd(), segment_size)
+}
+
+/// `getsockopt(fd, IPPROTO_UDP, UDP_SEGMENT)`
+///
+/// When non-zero, sent datagrams are split into segments of this size using
+/// generic segmentation offload. This can be overridden for a single call
+/// with [`SendAncillaryMessage::UdpSegment`].
+///
+/// This requires Linux 4.18 or later.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `udp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `udp`]: https://man7.org/linux/man-pages/man7/udp.7.html
+/// [`SendAncillaryMessage::UdpSegment`]: crate::net::SendAncillaryMessage::UdpSegment
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "UDP_SEGMENT")]
+pub fn get_udp_segment<Fd: AsFd>(fd: Fd) -> io::Result<u16> {
+    backend::net::syscalls::sockopt::get_udp_segment(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_ADD_MEMBERSHIP, mreq)`
+///
+/// Adds a physical-layer multicast or unicast address, or enables
+/// promiscuous or all-multicast mode, on the interface with index
+/// `ifindex`. `address` is only used for [`PacketMembership::Multicast`] and
+/// [`PacketMembership::Unicast`], and may be up to 8 bytes long.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_ADD_MEMBERSHIP")]
+pub fn set_packet_add_membership<Fd: AsFd>(
+    fd: Fd,
+    ifindex: u32,
+    membership: PacketMembership,
+    address: &[u8],
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_add_membership(
+        fd.as_fd(),
+        ifindex,
+        membership,
+        address,
+    )
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_DROP_MEMBERSHIP, mreq)`
+///
+/// Reverses a previous [`set_packet_add_membership`] with the same
+/// arguments.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_DROP_MEMBERSHIP")]
+pub fn set_packet_drop_membership<Fd: AsFd>(
+    fd: Fd,
+    ifindex: u32,
+    membership: PacketMembership,
+    address: &[u8],
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_drop_membership(
+        fd.as_fd(),
+        ifindex,
+        membership,
+        address,
+    )
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_VERSION, version)`
+///
+/// This must be set before creating a ring with [`set_packet_rx_ring`] or
+/// [`set_packet_tx_ring`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_VERSION")]
+pub fn set_packet_version<Fd: AsFd>(fd: Fd, version: PacketVersion) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_version(fd.as_fd(), version)
+}
+
+/// `getsockopt(fd, SOL_PACKET, PACKET_VERSION)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_VERSION")]
+pub fn get_packet_version<Fd: AsFd>(fd: Fd) -> io::Result<PacketVersion> {
+    backend::net::syscalls::sockopt::get_packet_version(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_FANOUT, group_id | (mode | flags) << 16)`
+///
+/// Adds the socket to the fanout group `group_id`, creating it if needed.
+/// Packets are distributed among the sockets in the group according to
+/// `mode`. All sockets in a group must use the same mode and flags, and be
+/// bound to the same protocol and interface.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_FANOUT")]
+pub fn set_packet_fanout<Fd: AsFd>(
+    fd: Fd,
+    group_id: u16,
+    mode: PacketFanoutMode,
+    flags: PacketFanoutFlags,
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_fanout(fd.as_fd(), group_id, mode, flags)
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_RX_RING, req)`
+///
+/// Creates a memory-mapped receive ring. This requires
+/// [`PacketVersion::V3`]; see [`PacketRxRing`] for a reader which sets the
+/// version, creates the ring, and maps it.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+/// [`PacketRxRing`]: crate::net::packet::PacketRxRing
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_RX_RING")]
+pub fn set_packet_rx_ring<Fd: AsFd>(fd: Fd, req: &TpacketReq3) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_rx_ring(fd.as_fd(), req)
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_TX_RING, req)`
+///
+/// Creates a memory-mapped transmit ring. This requires
+/// [`PacketVersion::V3`], which is supported for transmit rings since Linux
+/// 4.20.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `packet`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `packet`]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "PACKET_TX_RING")]
+pub fn set_packet_tx_ring<Fd: AsFd>(fd: Fd, req: &TpacketReq3) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_packet_tx_ring(fd.as_fd(), req)
+}
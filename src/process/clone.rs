@@ -0,0 +1,225 @@
+//! The Linux `clone3` system call.
+//!
+//! # Safety
+//!
+//! `clone3` creates a new process which begins executing in the middle of the
+//! caller, with a copy of its memory but only the calling thread. See
+//! [`clone3`] for what code in the child may do.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::process::{Pid, Signal};
+use crate::{backend, io};
+use linux_raw_sys::general::clone_args;
+
+bitflags::bitflags! {
+    /// `CLONE_*` flags for use with [`CloneArgs`].
+    ///
+    /// This includes only the flags which are meaningful for creating a new
+    /// process with its own copy of the caller's memory. Some of these, such
+    /// as `CLONE_FILES` and `CLONE_FS`, share other state with the caller.
+    /// Flags which share the caller's memory or make the child a thread, such
+    /// as `CLONE_VM`, `CLONE_SIGHAND` and `CLONE_THREAD`, are not supported.
+    ///
+    /// `CLONE_INTO_CGROUP` is not included; it's set by [`CloneArgs::cgroup`]
+    /// together with the cgroup file descriptor.
+    pub struct CloneFlags: u64 {
+        /// `CLONE_CLEAR_SIGHAND` (since Linux 5.5)
+        const CLEAR_SIGHAND = linux_raw_sys::general::CLONE_CLEAR_SIGHAND;
+        /// `CLONE_FILES`
+        const FILES = linux_raw_sys::general::CLONE_FILES as u64;
+        /// `CLONE_FS`
+        const FS = linux_raw_sys::general::CLONE_FS as u64;
+        /// `CLONE_IO`
+        const IO = linux_raw_sys::general::CLONE_IO as u64;
+        /// `CLONE_NEWCGROUP`
+        const NEWCGROUP = linux_raw_sys::general::CLONE_NEWCGROUP as u64;
+        /// `CLONE_NEWIPC`
+        const NEWIPC = linux_raw_sys::general::CLONE_NEWIPC as u64;
+        /// `CLONE_NEWNET`
+        const NEWNET = linux_raw_sys::general::CLONE_NEWNET as u64;
+        /// `CLONE_NEWNS`
+        const NEWNS = linux_raw_sys::general::CLONE_NEWNS as u64;
+        /// `CLONE_NEWPID`
+        const NEWPID = linux_raw_sys::general::CLONE_NEWPID as u64;
+        /// `CLONE_NEWTIME` (since Linux 5.6)
+        const NEWTIME = linux_raw_sys::general::CLONE_NEWTIME as u64;
+        /// `CLONE_NEWUSER`
+        const NEWUSER = linux_raw_sys::general::CLONE_NEWUSER as u64;
+        /// `CLONE_NEWUTS`
+        const NEWUTS = linux_raw_sys::general::CLONE_NEWUTS as u64;
+        /// `CLONE_PARENT`
+        const PARENT = linux_raw_sys::general::CLONE_PARENT as u64;
+        /// `CLONE_PIDFD`
+        ///
+        /// The pidfd is returned in [`CloneResult::Parent`].
+        const PIDFD = linux_raw_sys::general::CLONE_PIDFD as u64;
+        /// `CLONE_PTRACE`
+        const PTRACE = linux_raw_sys::general::CLONE_PTRACE as u64;
+        /// `CLONE_SYSVSEM`
+        const SYSVSEM = linux_raw_sys::general::CLONE_SYSVSEM as u64;
+        /// `CLONE_UNTRACED`
+        const UNTRACED = linux_raw_sys::general::CLONE_UNTRACED as u64;
+    }
+}
+
+/// Arguments for [`clone3`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::process::{clone3, CloneArgs, CloneFlags, CloneResult};
+///
+/// let args = CloneArgs::new(CloneFlags::NEWUSER | CloneFlags::PIDFD);
+///
+/// // SAFETY: The child only calls async-signal-safe functions.
+/// match unsafe { clone3(&args)? } {
+///     CloneResult::Child => {
+///         // Set up the new user namespace and `execve` something.
+///     }
+///     CloneResult::Parent { pid, pidfd } => {
+///         // Supervise the child through `pidfd`.
+/// #       let _ = (pid, pidfd);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct CloneArgs<'a> {
+    flags: CloneFlags,
+    exit_signal: Option<Signal>,
+    cgroup: Option<BorrowedFd<'a>>,
+}
+
+impl<'a> CloneArgs<'a> {
+    /// Construct a new `CloneArgs` with the given flags.
+    ///
+    /// The child sends [`Signal::Child`] to the parent when it exits, like a
+    /// child created with `fork`.
+    #[inline]
+    pub const fn new(flags: CloneFlags) -> Self {
+        Self {
+            flags,
+            exit_signal: Some(Signal::Child),
+            cgroup: None,
+        }
+    }
+
+    /// Set the signal the child sends to the parent when it exits, or `None`
+    /// to send no signal.
+    #[inline]
+    pub fn exit_signal(mut self, exit_signal: Option<Signal>) -> Self {
+        self.exit_signal = exit_signal;
+        self
+    }
+
+    /// Place the child in the cgroup referred to by `cgroup`, a directory file
+    /// descriptor in the cgroup v2 hierarchy.
+    ///
+    /// This sets `CLONE_INTO_CGROUP` (since Linux 5.7).
+    #[inline]
+    pub fn cgroup(mut self, cgroup: BorrowedFd<'a>) -> Self {
+        self.cgroup = Some(cgroup);
+        self
+    }
+
+    /// Return the flags.
+    #[inline]
+    pub const fn flags(&self) -> CloneFlags {
+        self.flags
+    }
+}
+
+impl<'a> core::fmt::Debug for CloneArgs<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CloneArgs")
+            .field("flags", &self.flags)
+            .field("exit_signal", &self.exit_signal)
+            .field("cgroup", &self.cgroup)
+            .finish()
+    }
+}
+
+/// The result of [`clone3`], which returns once in the parent and once in
+/// the child.
+#[derive(Debug)]
+pub enum CloneResult {
+    /// Returned in the parent.
+    Parent {
+        /// The process ID of the child.
+        pid: Pid,
+
+        /// A pidfd for the child, if [`CloneFlags::PIDFD`] was set.
+        pidfd: Option<OwnedFd>,
+    },
+
+    /// Returned in the child.
+    Child,
+}
+
+/// `clone3(args, sizeof(struct clone_args))`—Creates a new process.
+///
+/// This is like `fork`, with additional options for placing the child in new
+/// namespaces or a cgroup atomically, and for obtaining a pidfd for it
+/// without any race against PID reuse.
+///
+/// Like [`rustix::runtime::fork`], this does not invoke any handlers (such as
+/// those registered with `pthread_atfork`), and does not update any of libc's
+/// or the threading runtime's state in the child.
+///
+/// This requires Linux 5.3 or later, and some flags require later versions.
+///
+/// # Safety
+///
+/// The child is a copy of the calling process containing only the calling
+/// thread, and must not rely on any state shared with other threads. Until it
+/// calls `execve` or exits, the child must only call [async-signal-safe]
+/// functions; in particular it must not allocate, acquire locks that may be
+/// held by other threads of the parent, or call into libc or the threading
+/// runtime in ways that depend on the current thread's identity.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/clone3.2.html
+/// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
+/// [`rustix::runtime::fork`]: https://docs.rs/rustix/*/rustix/runtime/fn.fork.html
+#[inline]
+pub unsafe fn clone3(args: &CloneArgs<'_>) -> io::Result<CloneResult> {
+    let mut pidfd = -1;
+    let mut flags = args.flags.bits();
+    if args.cgroup.is_some() {
+        flags |= linux_raw_sys::general::CLONE_INTO_CGROUP;
+    }
+    let raw = clone_args {
+        flags,
+        pidfd: if args.flags.contains(CloneFlags::PIDFD) {
+            &mut pidfd as *mut RawFd as usize as u64
+        } else {
+            0
+        },
+        child_tid: 0,
+        parent_tid: 0,
+        exit_signal: args.exit_signal.map_or(0, |sig| sig.as_raw() as u64),
+        stack: 0,
+        stack_size: 0,
+        tls: 0,
+        set_tid: 0,
+        set_tid_size: 0,
+        cgroup: args.cgroup.map_or(0, |fd| fd.as_raw_fd() as u64),
+    };
+
+    match backend::process::syscalls::clone3(&raw)? {
+        None => Ok(CloneResult::Child),
+        Some(pid) => Ok(CloneResult::Parent {
+            pid,
+            pidfd: if args.flags.contains(CloneFlags::PIDFD) {
+                Some(OwnedFd::from_raw_fd(pidfd))
+            } else {
+                None
+            },
+        }),
+    }
+}
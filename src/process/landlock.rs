@@ -0,0 +1,282 @@
+//! Linux Landlock unprivileged sandboxing.
+//!
+//! A process sandboxes itself by creating a ruleset with
+//! [`landlock_create_ruleset`], which lists the kinds of access it handles,
+//! adding rules which allow some of that access with [`landlock_add_rule`],
+//! and enforcing the ruleset on itself with [`landlock_restrict_self`]. After
+//! that, any handled access which isn't allowed by a rule is denied.
+//!
+//! Unless the caller has `CAP_SYS_ADMIN` in its user namespace, the
+//! `no_new_privs` attribute must be set before calling
+//! [`landlock_restrict_self`], with `rustix::thread::set_no_new_privs`.
+//!
+//! # Safety
+//!
+//! The Landlock system calls are passed pointers to kernel-layout structs
+//! defined in this module.
+#![allow(unsafe_code)]
+
+use crate::backend::c::{c_uint, c_void};
+use crate::backend::process::syscalls;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd};
+use crate::io;
+use core::mem::size_of;
+use core::ptr::null;
+
+const LANDLOCK_CREATE_RULESET_VERSION: c_uint = 1 << 0;
+
+const LANDLOCK_RULE_PATH_BENEATH: c_uint = 1;
+const LANDLOCK_RULE_NET_PORT: c_uint = 2;
+
+bitflags::bitflags! {
+    /// `LANDLOCK_ACCESS_FS_*` flags for use with [`RulesetAttr`] and
+    /// [`Rule::PathBeneath`].
+    pub struct AccessFs: u64 {
+        /// `LANDLOCK_ACCESS_FS_EXECUTE`
+        const EXECUTE = 1 << 0;
+        /// `LANDLOCK_ACCESS_FS_WRITE_FILE`
+        const WRITE_FILE = 1 << 1;
+        /// `LANDLOCK_ACCESS_FS_READ_FILE`
+        const READ_FILE = 1 << 2;
+        /// `LANDLOCK_ACCESS_FS_READ_DIR`
+        const READ_DIR = 1 << 3;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_DIR`
+        const REMOVE_DIR = 1 << 4;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_FILE`
+        const REMOVE_FILE = 1 << 5;
+        /// `LANDLOCK_ACCESS_FS_MAKE_CHAR`
+        const MAKE_CHAR = 1 << 6;
+        /// `LANDLOCK_ACCESS_FS_MAKE_DIR`
+        const MAKE_DIR = 1 << 7;
+        /// `LANDLOCK_ACCESS_FS_MAKE_REG`
+        const MAKE_REG = 1 << 8;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SOCK`
+        const MAKE_SOCK = 1 << 9;
+        /// `LANDLOCK_ACCESS_FS_MAKE_FIFO`
+        const MAKE_FIFO = 1 << 10;
+        /// `LANDLOCK_ACCESS_FS_MAKE_BLOCK`
+        const MAKE_BLOCK = 1 << 11;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SYM`
+        const MAKE_SYM = 1 << 12;
+        /// `LANDLOCK_ACCESS_FS_REFER` (ABI version 2, since Linux 5.19)
+        const REFER = 1 << 13;
+        /// `LANDLOCK_ACCESS_FS_TRUNCATE` (ABI version 3, since Linux 6.2)
+        const TRUNCATE = 1 << 14;
+        /// `LANDLOCK_ACCESS_FS_IOCTL_DEV` (ABI version 5, since Linux 6.10)
+        const IOCTL_DEV = 1 << 15;
+    }
+}
+
+impl AccessFs {
+    /// Return all the access rights supported by Landlock ABI version `abi`,
+    /// as returned by [`landlock_abi_version`].
+    pub const fn from_abi(abi: u32) -> Self {
+        let bits = match abi {
+            0 => 0,
+            1 => (1 << 13) - 1,
+            2 => (1 << 14) - 1,
+            3 | 4 => (1 << 15) - 1,
+            _ => (1 << 16) - 1,
+        };
+        Self::from_bits_truncate(bits)
+    }
+}
+
+bitflags::bitflags! {
+    /// `LANDLOCK_ACCESS_NET_*` flags for use with [`RulesetAttr`] and
+    /// [`Rule::NetPort`].
+    pub struct AccessNet: u64 {
+        /// `LANDLOCK_ACCESS_NET_BIND_TCP` (ABI version 4, since Linux 6.7)
+        const BIND_TCP = 1 << 0;
+        /// `LANDLOCK_ACCESS_NET_CONNECT_TCP` (ABI version 4, since Linux 6.7)
+        const CONNECT_TCP = 1 << 1;
+    }
+}
+
+impl AccessNet {
+    /// Return all the access rights supported by Landlock ABI version `abi`,
+    /// as returned by [`landlock_abi_version`].
+    pub const fn from_abi(abi: u32) -> Self {
+        let bits = match abi {
+            0..=3 => 0,
+            _ => (1 << 2) - 1,
+        };
+        Self::from_bits_truncate(bits)
+    }
+}
+
+/// `struct landlock_ruleset_attr`—The access rights handled by a ruleset,
+/// for use with [`landlock_create_ruleset`].
+///
+/// Access rights which aren't handled are always allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "landlock_ruleset_attr")]
+pub struct RulesetAttr {
+    /// Filesystem access rights handled by the ruleset.
+    pub handled_access_fs: AccessFs,
+
+    /// Network access rights handled by the ruleset.
+    pub handled_access_net: AccessNet,
+}
+
+/// `struct landlock_ruleset_attr`
+#[repr(C)]
+struct RawRulesetAttr {
+    handled_access_fs: u64,
+    handled_access_net: u64,
+}
+
+/// A rule for use with [`landlock_add_rule`].
+#[derive(Debug, Clone, Copy)]
+pub enum Rule<'a> {
+    /// `LANDLOCK_RULE_PATH_BENEATH`—Allow access to a file, or to everything
+    /// beneath a directory.
+    #[doc(alias = "landlock_path_beneath_attr")]
+    PathBeneath {
+        /// The allowed access, which must be handled by the ruleset.
+        allowed_access: AccessFs,
+
+        /// A file descriptor for the file or directory, which may be opened
+        /// with `OFlags::PATH`.
+        parent: BorrowedFd<'a>,
+    },
+
+    /// `LANDLOCK_RULE_NET_PORT`—Allow access to a TCP port (ABI version 4,
+    /// since Linux 6.7).
+    #[doc(alias = "landlock_net_port_attr")]
+    NetPort {
+        /// The allowed access, which must be handled by the ruleset.
+        allowed_access: AccessNet,
+
+        /// The port, in host byte order.
+        port: u16,
+    },
+}
+
+/// `struct landlock_path_beneath_attr`
+#[repr(C, packed)]
+struct RawPathBeneathAttr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+/// `struct landlock_net_port_attr`
+#[repr(C)]
+struct RawNetPortAttr {
+    allowed_access: u64,
+    port: u64,
+}
+
+/// `landlock_create_ruleset(NULL, 0, LANDLOCK_CREATE_RULESET_VERSION)`—Return
+/// the highest Landlock ABI version supported by the kernel.
+///
+/// This fails with [`io::Errno::NOSYS`] if the kernel doesn't support
+/// Landlock, and with [`io::Errno::OPNOTSUPP`] if Landlock is supported but
+/// disabled at boot time.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+#[doc(alias = "LANDLOCK_CREATE_RULESET_VERSION")]
+pub fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        syscalls::landlock_create_ruleset(null(), 0, LANDLOCK_CREATE_RULESET_VERSION)
+            .map(|abi| abi as u32)
+    }
+}
+
+/// `landlock_create_ruleset(attr, sizeof(*attr), 0)`—Create a new Landlock
+/// ruleset.
+///
+/// The returned file descriptor has its close-on-exec flag set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+pub fn landlock_create_ruleset(attr: &RulesetAttr) -> io::Result<OwnedFd> {
+    let raw = RawRulesetAttr {
+        handled_access_fs: attr.handled_access_fs.bits(),
+        handled_access_net: attr.handled_access_net.bits(),
+    };
+    // Kernels older than ABI version 4 don't know about
+    // `handled_access_net`. They accept it if it's zero, but pass the
+    // shorter size when we can anyway.
+    let size = if attr.handled_access_net.is_empty() {
+        size_of::<u64>()
+    } else {
+        size_of::<RawRulesetAttr>()
+    };
+    unsafe {
+        let fd = syscalls::landlock_create_ruleset(
+            &raw as *const RawRulesetAttr as *const c_void,
+            size,
+            0,
+        )?;
+        Ok(OwnedFd::from_raw_fd(fd))
+    }
+}
+
+/// `landlock_add_rule(ruleset, rule_type, rule_attr, 0)`—Add a rule to a
+/// Landlock ruleset.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+#[inline]
+pub fn landlock_add_rule<Fd: AsFd>(ruleset: Fd, rule: &Rule<'_>) -> io::Result<()> {
+    match *rule {
+        Rule::PathBeneath {
+            allowed_access,
+            parent,
+        } => {
+            let raw = RawPathBeneathAttr {
+                allowed_access: allowed_access.bits(),
+                parent_fd: parent.as_raw_fd(),
+            };
+            unsafe {
+                syscalls::landlock_add_rule(
+                    ruleset.as_fd(),
+                    LANDLOCK_RULE_PATH_BENEATH,
+                    &raw as *const RawPathBeneathAttr as *const c_void,
+                )
+            }
+        }
+        Rule::NetPort {
+            allowed_access,
+            port,
+        } => {
+            let raw = RawNetPortAttr {
+                allowed_access: allowed_access.bits(),
+                port: port.into(),
+            };
+            unsafe {
+                syscalls::landlock_add_rule(
+                    ruleset.as_fd(),
+                    LANDLOCK_RULE_NET_PORT,
+                    &raw as *const RawNetPortAttr as *const c_void,
+                )
+            }
+        }
+    }
+}
+
+/// `landlock_restrict_self(ruleset, 0)`—Enforce a Landlock ruleset on the
+/// calling thread.
+///
+/// The ruleset applies to the calling thread and to threads and processes it
+/// creates afterwards, and can't be removed. Enforcing several rulesets
+/// stacks them, so that access must be allowed by all of them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html
+#[inline]
+pub fn landlock_restrict_self<Fd: AsFd>(ruleset: Fd) -> io::Result<()> {
+    syscalls::landlock_restrict_self(ruleset.as_fd())
+}
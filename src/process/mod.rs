@@ -4,12 +4,16 @@
 mod chdir;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 mod chroot;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod clone;
 mod exit;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(not(target_os = "wasi"))]
 mod kill;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod landlock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
 #[cfg(target_os = "linux")]
 mod pidfd;
@@ -19,6 +23,10 @@ mod prctl;
 mod priority;
 #[cfg(target_os = "freebsd")]
 mod procctl;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod process_vm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 #[cfg(any(
@@ -29,6 +37,10 @@ mod rlimit;
 ))]
 mod sched;
 mod sched_yield;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod seccomp;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod signalfd;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod system;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
@@ -40,6 +52,8 @@ mod wait;
 pub use chdir::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub use chroot::*;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use clone::*;
 pub use exit::*;
 #[cfg(not(target_os = "wasi"))]
 pub use id::*;
@@ -55,6 +69,10 @@ pub use prctl::*;
 pub use priority::*;
 #[cfg(target_os = "freebsd")]
 pub use procctl::*;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use process_vm::*;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use ptrace::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 pub use rlimit::*;
 #[cfg(any(
@@ -65,6 +83,8 @@ pub use rlimit::*;
 ))]
 pub use sched::*;
 pub use sched_yield::sched_yield;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use signalfd::*;
 #[cfg(not(target_os = "wasi"))]
 pub use system::*;
 #[cfg(not(target_os = "wasi"))]
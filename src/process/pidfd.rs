@@ -1,6 +1,14 @@
-use crate::fd::OwnedFd;
-use crate::process::Pid;
+//! # Safety
+//!
+//! [`PidfdSiginfo`] reads the `siginfo_t` union fields that its constructor
+//! initializes.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, OwnedFd, RawFd};
+use crate::process::{getpid, getuid, Pid, Signal};
 use crate::{backend, io};
+use core::fmt;
+use linux_raw_sys::general::siginfo_t;
 
 bitflags::bitflags! {
     /// `PIDFD_*` flags for use with [`pidfd_open`].
@@ -12,9 +20,59 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    /// `PIDFD_SIGNAL_*` flags for use with [`pidfd_send_signal`].
+    ///
+    /// [`pidfd_send_signal`]: crate::process::pidfd_send_signal
+    pub struct PidfdSignalFlags: backend::c::c_uint {
+        /// `PIDFD_SIGNAL_THREAD` (since Linux 6.9)
+        const THREAD = backend::c::PIDFD_SIGNAL_THREAD;
+        /// `PIDFD_SIGNAL_THREAD_GROUP` (since Linux 6.9)
+        const THREAD_GROUP = backend::c::PIDFD_SIGNAL_THREAD_GROUP;
+        /// `PIDFD_SIGNAL_PROCESS_GROUP` (since Linux 6.9)
+        const PROCESS_GROUP = backend::c::PIDFD_SIGNAL_PROCESS_GROUP;
+    }
+}
+
+bitflags::bitflags! {
+    /// `PIDFD_GETFD_*` flags for use with [`pidfd_getfd`].
+    ///
+    /// No flags are currently defined.
+    ///
+    /// [`pidfd_getfd`]: crate::process::pidfd_getfd
+    pub struct PidfdGetfdFlags: backend::c::c_uint {}
+}
+
 /// `syscall(SYS_pidfd_open, pid, flags)`—Creates a file descriptor for
 /// a process.
 ///
+/// The file descriptor refers to the process itself rather than to its PID,
+/// so it can't be confused with a different process that later reuses the
+/// PID. It becomes readable when the process exits, so it can be waited for
+/// with [`poll`] or [`epoll`] alongside other file descriptors.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::fd::AsFd;
+/// use rustix::io::{poll, PollFd, PollFlags};
+/// use rustix::process::{pidfd_open, waitid, PidfdFlags, WaitId, WaitidOptions};
+///
+/// let child = std::process::Command::new("true").spawn().unwrap();
+/// let pid = rustix::process::Pid::from_child(&child);
+/// let pidfd = pidfd_open(pid, PidfdFlags::empty())?;
+///
+/// // Wait for the process to exit.
+/// poll(&mut [PollFd::new(&pidfd, PollFlags::IN)], -1)?;
+///
+/// // Reap it, without any risk of reaping a different process.
+/// let status = waitid(WaitId::PidFd(pidfd.as_fd()), WaitidOptions::EXITED)?;
+/// # let _ = status;
+/// # Ok(())
+/// # }
+/// ```
+///
 /// # References
 ///  - [Linux]
 ///
@@ -23,3 +81,123 @@ bitflags::bitflags! {
 pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     backend::process::syscalls::pidfd_open(pid, flags)
 }
+
+/// A `siginfo_t` to send with [`pidfd_send_signal`].
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[doc(alias = "siginfo_t")]
+pub struct PidfdSiginfo(siginfo_t);
+
+impl PidfdSiginfo {
+    /// Construct a `siginfo_t` for `sig` with the signal code `code` and the
+    /// value `value`, and with the PID and real UID of the calling process,
+    /// like the one `sigqueue` sends.
+    ///
+    /// When sending to a different process, the kernel only accepts negative
+    /// codes, such as `SI_QUEUE` (-1), so that the signal can't be mistaken
+    /// for one generated by the kernel.
+    #[inline]
+    pub fn new(sig: Signal, code: i32, value: usize) -> Self {
+        // SAFETY: `siginfo_t` is plain data, so all zeros is a valid value.
+        let mut info: siginfo_t = unsafe { core::mem::zeroed() };
+        // SAFETY: The zeroed union is valid as any of its variants, and the
+        // struct with the common fields is the one the kernel reads.
+        let fields = unsafe { &mut info.__bindgen_anon_1.__bindgen_anon_1 };
+        fields.si_signo = sig.as_raw() as _;
+        fields.si_code = code;
+        fields._sifields._rt._pid = Pid::as_raw(Some(getpid())) as _;
+        fields._sifields._rt._uid = getuid().as_raw() as _;
+        fields._sifields._rt._sigval.sival_ptr = value as *mut _;
+        Self(info)
+    }
+
+    /// Return the signal, if it's a valid signal number.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.raw_signal() as _)
+    }
+
+    /// Return the raw signal number, `si_signo`.
+    #[inline]
+    pub fn raw_signal(&self) -> i32 {
+        // SAFETY: The common fields are always initialized.
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_signo }
+    }
+
+    /// Return the signal code, `si_code`.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        // SAFETY: The common fields are always initialized.
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_code }
+    }
+
+    /// Return the value, `si_value`.
+    #[inline]
+    pub fn value(&self) -> usize {
+        // SAFETY: `new` initializes the `_rt` fields.
+        unsafe {
+            self.0
+                .__bindgen_anon_1
+                .__bindgen_anon_1
+                ._sifields
+                ._rt
+                ._sigval
+                .sival_ptr as usize
+        }
+    }
+}
+
+impl fmt::Debug for PidfdSiginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PidfdSiginfo")
+            .field("signo", &self.raw_signal())
+            .field("code", &self.code())
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+/// `syscall(SYS_pidfd_send_signal, pidfd, sig, info, flags)`—Sends a signal
+/// to the process referred to by a pidfd.
+///
+/// Unlike [`kill_process`], this can't send the signal to a different process
+/// that happens to reuse the PID after the original process exits.
+///
+/// If `info` is `None`, the kernel fills in a `siginfo_t` as `kill` does.
+/// Otherwise, its signal number must be `sig`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
+/// [`kill_process`]: crate::process::kill_process
+#[inline]
+pub fn pidfd_send_signal<Fd: AsFd>(
+    pidfd: Fd,
+    sig: Signal,
+    info: Option<&PidfdSiginfo>,
+    flags: PidfdSignalFlags,
+) -> io::Result<()> {
+    backend::process::syscalls::pidfd_send_signal(pidfd.as_fd(), sig, info, flags)
+}
+
+/// `syscall(SYS_pidfd_getfd, pidfd, targetfd, flags)`—Duplicates a file
+/// descriptor from the process referred to by a pidfd.
+///
+/// `targetfd` is a file descriptor number in the other process. The new file
+/// descriptor has `FD_CLOEXEC` set. This requires permission to
+/// [`ptrace`] the other process.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html
+/// [`ptrace`]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+pub fn pidfd_getfd<Fd: AsFd>(
+    pidfd: Fd,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    backend::process::syscalls::pidfd_getfd(pidfd.as_fd(), targetfd, flags)
+}
@@ -0,0 +1,74 @@
+//! Linux `process_vm_readv` and `process_vm_writev`.
+//!
+//! # Safety
+//!
+//! `process_vm_writev` can write to the memory of the calling process.
+#![allow(unsafe_code)]
+
+use crate::backend;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::process::Pid;
+
+/// `struct iovec` for use with [`process_vm_readv`] and
+/// [`process_vm_writev`]—A range of memory in another process.
+///
+/// Unlike [`IoSlice`] and [`IoSliceMut`], this doesn't refer to memory in the
+/// calling process, so it holds a plain address.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "iovec")]
+pub struct RemoteIoVec {
+    /// The address of the start of the range in the other process.
+    pub base: usize,
+
+    /// The length of the range, in bytes.
+    pub len: usize,
+}
+
+/// `process_vm_readv(pid, local, remote, 0)`—Reads from the memory of
+/// another process into multiple buffers.
+///
+/// This returns the number of bytes read, which may be less than requested.
+/// A transfer stops at the first range in `remote` which can't be read; if
+/// nothing could be read, this fails, typically with [`io::Errno::FAULT`].
+///
+/// The caller must have permission to trace `pid`, as with `ptrace`
+/// `PTRACE_MODE_ATTACH_REALCREDS`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_readv.2.html
+#[inline]
+pub fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    backend::process::syscalls::process_vm_readv(pid, local, remote)
+}
+
+/// `process_vm_writev(pid, local, remote, 0)`—Writes to the memory of
+/// another process from multiple buffers.
+///
+/// This returns the number of bytes written, which may be less than
+/// requested, in the same way as [`process_vm_readv`].
+///
+/// # Safety
+///
+/// If `pid` is the calling process, or another process which shares its
+/// address space, the ranges in `remote` must be valid to write to, and must
+/// not be accessed through Rust references while this call is in progress.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_writev.2.html
+#[inline]
+pub unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    backend::process::syscalls::process_vm_writev(pid, local, remote)
+}
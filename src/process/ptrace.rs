@@ -0,0 +1,805 @@
+//! The Linux `ptrace` system call.
+//!
+//! # Safety
+//!
+//! `ptrace` is passed pointers to kernel-layout structs defined in this
+//! module, and `siginfo_t` unions are read according to the signal number
+//! and code.
+#![allow(unsafe_code)]
+
+use crate::backend::c::{c_uint, c_void};
+use crate::backend::process::syscalls;
+use crate::io;
+use crate::process::{Pid, Signal};
+use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::null_mut;
+use linux_raw_sys::general::siginfo_t;
+
+const PTRACE_TRACEME: c_uint = 0;
+const PTRACE_PEEKDATA: c_uint = 2;
+const PTRACE_POKEDATA: c_uint = 5;
+const PTRACE_CONT: c_uint = 7;
+const PTRACE_ATTACH: c_uint = 16;
+const PTRACE_DETACH: c_uint = 17;
+const PTRACE_SYSCALL: c_uint = 24;
+const PTRACE_SETOPTIONS: c_uint = 0x4200;
+const PTRACE_GETEVENTMSG: c_uint = 0x4201;
+const PTRACE_GETSIGINFO: c_uint = 0x4202;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const PTRACE_GETREGSET: c_uint = 0x4204;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const PTRACE_SETREGSET: c_uint = 0x4205;
+const PTRACE_SEIZE: c_uint = 0x4206;
+const PTRACE_INTERRUPT: c_uint = 0x4207;
+const PTRACE_GET_SYSCALL_INFO: c_uint = 0x420e;
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const NT_PRSTATUS: usize = 1;
+
+const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+const PTRACE_SYSCALL_INFO_SECCOMP: u8 = 3;
+
+bitflags::bitflags! {
+    /// `PTRACE_O_*` flags for use with [`ptrace_setoptions`] and
+    /// [`ptrace_seize`].
+    pub struct PtraceOptions: u32 {
+        /// `PTRACE_O_TRACESYSGOOD`—Report system call stops with
+        /// `SIGTRAP | 0x80`; see [`WaitStatus::syscall_stopped`].
+        ///
+        /// [`WaitStatus::syscall_stopped`]: crate::process::WaitStatus::syscall_stopped
+        const TRACESYSGOOD = 0x0000_0001;
+        /// `PTRACE_O_TRACEFORK`
+        const TRACEFORK = 0x0000_0002;
+        /// `PTRACE_O_TRACEVFORK`
+        const TRACEVFORK = 0x0000_0004;
+        /// `PTRACE_O_TRACECLONE`
+        const TRACECLONE = 0x0000_0008;
+        /// `PTRACE_O_TRACEEXEC`
+        const TRACEEXEC = 0x0000_0010;
+        /// `PTRACE_O_TRACEVFORKDONE`
+        const TRACEVFORKDONE = 0x0000_0020;
+        /// `PTRACE_O_TRACEEXIT`
+        const TRACEEXIT = 0x0000_0040;
+        /// `PTRACE_O_TRACESECCOMP`
+        const TRACESECCOMP = 0x0000_0080;
+        /// `PTRACE_O_EXITKILL`—Kill the tracee if the tracer exits.
+        const EXITKILL = 0x0010_0000;
+        /// `PTRACE_O_SUSPEND_SECCOMP`
+        const SUSPEND_SECCOMP = 0x0020_0000;
+    }
+}
+
+/// `PTRACE_EVENT_*`—The event which caused a ptrace stop, as returned by
+/// [`WaitStatus::ptrace_event`].
+///
+/// [`WaitStatus::ptrace_event`]: crate::process::WaitStatus::ptrace_event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum PtraceEvent {
+    /// `PTRACE_EVENT_FORK`
+    Fork = 1,
+    /// `PTRACE_EVENT_VFORK`
+    Vfork = 2,
+    /// `PTRACE_EVENT_CLONE`
+    Clone = 3,
+    /// `PTRACE_EVENT_EXEC`
+    Exec = 4,
+    /// `PTRACE_EVENT_VFORK_DONE`
+    VforkDone = 5,
+    /// `PTRACE_EVENT_EXIT`
+    Exit = 6,
+    /// `PTRACE_EVENT_SECCOMP`
+    Seccomp = 7,
+    /// `PTRACE_EVENT_STOP`—A group-stop or [`ptrace_interrupt`] stop of a
+    /// tracee attached with [`ptrace_seize`].
+    Stop = 128,
+}
+
+impl PtraceEvent {
+    /// Convert a raw `PTRACE_EVENT_*` value into a `PtraceEvent`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Fork),
+            2 => Some(Self::Vfork),
+            3 => Some(Self::Clone),
+            4 => Some(Self::Exec),
+            5 => Some(Self::VforkDone),
+            6 => Some(Self::Exit),
+            7 => Some(Self::Seccomp),
+            128 => Some(Self::Stop),
+            _ => None,
+        }
+    }
+}
+
+#[inline]
+fn ptrace(request: c_uint, pid: Pid, addr: *mut c_void, data: *mut c_void) -> io::Result<()> {
+    unsafe { syscalls::ptrace(request, Some(pid), addr, data).map(drop) }
+}
+
+#[inline]
+fn signal_data(signal: Option<Signal>) -> *mut c_void {
+    signal.map_or(0, |sig| sig.as_raw() as usize) as *mut c_void
+}
+
+/// `ptrace(PTRACE_TRACEME, 0, 0, 0)`—Make the calling process a tracee of
+/// its parent.
+///
+/// This is typically called in a child process between `fork` and `execve`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_TRACEME")]
+pub fn ptrace_traceme() -> io::Result<()> {
+    unsafe { syscalls::ptrace(PTRACE_TRACEME, None, null_mut(), null_mut()).map(drop) }
+}
+
+/// `ptrace(PTRACE_ATTACH, pid, 0, 0)`—Attach to a process and stop it with
+/// [`Signal::Stop`].
+///
+/// Prefer [`ptrace_seize`], which doesn't send a signal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_ATTACH")]
+pub fn ptrace_attach(pid: Pid) -> io::Result<()> {
+    ptrace(PTRACE_ATTACH, pid, null_mut(), null_mut())
+}
+
+/// `ptrace(PTRACE_SEIZE, pid, 0, options)`—Attach to a process without
+/// stopping it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SEIZE")]
+pub fn ptrace_seize(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    ptrace(
+        PTRACE_SEIZE,
+        pid,
+        null_mut(),
+        options.bits() as usize as *mut c_void,
+    )
+}
+
+/// `ptrace(PTRACE_INTERRUPT, pid, 0, 0)`—Stop a tracee attached with
+/// [`ptrace_seize`].
+///
+/// The tracee reports a [`PtraceEvent::Stop`] stop.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_INTERRUPT")]
+pub fn ptrace_interrupt(pid: Pid) -> io::Result<()> {
+    ptrace(PTRACE_INTERRUPT, pid, null_mut(), null_mut())
+}
+
+/// `ptrace(PTRACE_CONT, pid, 0, signal)`—Resume a stopped tracee,
+/// optionally delivering a signal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_CONT")]
+pub fn ptrace_cont(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    ptrace(PTRACE_CONT, pid, null_mut(), signal_data(signal))
+}
+
+/// `ptrace(PTRACE_SYSCALL, pid, 0, signal)`—Resume a stopped tracee until
+/// the next system call entry or exit, optionally delivering a signal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SYSCALL")]
+pub fn ptrace_syscall(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    ptrace(PTRACE_SYSCALL, pid, null_mut(), signal_data(signal))
+}
+
+/// `ptrace(PTRACE_DETACH, pid, 0, signal)`—Detach from a stopped tracee and
+/// resume it, optionally delivering a signal.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_DETACH")]
+pub fn ptrace_detach(pid: Pid, signal: Option<Signal>) -> io::Result<()> {
+    ptrace(PTRACE_DETACH, pid, null_mut(), signal_data(signal))
+}
+
+/// `ptrace(PTRACE_SETOPTIONS, pid, 0, options)`—Set the ptrace options of a
+/// stopped tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SETOPTIONS")]
+pub fn ptrace_setoptions(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    ptrace(
+        PTRACE_SETOPTIONS,
+        pid,
+        null_mut(),
+        options.bits() as usize as *mut c_void,
+    )
+}
+
+/// `ptrace(PTRACE_GETEVENTMSG, pid, 0, &msg)`—Return the message for the
+/// current [`PtraceEvent`] stop of a tracee.
+///
+/// This is the new process ID for [`PtraceEvent::Fork`],
+/// [`PtraceEvent::Vfork`], and [`PtraceEvent::Clone`], the former thread ID
+/// for [`PtraceEvent::Exec`], the raw wait status for [`PtraceEvent::Exit`],
+/// and the filter's data for [`PtraceEvent::Seccomp`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_GETEVENTMSG")]
+pub fn ptrace_geteventmsg(pid: Pid) -> io::Result<usize> {
+    let mut msg: usize = 0;
+    ptrace(
+        PTRACE_GETEVENTMSG,
+        pid,
+        null_mut(),
+        &mut msg as *mut usize as *mut c_void,
+    )?;
+    Ok(msg)
+}
+
+/// `ptrace(PTRACE_PEEKDATA, pid, addr, &word)`—Read a word from the memory of
+/// a stopped tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_PEEKDATA")]
+pub fn ptrace_peekdata(pid: Pid, addr: usize) -> io::Result<usize> {
+    // The raw system call stores the word at `data`, unlike the libc
+    // wrapper, which returns it.
+    let mut word: usize = 0;
+    ptrace(
+        PTRACE_PEEKDATA,
+        pid,
+        addr as *mut c_void,
+        &mut word as *mut usize as *mut c_void,
+    )?;
+    Ok(word)
+}
+
+/// `ptrace(PTRACE_POKEDATA, pid, addr, word)`—Write a word to the memory of a
+/// stopped tracee.
+///
+/// # Safety
+///
+/// If `pid` shares the address space of the calling process, such as a
+/// tracee created with `CLONE_VM`, the word at `addr` must be valid to write
+/// to, and must not be accessed through Rust references while this call is
+/// in progress.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_POKEDATA")]
+pub unsafe fn ptrace_pokedata(pid: Pid, addr: usize, word: usize) -> io::Result<()> {
+    ptrace(
+        PTRACE_POKEDATA,
+        pid,
+        addr as *mut c_void,
+        word as *mut c_void,
+    )
+}
+
+/// The `siginfo_t` of the signal which caused a tracee's signal-delivery
+/// stop, as returned by [`ptrace_getsiginfo`].
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+#[doc(alias = "siginfo_t")]
+pub struct PtraceSiginfo(siginfo_t);
+
+impl PtraceSiginfo {
+    /// Return the signal, if it's a valid signal number.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.raw_signal())
+    }
+
+    /// Return the raw signal number, `si_signo`.
+    #[inline]
+    pub fn raw_signal(&self) -> i32 {
+        // SAFETY: The common fields are valid for every signal.
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_signo }
+    }
+
+    /// Return the errno value, `si_errno`.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_errno }
+    }
+
+    /// Return the signal code, `si_code`.
+    ///
+    /// This is positive for signals generated by the kernel, such as
+    /// `SIGSEGV` from a fault, and zero or negative for signals sent by a
+    /// process.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_code }
+    }
+
+    /// Return the ID of the sending process, `si_pid`, for signals sent with
+    /// `kill` and similar functions.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        if self.code() > 0 {
+            return None;
+        }
+        // SAFETY: Signals sent by a process have `_kill` fields, and `_pid`
+        // is at the same offset for `_rt` and `_sigchld` fields.
+        unsafe {
+            Pid::from_raw(
+                self.0
+                    .__bindgen_anon_1
+                    .__bindgen_anon_1
+                    ._sifields
+                    ._kill
+                    ._pid as _,
+            )
+        }
+    }
+
+    /// Return the faulting address, `si_addr`, for `SIGSEGV`, `SIGBUS`,
+    /// `SIGILL`, `SIGFPE`, and `SIGTRAP` signals generated by the kernel.
+    #[inline]
+    pub fn addr(&self) -> Option<usize> {
+        let sig = self.raw_signal();
+        if self.code() <= 0
+            || !(sig == Signal::Segv.as_raw()
+                || sig == Signal::Bus.as_raw()
+                || sig == Signal::Ill.as_raw()
+                || sig == Signal::Fpe.as_raw()
+                || sig == Signal::Trap.as_raw())
+        {
+            return None;
+        }
+        // SAFETY: These signals have `_sigfault` fields.
+        unsafe {
+            Some(
+                self.0
+                    .__bindgen_anon_1
+                    .__bindgen_anon_1
+                    ._sifields
+                    ._sigfault
+                    ._addr as usize,
+            )
+        }
+    }
+}
+
+impl fmt::Debug for PtraceSiginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PtraceSiginfo")
+            .field("signo", &self.raw_signal())
+            .field("errno", &self.errno())
+            .field("code", &self.code())
+            .finish()
+    }
+}
+
+/// `ptrace(PTRACE_GETSIGINFO, pid, 0, &siginfo)`—Return information about
+/// the signal which caused a tracee's signal-delivery stop.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_GETSIGINFO")]
+pub fn ptrace_getsiginfo(pid: Pid) -> io::Result<PtraceSiginfo> {
+    let mut siginfo = MaybeUninit::<siginfo_t>::zeroed();
+    ptrace(
+        PTRACE_GETSIGINFO,
+        pid,
+        null_mut(),
+        siginfo.as_mut_ptr().cast(),
+    )?;
+    // SAFETY: `siginfo_t` is plain data, and was zero-initialized.
+    Ok(PtraceSiginfo(unsafe { siginfo.assume_init() }))
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, for
+/// use with [`ptrace_getregset`] and [`ptrace_setregset`].
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct PtraceRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, for
+/// use with [`ptrace_getregset`] and [`ptrace_setregset`].
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct PtraceRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// `struct user_pt_regs`—The general-purpose registers of a tracee, for use
+/// with [`ptrace_getregset`] and [`ptrace_setregset`].
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "user_pt_regs")]
+pub struct PtraceRegs {
+    /// `x0` through `x30`.
+    pub regs: [u64; 31],
+    /// The stack pointer.
+    pub sp: u64,
+    /// The program counter.
+    pub pc: u64,
+    /// The processor state.
+    pub pstate: u64,
+}
+
+/// `struct user_regs`—The general-purpose registers of a tracee, for use
+/// with [`ptrace_getregset`] and [`ptrace_setregset`].
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "user_regs")]
+pub struct PtraceRegs {
+    /// `r0` through `r15`, `cpsr`, and `orig_r0`.
+    pub uregs: [u32; 18],
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee, for
+/// use with [`ptrace_getregset`] and [`ptrace_setregset`].
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct PtraceRegs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+/// `struct iovec`
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[repr(C)]
+struct Iovec {
+    base: *mut c_void,
+    len: usize,
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, NT_PRSTATUS, &iov)`—Return the
+/// general-purpose registers of a stopped tracee.
+///
+/// If the tracee's registers have a different layout, such as for a 32-bit
+/// tracee of a 64-bit tracer, this fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[inline]
+#[doc(alias = "PTRACE_GETREGSET")]
+#[doc(alias = "PTRACE_GETREGS")]
+pub fn ptrace_getregset(pid: Pid) -> io::Result<PtraceRegs> {
+    let mut regs = PtraceRegs::default();
+    let mut iov = Iovec {
+        base: (&mut regs as *mut PtraceRegs).cast(),
+        len: size_of::<PtraceRegs>(),
+    };
+    ptrace(
+        PTRACE_GETREGSET,
+        pid,
+        NT_PRSTATUS as *mut c_void,
+        (&mut iov as *mut Iovec).cast(),
+    )?;
+
+    // The kernel sets `len` to the size of the registers it wrote, which is
+    // smaller for a 32-bit tracee.
+    if iov.len != size_of::<PtraceRegs>() {
+        return Err(io::Errno::INVAL);
+    }
+    Ok(regs)
+}
+
+/// `ptrace(PTRACE_SETREGSET, pid, NT_PRSTATUS, &iov)`—Set the
+/// general-purpose registers of a stopped tracee.
+///
+/// # Safety
+///
+/// If `pid` shares the address space of the calling process, such as a
+/// tracee created with `CLONE_VM`, the tracee must not use `regs` to access
+/// memory of the calling process in a way that violates Rust's aliasing
+/// rules, or to run code which does.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[inline]
+#[doc(alias = "PTRACE_SETREGSET")]
+#[doc(alias = "PTRACE_SETREGS")]
+pub unsafe fn ptrace_setregset(pid: Pid, regs: &PtraceRegs) -> io::Result<()> {
+    // The kernel doesn't write to `regs`.
+    let mut iov = Iovec {
+        base: (regs as *const PtraceRegs as *mut PtraceRegs).cast(),
+        len: size_of::<PtraceRegs>(),
+    };
+    ptrace(
+        PTRACE_SETREGSET,
+        pid,
+        NT_PRSTATUS as *mut c_void,
+        (&mut iov as *mut Iovec).cast(),
+    )
+}
+
+/// `struct ptrace_syscall_info`
+#[repr(C)]
+struct RawPtraceSyscallInfo {
+    op: u8,
+    pad: [u8; 3],
+    arch: u32,
+    instruction_pointer: u64,
+    stack_pointer: u64,
+    // The `entry`, `exit`, and `seccomp` union. `seccomp` is the largest,
+    // with `nr`, `args`, and a `u32` `ret_data`, padded to 8 bytes.
+    data: [u64; 8],
+}
+
+/// Information about the system call which caused a tracee's stop, as
+/// returned by [`ptrace_get_syscall_info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "ptrace_syscall_info")]
+pub struct PtraceSyscallInfo {
+    /// The `AUDIT_ARCH_*` value for the system call convention used.
+    pub arch: u32,
+
+    /// The address of the instruction that made the system call.
+    pub instruction_pointer: u64,
+
+    /// The tracee's stack pointer.
+    pub stack_pointer: u64,
+
+    /// The kind of stop, and its details.
+    pub op: PtraceSyscallInfoOp,
+}
+
+/// `PTRACE_SYSCALL_INFO_*`—The kind of a [`PtraceSyscallInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PtraceSyscallInfoOp {
+    /// `PTRACE_SYSCALL_INFO_NONE`—The stop isn't a system call stop.
+    None,
+
+    /// `PTRACE_SYSCALL_INFO_ENTRY`—A system call entry stop.
+    Entry {
+        /// The system call number.
+        nr: u64,
+        /// The system call arguments.
+        args: [u64; 6],
+    },
+
+    /// `PTRACE_SYSCALL_INFO_EXIT`—A system call exit stop.
+    Exit {
+        /// The return value, or the negated errno value if `is_error` is
+        /// true.
+        rval: i64,
+        /// Whether the system call failed.
+        is_error: bool,
+    },
+
+    /// `PTRACE_SYSCALL_INFO_SECCOMP`—A [`PtraceEvent::Seccomp`] stop.
+    Seccomp {
+        /// The system call number.
+        nr: u64,
+        /// The system call arguments.
+        args: [u64; 6],
+        /// The data returned by the seccomp filter with
+        /// `SECCOMP_RET_TRACE`.
+        ret_data: u32,
+    },
+}
+
+/// `ptrace(PTRACE_GET_SYSCALL_INFO, pid, sizeof(info), &info)`—Return
+/// information about the system call which caused a tracee's stop.
+///
+/// This requires Linux 5.3 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_GET_SYSCALL_INFO")]
+pub fn ptrace_get_syscall_info(pid: Pid) -> io::Result<PtraceSyscallInfo> {
+    let mut raw = RawPtraceSyscallInfo {
+        op: 0,
+        pad: [0; 3],
+        arch: 0,
+        instruction_pointer: 0,
+        stack_pointer: 0,
+        data: [0; 8],
+    };
+    // This returns the number of bytes the kernel has available, which may
+    // be more or less than the size of our buffer.
+    unsafe {
+        syscalls::ptrace(
+            PTRACE_GET_SYSCALL_INFO,
+            Some(pid),
+            size_of::<RawPtraceSyscallInfo>() as *mut c_void,
+            (&mut raw as *mut RawPtraceSyscallInfo).cast(),
+        )?;
+    }
+
+    let d = raw.data;
+    let args = [d[1], d[2], d[3], d[4], d[5], d[6]];
+    let op = match raw.op {
+        PTRACE_SYSCALL_INFO_ENTRY => PtraceSyscallInfoOp::Entry { nr: d[0], args },
+        PTRACE_SYSCALL_INFO_EXIT => PtraceSyscallInfoOp::Exit {
+            rval: d[0] as i64,
+            // `is_error` is the first byte after `rval`.
+            is_error: d[1].to_ne_bytes()[0] != 0,
+        },
+        PTRACE_SYSCALL_INFO_SECCOMP => PtraceSyscallInfoOp::Seccomp {
+            nr: d[0],
+            args,
+            // `ret_data` is the first four bytes after `args`.
+            ret_data: {
+                let bytes = d[7].to_ne_bytes();
+                u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            },
+        },
+        _ => PtraceSyscallInfoOp::None,
+    };
+    Ok(PtraceSyscallInfo {
+        arch: raw.arch,
+        instruction_pointer: raw.instruction_pointer,
+        stack_pointer: raw.stack_pointer,
+        op,
+    })
+}
@@ -0,0 +1,586 @@
+//! Linux `seccomp` filters and user-space notification.
+//!
+//! A filter is a classic BPF program, installed with
+//! [`seccomp_set_mode_filter`], which is run on every system call the thread
+//! makes and returns a [`SeccompAction`]. If a filter is installed with
+//! [`SeccompFilterFlags::NEW_LISTENER`], system calls for which it returns
+//! [`SeccompAction::USER_NOTIF`] are forwarded to a supervisor, which
+//! receives them on the returned listener file descriptor with
+//! [`seccomp_notif_recv`] and answers with [`seccomp_notif_send`].
+
+use crate::backend::c::{c_uint, c_void};
+use crate::backend::process::syscalls;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::io;
+use crate::process::Pid;
+use core::convert::TryFrom;
+use core::mem::size_of;
+
+const SECCOMP_SET_MODE_FILTER: c_uint = 1;
+const SECCOMP_GET_ACTION_AVAIL: c_uint = 2;
+
+const SECCOMP_RET_ACTION_FULL: u32 = 0xffff_0000;
+const SECCOMP_RET_DATA: u32 = 0x0000_ffff;
+
+const SECCOMP_USER_NOTIF_FLAG_CONTINUE: u32 = 1;
+
+const SECCOMP_ADDFD_FLAG_SETFD: u32 = 1;
+const SECCOMP_ADDFD_FLAG_SEND: u32 = 2;
+
+// The notification `ioctl` numbers are `_IOWR('!', nr, type)` and friends,
+// whose encoding differs between architectures.
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+))]
+const IOC_WRITE: c_uint = 4;
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+))]
+const IOC_DIRSHIFT: c_uint = 29;
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+const IOC_WRITE: c_uint = 1;
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64",
+)))]
+const IOC_DIRSHIFT: c_uint = 30;
+const IOC_READ: c_uint = 2;
+
+const fn seccomp_ioc(dir: c_uint, nr: c_uint, size: usize) -> c_uint {
+    (dir << IOC_DIRSHIFT) | ((size as c_uint) << 16) | ((b'!' as c_uint) << 8) | nr
+}
+
+const SECCOMP_IOCTL_NOTIF_RECV: c_uint =
+    seccomp_ioc(IOC_READ | IOC_WRITE, 0, size_of::<RawSeccompNotif>());
+const SECCOMP_IOCTL_NOTIF_SEND: c_uint =
+    seccomp_ioc(IOC_READ | IOC_WRITE, 1, size_of::<SeccompNotifResp>());
+// Linux 5.0 through 5.6 defined this with `_IOR`, and later versions accept
+// both, so use the original encoding.
+const SECCOMP_IOCTL_NOTIF_ID_VALID: c_uint = seccomp_ioc(IOC_READ, 2, size_of::<u64>());
+const SECCOMP_IOCTL_NOTIF_ADDFD: c_uint =
+    seccomp_ioc(IOC_WRITE, 3, size_of::<RawSeccompNotifAddfd>());
+
+bitflags::bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` flags for use with [`seccomp_set_mode_filter`].
+    pub struct SeccompFilterFlags: u32 {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`—Install the filter on all threads of
+        /// the process.
+        const TSYNC = 0x01;
+        /// `SECCOMP_FILTER_FLAG_LOG`—Log all actions except
+        /// [`SeccompAction::ALLOW`] (since Linux 4.14).
+        const LOG = 0x02;
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW`—Don't enable speculative
+        /// execution mitigations (since Linux 4.17).
+        const SPEC_ALLOW = 0x04;
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER`—Return a listener file
+        /// descriptor for user-space notification (since Linux 5.0).
+        const NEW_LISTENER = 0x08;
+        /// `SECCOMP_FILTER_FLAG_TSYNC_ESRCH`—With `TSYNC`, fail with
+        /// [`io::Errno::SRCH`] rather than returning a thread ID if a thread
+        /// can't be synchronized (since Linux 5.7).
+        const TSYNC_ESRCH = 0x10;
+        /// `SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV`—Once a notification has
+        /// been received, only wake the notifying thread for fatal signals
+        /// (since Linux 5.19).
+        const WAIT_KILLABLE_RECV = 0x20;
+    }
+}
+
+/// `struct sock_filter`—A classic BPF instruction.
+///
+/// A seccomp filter operates on a [`SeccompData`], which is loaded with
+/// absolute (`BPF_ABS`) loads at the offsets of its fields, and returns the
+/// raw value of a [`SeccompAction`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "sock_filter")]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+    /// The jump offset if a conditional jump's condition is true.
+    pub jt: u8,
+    /// The jump offset if a conditional jump's condition is false.
+    pub jf: u8,
+    /// The generic operand.
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// `BPF_STMT(code, k)`—Construct a non-jump instruction.
+    #[inline]
+    #[doc(alias = "BPF_STMT")]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// `BPF_JUMP(code, k, jt, jf)`—Construct a jump instruction.
+    #[inline]
+    #[doc(alias = "BPF_JUMP")]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+/// `struct sock_fprog`
+#[repr(C)]
+struct SockFprog {
+    len: u16,
+    filter: *const SockFilter,
+}
+
+/// `struct seccomp_data`—The system call a filter is run on.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "seccomp_data")]
+pub struct SeccompData {
+    /// The system call number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value for the system call convention used.
+    pub arch: u32,
+    /// The address of the instruction that made the system call.
+    pub instruction_pointer: u64,
+    /// The system call arguments.
+    pub args: [u64; 6],
+}
+
+/// `SECCOMP_RET_*`—The action returned by a seccomp filter.
+///
+/// Filters return the value of [`SeccompAction::as_raw`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeccompAction(u32);
+
+impl SeccompAction {
+    /// `SECCOMP_RET_KILL_PROCESS`—Kill the process (since Linux 4.14).
+    pub const KILL_PROCESS: Self = Self(0x8000_0000);
+    /// `SECCOMP_RET_KILL_THREAD`—Kill the thread.
+    pub const KILL_THREAD: Self = Self(0x0000_0000);
+    /// `SECCOMP_RET_TRAP`—Send a `SIGSYS` signal to the thread.
+    pub const TRAP: Self = Self(0x0003_0000);
+    /// `SECCOMP_RET_ERRNO`—Fail the system call; see [`Self::errno`].
+    pub const ERRNO: Self = Self(0x0005_0000);
+    /// `SECCOMP_RET_USER_NOTIF`—Notify the listener (since Linux 5.0).
+    pub const USER_NOTIF: Self = Self(0x7fc0_0000);
+    /// `SECCOMP_RET_TRACE`—Notify a ptrace tracer; see [`Self::trace`].
+    pub const TRACE: Self = Self(0x7ff0_0000);
+    /// `SECCOMP_RET_LOG`—Log and allow the system call (since Linux 4.14).
+    pub const LOG: Self = Self(0x7ffc_0000);
+    /// `SECCOMP_RET_ALLOW`—Allow the system call.
+    pub const ALLOW: Self = Self(0x7fff_0000);
+
+    /// `SECCOMP_RET_ERRNO | errno`—Fail the system call with `errno`.
+    #[inline]
+    pub fn errno(errno: io::Errno) -> Self {
+        Self(Self::ERRNO.0 | (errno.raw_os_error() as u32 & SECCOMP_RET_DATA))
+    }
+
+    /// `SECCOMP_RET_TRACE | data`—Notify a ptrace tracer, which can read
+    /// `data` with `PTRACE_GETEVENTMSG`.
+    #[inline]
+    pub const fn trace(data: u16) -> Self {
+        Self(Self::TRACE.0 | data as u32)
+    }
+
+    /// Convert a raw `SECCOMP_RET_*` value, including any data, into a
+    /// `SeccompAction`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Convert this `SeccompAction` into a raw `SECCOMP_RET_*` value,
+    /// including any data.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, prog)`—Install a seccomp filter
+/// on the calling thread.
+///
+/// Unless the caller has `CAP_SYS_ADMIN` in its user namespace, the
+/// `no_new_privs` attribute must be set first, with
+/// `rustix::thread::set_no_new_privs`.
+///
+/// If `flags` contains [`SeccompFilterFlags::NEW_LISTENER`], this returns the
+/// listener file descriptor. If `flags` contains
+/// [`SeccompFilterFlags::TSYNC`] and another thread can't be synchronized,
+/// this fails with [`io::Errno::SRCH`], as if
+/// [`SeccompFilterFlags::TSYNC_ESRCH`] had been passed.
+///
+/// # Safety
+///
+/// A filter can make system calls return results they didn't produce, with
+/// [`SeccompAction::ERRNO`], or with [`SeccompAction::USER_NOTIF`] and a
+/// supervisor's response. The filter must not fake the results of system
+/// calls the process relies on, such as returning a file descriptor number
+/// which is owned elsewhere, or a byte count larger than what was read.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+#[doc(alias = "SECCOMP_SET_MODE_FILTER")]
+#[allow(unsafe_code)]
+pub unsafe fn seccomp_set_mode_filter(
+    filter: &[SockFilter],
+    flags: SeccompFilterFlags,
+) -> io::Result<Option<OwnedFd>> {
+    let len = u16::try_from(filter.len()).map_err(|_| io::Errno::INVAL)?;
+    let prog = SockFprog {
+        len,
+        filter: filter.as_ptr(),
+    };
+
+    unsafe {
+        let r = syscalls::seccomp(
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            &prog as *const SockFprog as *mut c_void,
+        )?;
+        if flags.contains(SeccompFilterFlags::NEW_LISTENER) {
+            Ok(Some(OwnedFd::from_raw_fd(r)))
+        } else if r != 0 {
+            // With `TSYNC`, a positive value is the ID of a thread which
+            // couldn't be synchronized.
+            Err(io::Errno::SRCH)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// `seccomp(SECCOMP_GET_ACTION_AVAIL, 0, &action)`—Test whether the kernel
+/// supports a [`SeccompAction`].
+///
+/// Any data in `action`, such as the errno of [`SeccompAction::errno`], is
+/// ignored.
+///
+/// This requires Linux 4.14 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+#[doc(alias = "SECCOMP_GET_ACTION_AVAIL")]
+#[allow(unsafe_code)]
+pub fn seccomp_get_action_avail(action: SeccompAction) -> io::Result<bool> {
+    let mut raw = action.0 & SECCOMP_RET_ACTION_FULL;
+    match unsafe {
+        syscalls::seccomp(
+            SECCOMP_GET_ACTION_AVAIL,
+            0,
+            &mut raw as *mut u32 as *mut c_void,
+        )
+    } {
+        Ok(_) => Ok(true),
+        Err(io::Errno::OPNOTSUPP) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `struct seccomp_notif`
+#[repr(C)]
+struct RawSeccompNotif {
+    id: u64,
+    pid: u32,
+    flags: u32,
+    data: SeccompData,
+}
+
+/// A system call for which a filter returned [`SeccompAction::USER_NOTIF`],
+/// for use with [`seccomp_notif_recv`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "seccomp_notif")]
+pub struct SeccompNotif {
+    /// The cookie identifying this notification, for use with
+    /// [`SeccompNotifResp`], [`seccomp_notif_id_valid`], and
+    /// [`SeccompNotifAddfd`].
+    pub id: u64,
+
+    /// The ID of the thread which made the system call, or `None` if it isn't
+    /// visible in the listener's PID namespace.
+    pub pid: Option<Pid>,
+
+    /// The system call.
+    pub data: SeccompData,
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_RECV)`—Receive a notification.
+///
+/// This blocks until a notification is available, unless `listener` is in
+/// non-blocking mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_RECV")]
+#[allow(unsafe_code)]
+pub fn seccomp_notif_recv<Fd: AsFd>(listener: Fd) -> io::Result<SeccompNotif> {
+    // The kernel requires the struct to be zeroed.
+    let mut raw = RawSeccompNotif {
+        id: 0,
+        pid: 0,
+        flags: 0,
+        data: SeccompData {
+            nr: 0,
+            arch: 0,
+            instruction_pointer: 0,
+            args: [0; 6],
+        },
+    };
+    unsafe {
+        syscalls::ioctl_seccomp(
+            listener.as_fd(),
+            SECCOMP_IOCTL_NOTIF_RECV,
+            &mut raw as *mut RawSeccompNotif as *mut c_void,
+        )?;
+        Ok(SeccompNotif {
+            id: raw.id,
+            pid: Pid::from_raw(raw.pid as _),
+            data: raw.data,
+        })
+    }
+}
+
+/// `struct seccomp_notif_resp`—A response to a [`SeccompNotif`], for use
+/// with [`seccomp_notif_send`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "seccomp_notif_resp")]
+pub struct SeccompNotifResp {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+impl SeccompNotifResp {
+    /// Make the system call of notification `id` succeed, returning `val`.
+    #[inline]
+    pub const fn success(id: u64, val: i64) -> Self {
+        Self {
+            id,
+            val,
+            error: 0,
+            flags: 0,
+        }
+    }
+
+    /// Make the system call of notification `id` fail with `errno`.
+    #[inline]
+    pub fn error(id: u64, errno: io::Errno) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: -errno.raw_os_error(),
+            flags: 0,
+        }
+    }
+
+    /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE`—Let the kernel execute the system
+    /// call of notification `id` (since Linux 5.5).
+    ///
+    /// The arguments may have been changed by another thread of the target
+    /// since the notification was sent, so this must not be used to
+    /// implement a security policy based on pointer arguments.
+    #[inline]
+    pub const fn continue_syscall(id: u64) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: 0,
+            flags: SECCOMP_USER_NOTIF_FLAG_CONTINUE,
+        }
+    }
+
+    /// Return the ID of the notification this responds to.
+    #[inline]
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_SEND, resp)`—Respond to a
+/// notification.
+///
+/// This fails with [`io::Errno::NOENT`] if the target thread was interrupted
+/// or killed since the notification was received.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_SEND")]
+#[allow(unsafe_code)]
+pub fn seccomp_notif_send<Fd: AsFd>(listener: Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    // The kernel doesn't write to `resp`, despite the `_IOWR` encoding.
+    unsafe {
+        syscalls::ioctl_seccomp(
+            listener.as_fd(),
+            SECCOMP_IOCTL_NOTIF_SEND,
+            resp as *const SeccompNotifResp as *mut c_void,
+        )
+        .map(|_r| ())
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Test whether a
+/// notification is still pending.
+///
+/// A supervisor should check this after reading the target's memory, such
+/// as through `/proc/<pid>/mem`, to make sure that the target thread hasn't
+/// exited and had its PID reused in the meantime.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ID_VALID")]
+#[allow(unsafe_code)]
+pub fn seccomp_notif_id_valid<Fd: AsFd>(listener: Fd, id: u64) -> io::Result<bool> {
+    match unsafe {
+        syscalls::ioctl_seccomp(
+            listener.as_fd(),
+            SECCOMP_IOCTL_NOTIF_ID_VALID,
+            &id as *const u64 as *mut c_void,
+        )
+    } {
+        Ok(_) => Ok(true),
+        Err(io::Errno::NOENT) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `struct seccomp_notif_addfd`
+#[repr(C)]
+struct RawSeccompNotifAddfd {
+    id: u64,
+    flags: u32,
+    srcfd: u32,
+    newfd: u32,
+    newfd_flags: u32,
+}
+
+/// Arguments for [`seccomp_notif_addfd`].
+#[derive(Debug, Clone, Copy)]
+#[doc(alias = "seccomp_notif_addfd")]
+pub struct SeccompNotifAddfd<'a> {
+    id: u64,
+    fd: BorrowedFd<'a>,
+    flags: u32,
+    newfd: u32,
+    cloexec: bool,
+}
+
+impl<'a> SeccompNotifAddfd<'a> {
+    /// Construct a new `SeccompNotifAddfd` to install a duplicate of `fd`
+    /// in the target of notification `id`, at the lowest available file
+    /// descriptor number.
+    #[inline]
+    pub const fn new(id: u64, fd: BorrowedFd<'a>) -> Self {
+        Self {
+            id,
+            fd,
+            flags: 0,
+            newfd: 0,
+            cloexec: false,
+        }
+    }
+
+    /// `SECCOMP_ADDFD_FLAG_SETFD`—Install the file descriptor at
+    /// `target_fd`, replacing any file descriptor already open there.
+    #[inline]
+    pub fn target_fd(mut self, target_fd: RawFd) -> Self {
+        self.flags |= SECCOMP_ADDFD_FLAG_SETFD;
+        self.newfd = target_fd as u32;
+        self
+    }
+
+    /// `SECCOMP_ADDFD_FLAG_SEND`—Atomically install the file descriptor and
+    /// respond to the notification, making the target's system call return
+    /// the new file descriptor number (since Linux 5.14).
+    #[inline]
+    pub fn send(mut self) -> Self {
+        self.flags |= SECCOMP_ADDFD_FLAG_SEND;
+        self
+    }
+
+    /// Set the close-on-exec flag on the new file descriptor.
+    #[inline]
+    pub fn cloexec(mut self) -> Self {
+        self.cloexec = true;
+        self
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_ADDFD, addfd)`—Install a file
+/// descriptor in the target of a notification.
+///
+/// On success, this returns the file descriptor number in the target
+/// process, which is not a valid file descriptor in the calling process.
+///
+/// This requires Linux 5.9 or later.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ADDFD")]
+#[allow(unsafe_code)]
+pub fn seccomp_notif_addfd<Fd: AsFd>(
+    listener: Fd,
+    addfd: &SeccompNotifAddfd<'_>,
+) -> io::Result<RawFd> {
+    let raw = RawSeccompNotifAddfd {
+        id: addfd.id,
+        flags: addfd.flags,
+        srcfd: addfd.fd.as_raw_fd() as u32,
+        newfd: addfd.newfd,
+        newfd_flags: if addfd.cloexec {
+            linux_raw_sys::general::O_CLOEXEC
+        } else {
+            0
+        },
+    };
+    unsafe {
+        syscalls::ioctl_seccomp(
+            listener.as_fd(),
+            SECCOMP_IOCTL_NOTIF_ADDFD,
+            &raw as *const RawSeccompNotifAddfd as *mut c_void,
+        )
+    }
+}
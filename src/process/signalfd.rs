@@ -0,0 +1,355 @@
+//! `signalfd` and its signal mask and record types.
+//!
+//! # Safety
+//!
+//! `signalfd_read` reads raw `signalfd_siginfo` records into a typed buffer.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, OwnedFd};
+use crate::process::{Pid, Signal};
+use crate::{backend, io};
+use core::fmt;
+use core::mem::size_of;
+use linux_raw_sys::general::kernel_sigset_t;
+
+pub use backend::process::types::SignalfdFlags;
+
+/// The number of bits in each word of a [`SigSet`].
+const WORD_BITS: usize = 8 * size_of::<linux_raw_sys::ctypes::c_ulong>();
+
+/// A set of signals, in the kernel's `sigset_t` layout.
+///
+/// This is the mask type used by [`signalfd`]. Unlike libc's `sigset_t`, it
+/// is exactly the size the kernel expects.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct SigSet(kernel_sigset_t);
+
+impl SigSet {
+    /// Return a set with no signals in it.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(kernel_sigset_t {
+            sig: [0; linux_raw_sys::general::_NSIG as usize / WORD_BITS],
+        })
+    }
+
+    /// Return a set with every signal in it.
+    #[inline]
+    pub const fn full() -> Self {
+        Self(kernel_sigset_t {
+            sig: [!0; linux_raw_sys::general::_NSIG as usize / WORD_BITS],
+        })
+    }
+
+    /// Add `sig` to the set.
+    #[inline]
+    pub fn insert(&mut self, sig: Signal) {
+        let (word, bit) = Self::position(sig);
+        self.0.sig[word] |= bit;
+    }
+
+    /// Remove `sig` from the set.
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) {
+        let (word, bit) = Self::position(sig);
+        self.0.sig[word] &= !bit;
+    }
+
+    /// Return `true` if `sig` is in the set.
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        let (word, bit) = Self::position(sig);
+        self.0.sig[word] & bit != 0
+    }
+
+    /// Return `true` if the set has no signals in it.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.sig.iter().all(|word| *word == 0)
+    }
+
+    /// Return the word index and bit mask for `sig`.
+    #[inline]
+    fn position(sig: Signal) -> (usize, linux_raw_sys::ctypes::c_ulong) {
+        let index = sig.as_raw() as usize - 1;
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+}
+
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl PartialEq for SigSet {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.sig == other.0.sig
+    }
+}
+
+impl Eq for SigSet {}
+
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                (1..linux_raw_sys::general::_NSIG as i32)
+                    .filter_map(Signal::from_raw)
+                    .filter(|sig| self.contains(*sig)),
+            )
+            .finish()
+    }
+}
+
+impl core::iter::FromIterator<Signal> for SigSet {
+    fn from_iter<I: IntoIterator<Item = Signal>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Signal> for SigSet {
+    fn extend<I: IntoIterator<Item = Signal>>(&mut self, iter: I) {
+        for sig in iter {
+            self.insert(sig);
+        }
+    }
+}
+
+/// A `signalfd_siginfo`, describing a signal read from a [`signalfd`].
+///
+/// Fields that don't apply to a particular signal are zero.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SignalfdSiginfo {
+    ssi_signo: u32,
+    ssi_errno: i32,
+    ssi_code: i32,
+    ssi_pid: u32,
+    ssi_uid: u32,
+    ssi_fd: i32,
+    ssi_tid: u32,
+    ssi_band: u32,
+    ssi_overrun: u32,
+    ssi_trapno: u32,
+    ssi_status: i32,
+    ssi_int: i32,
+    ssi_ptr: u64,
+    ssi_utime: u64,
+    ssi_stime: u64,
+    ssi_addr: u64,
+    ssi_addr_lsb: u16,
+    __pad2: u16,
+    ssi_syscall: i32,
+    ssi_call_addr: u64,
+    ssi_arch: u32,
+    __pad: [u8; 28],
+}
+
+impl SignalfdSiginfo {
+    /// Return the signal, if rustix knows about it.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.ssi_signo as i32)
+    }
+
+    /// Return the raw signal number.
+    #[inline]
+    pub fn raw_signal(&self) -> i32 {
+        self.ssi_signo as i32
+    }
+
+    /// Return the `si_code` value, describing why the signal was sent.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.ssi_code
+    }
+
+    /// Return the `si_errno` value.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        self.ssi_errno
+    }
+
+    /// Return the ID of the process which sent the signal, if any.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        // SAFETY: The kernel reports a process ID here.
+        unsafe { Pid::from_raw(self.ssi_pid as _) }
+    }
+
+    /// Return the real user ID of the process which sent the signal.
+    #[inline]
+    pub fn uid(&self) -> crate::process::Uid {
+        // SAFETY: The kernel reports a user ID here.
+        unsafe { crate::process::Uid::from_raw(self.ssi_uid as _) }
+    }
+
+    /// Return the file descriptor, for `SIGIO`.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.ssi_fd
+    }
+
+    /// Return the kernel timer ID, for POSIX timer signals.
+    #[inline]
+    pub fn tid(&self) -> u32 {
+        self.ssi_tid
+    }
+
+    /// Return the band event, for `SIGIO`.
+    #[inline]
+    pub fn band(&self) -> u32 {
+        self.ssi_band
+    }
+
+    /// Return the overrun count, for POSIX timer signals.
+    #[inline]
+    pub fn overrun(&self) -> u32 {
+        self.ssi_overrun
+    }
+
+    /// Return the exit status or signal, for `SIGCHLD`.
+    #[inline]
+    pub fn status(&self) -> i32 {
+        self.ssi_status
+    }
+
+    /// Return the integer value sent with `sigqueue`.
+    #[inline]
+    pub fn int(&self) -> i32 {
+        self.ssi_int
+    }
+
+    /// Return the pointer value sent with `sigqueue`.
+    #[inline]
+    pub fn ptr(&self) -> u64 {
+        self.ssi_ptr
+    }
+
+    /// Return the user CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn utime(&self) -> u64 {
+        self.ssi_utime
+    }
+
+    /// Return the system CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn stime(&self) -> u64 {
+        self.ssi_stime
+    }
+
+    /// Return the address that generated the signal, for hardware-generated
+    /// signals.
+    #[inline]
+    pub fn addr(&self) -> u64 {
+        self.ssi_addr
+    }
+}
+
+impl Default for SignalfdSiginfo {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            ssi_signo: 0,
+            ssi_errno: 0,
+            ssi_code: 0,
+            ssi_pid: 0,
+            ssi_uid: 0,
+            ssi_fd: 0,
+            ssi_tid: 0,
+            ssi_band: 0,
+            ssi_overrun: 0,
+            ssi_trapno: 0,
+            ssi_status: 0,
+            ssi_int: 0,
+            ssi_ptr: 0,
+            ssi_utime: 0,
+            ssi_stime: 0,
+            ssi_addr: 0,
+            ssi_addr_lsb: 0,
+            __pad2: 0,
+            ssi_syscall: 0,
+            ssi_call_addr: 0,
+            ssi_arch: 0,
+            __pad: [0; 28],
+        }
+    }
+}
+
+impl fmt::Debug for SignalfdSiginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalfdSiginfo")
+            .field("signo", &self.ssi_signo)
+            .field("errno", &self.ssi_errno)
+            .field("code", &self.ssi_code)
+            .field("pid", &self.ssi_pid)
+            .field("uid", &self.ssi_uid)
+            .field("status", &self.ssi_status)
+            .finish()
+    }
+}
+
+/// `signalfd4(-1, mask, flags)`—Creates a file descriptor for receiving
+/// signals.
+///
+/// The signals in `mask` should be blocked, with `pthread_sigmask` or
+/// similar, so that they're delivered to the file descriptor instead of
+/// through their default dispositions. Read them with [`signalfd_read`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+#[doc(alias = "signalfd4")]
+pub fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    backend::process::syscalls::signalfd(mask, flags)
+}
+
+/// `signalfd4(fd, mask, flags)`—Replaces the set of signals an existing
+/// signalfd file descriptor receives.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+#[doc(alias = "signalfd4")]
+pub fn signalfd_set_mask<Fd: AsFd>(fd: Fd, mask: &SigSet, flags: SignalfdFlags) -> io::Result<()> {
+    backend::process::syscalls::signalfd_set_mask(fd.as_fd(), mask, flags)
+}
+
+/// `read(fd, buf)`—Reads pending signals from a signalfd file descriptor.
+///
+/// This fills as many entries of `buf` as there are pending signals, and
+/// returns the number filled. If no signals are pending, this blocks, or
+/// fails with [`io::Errno::AGAIN`] if the file descriptor is non-blocking.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+pub fn signalfd_read<Fd: AsFd>(fd: Fd, buf: &mut [SignalfdSiginfo]) -> io::Result<usize> {
+    // SAFETY: `SignalfdSiginfo` is a plain `repr(C)` struct of integers, so
+    // any bytes the kernel writes form valid values.
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<u8>(), core::mem::size_of_val(buf))
+    };
+    let n = io::read(fd, bytes)?;
+    Ok(n / size_of::<SignalfdSiginfo>())
+}
+
+#[test]
+fn signalfd_layouts() {
+    assert_eq!(size_of::<SignalfdSiginfo>(), 128);
+    assert_eq!(
+        size_of::<SigSet>(),
+        linux_raw_sys::general::_NSIG as usize / 8
+    );
+}
@@ -1,9 +1,11 @@
-use crate::process::Pid;
+use crate::process::{Pid, Signal};
 use crate::{backend, io};
 use bitflags::bitflags;
 
 #[cfg(target_os = "linux")]
 use crate::fd::BorrowedFd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::PtraceEvent;
 
 #[cfg(linux_raw)]
 use crate::backend::process::wait::SiginfoExt;
@@ -83,10 +85,21 @@ impl WaitStatus {
         backend::process::wait::WIFCONTINUED(self.0 as _)
     }
 
+    /// Returns the signal that stopped the process,
+    /// if the process was stopped by a signal.
+    ///
+    /// This returns `None` if the stop status isn't a valid signal, such as
+    /// the `SIGTRAP | 0x80` of a system call stop; use
+    /// [`Self::stopping_signal_raw`] to get the number in that case.
+    #[inline]
+    pub fn stopping_signal(self) -> Option<Signal> {
+        Signal::from_raw(self.stopping_signal_raw()? as _)
+    }
+
     /// Returns the number of the signal that stopped the process,
     /// if the process was stopped by a signal.
     #[inline]
-    pub fn stopping_signal(self) -> Option<u32> {
+    pub fn stopping_signal_raw(self) -> Option<u32> {
         if self.stopped() {
             Some(backend::process::wait::WSTOPSIG(self.0 as _) as _)
         } else {
@@ -105,16 +118,49 @@ impl WaitStatus {
         }
     }
 
+    /// Returns the signal that terminated the process,
+    /// if the process was terminated by a signal.
+    ///
+    /// This returns `None` if the signal number isn't a valid [`Signal`];
+    /// use [`Self::terminating_signal_raw`] to get the number in that case.
+    #[inline]
+    pub fn terminating_signal(self) -> Option<Signal> {
+        Signal::from_raw(self.terminating_signal_raw()? as _)
+    }
+
     /// Returns the number of the signal that terminated the process,
     /// if the process was terminated by a signal.
     #[inline]
-    pub fn terminating_signal(self) -> Option<u32> {
+    pub fn terminating_signal_raw(self) -> Option<u32> {
         if self.signaled() {
             Some(backend::process::wait::WTERMSIG(self.0 as _) as _)
         } else {
             None
         }
     }
+
+    /// Returns the [`PtraceEvent`] that stopped the process, if the process
+    /// is a tracee in a `PTRACE_EVENT_*` stop.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub fn ptrace_event(self) -> Option<PtraceEvent> {
+        // The status is `SIGTRAP | (event << 8)` for events other than
+        // `PTRACE_EVENT_STOP`, which may be reported with other signals.
+        if self.stopped() && (self.0 >> 16) != 0 {
+            PtraceEvent::from_raw(self.0 >> 16)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the process is a tracee in a system call stop, when
+    /// traced with [`PtraceOptions::TRACESYSGOOD`](crate::process::PtraceOptions::TRACESYSGOOD).
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub fn syscall_stopped(self) -> bool {
+        self.stopped()
+            && backend::process::wait::WSTOPSIG(self.0 as _) as i32 == Signal::Trap.as_raw() | 0x80
+    }
 }
 
 /// The status of a process after calling [`waitid`].
@@ -163,11 +209,22 @@ impl WaitidStatus {
         self.si_code() == backend::c::CLD_CONTINUED
     }
 
+    /// Returns the signal that stopped the process,
+    /// if the process was stopped by a signal.
+    ///
+    /// This returns `None` if the signal number isn't a valid [`Signal`];
+    /// use [`Self::stopping_signal_raw`] to get the number in that case.
+    #[inline]
+    #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
+    pub fn stopping_signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.stopping_signal_raw()? as _)
+    }
+
     /// Returns the number of the signal that stopped the process,
     /// if the process was stopped by a signal.
     #[inline]
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
-    pub fn stopping_signal(&self) -> Option<u32> {
+    pub fn stopping_signal_raw(&self) -> Option<u32> {
         if self.stopped() {
             Some(self.si_status() as _)
         } else {
@@ -175,11 +232,22 @@ impl WaitidStatus {
         }
     }
 
+    /// Returns the signal that trapped the process,
+    /// if the process was trapped by a signal.
+    ///
+    /// This returns `None` if the signal number isn't a valid [`Signal`];
+    /// use [`Self::trapping_signal_raw`] to get the number in that case.
+    #[inline]
+    #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
+    pub fn trapping_signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.trapping_signal_raw()? as _)
+    }
+
     /// Returns the number of the signal that trapped the process,
     /// if the process was trapped by a signal.
     #[inline]
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
-    pub fn trapping_signal(&self) -> Option<u32> {
+    pub fn trapping_signal_raw(&self) -> Option<u32> {
         if self.trapped() {
             Some(self.si_status() as _)
         } else {
@@ -199,11 +267,22 @@ impl WaitidStatus {
         }
     }
 
+    /// Returns the signal that terminated the process,
+    /// if the process was terminated by a signal.
+    ///
+    /// This returns `None` if the signal number isn't a valid [`Signal`];
+    /// use [`Self::terminating_signal_raw`] to get the number in that case.
+    #[inline]
+    #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
+    pub fn terminating_signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.terminating_signal_raw()? as _)
+    }
+
     /// Returns the number of the signal that terminated the process,
     /// if the process was terminated by a signal.
     #[inline]
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia", target_os = "emscripten")))]
-    pub fn terminating_signal(&self) -> Option<u32> {
+    pub fn terminating_signal_raw(&self) -> Option<u32> {
         if self.killed() || self.dumped() {
             Some(self.si_status() as _)
         } else {
@@ -0,0 +1,137 @@
+//! Pseudoterminal operations.
+//!
+//! A pseudoterminal is created with [`openpt`], which returns the controller
+//! side. After [`grantpt`] and [`unlockpt`], the user side can be opened by
+//! the name returned by `ptsname`, or on Linux, with `ioctl_tiocgptpeer`.
+
+use crate::backend;
+use crate::fd::{AsFd, OwnedFd};
+use crate::io;
+#[cfg(any(
+    linux_raw,
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux"
+))]
+use {crate::ffi::CString, crate::path::SMALL_PATH_BUFFER_SIZE, alloc::vec::Vec};
+
+pub use backend::pty::types::OpenptFlags;
+
+/// `posix_openpt(flags)`—Open a pseudoterminal device.
+///
+/// On Linux, an additional `CLOEXEC` flag value may be passed to request the
+/// close-on-exec flag be set.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_openpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/posix_openpt.3.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=posix_openpt&sektion=2
+#[inline]
+#[doc(alias = "posix_openpt")]
+pub fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    backend::pty::syscalls::openpt(flags)
+}
+
+/// `ptsname(fd)`—Return the name of a pseudoterminal.
+///
+/// If `reuse` is non-empty, reuse its buffer to store the result if possible.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ptsname.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ptsname.3.html
+#[cfg(any(
+    linux_raw,
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux"
+))]
+#[doc(alias = "ptsname_r")]
+#[inline]
+pub fn ptsname<Fd: AsFd, B: Into<Vec<u8>>>(fd: Fd, reuse: B) -> io::Result<CString> {
+    _ptsname(fd.as_fd(), reuse.into())
+}
+
+#[cfg(any(
+    linux_raw,
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux"
+))]
+fn _ptsname(fd: crate::fd::BorrowedFd<'_>, mut buffer: Vec<u8>) -> io::Result<CString> {
+    // This code would benefit from having a better way to read into
+    // uninitialized memory, but that requires `unsafe`.
+    buffer.clear();
+    buffer.reserve(SMALL_PATH_BUFFER_SIZE);
+    buffer.resize(buffer.capacity(), 0_u8);
+
+    loop {
+        match backend::pty::syscalls::ptsname(fd, &mut buffer) {
+            Err(io::Errno::RANGE) => {
+                buffer.reserve(1); // use `Vec` reallocation strategy to grow capacity exponentially
+                buffer.resize(buffer.capacity(), 0_u8);
+            }
+            Ok(len) => {
+                buffer.resize(len, 0_u8);
+                return Ok(CString::new(buffer).unwrap());
+            }
+            Err(errno) => return Err(errno),
+        }
+    }
+}
+
+/// `unlockpt(fd)`—Unlock a pseudoterminal so that its user side can be
+/// opened.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/unlockpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/unlockpt.3.html
+#[inline]
+pub fn unlockpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::pty::syscalls::unlockpt(fd.as_fd())
+}
+
+/// `grantpt(fd)`—Grant access to the user side of a pseudoterminal.
+///
+/// On Linux, calling this function has no effect, as the kernel is expected to
+/// grant the appropriate access. On all other platforms, this function has
+/// unspecified behavior if the calling process has a signal handler installed
+/// for `SIGCHLD`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/grantpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/grantpt.3.html
+#[inline]
+pub fn grantpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::pty::syscalls::grantpt(fd.as_fd())
+}
+
+/// `ioctl(fd, TIOCGPTPEER)`—Open the user side of a pseudoterminal.
+///
+/// This is equivalent to opening the path returned by [`ptsname`], but
+/// avoids races with other processes renaming or replacing the device node.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_tty.2.html
+#[cfg(any(linux_raw, target_os = "linux"))]
+#[inline]
+pub fn ioctl_tiocgptpeer<Fd: AsFd>(fd: Fd, flags: OpenptFlags) -> io::Result<OwnedFd> {
+    backend::pty::syscalls::ioctl_tiocgptpeer(fd.as_fd(), flags)
+}
@@ -1,40 +1,70 @@
-use crate::termios::{Speed, Termios};
-use crate::{backend, io};
+use crate::io;
+use crate::termios::{speed_code, speed_value, Speed, Termios};
 
 /// `cfgetospeed(termios)`
+///
+/// If the output speed has no `B*` code, this returns `BOTHER` on Linux.
+#[deprecated(note = "use `Termios::output_speed` instead")]
 #[inline]
 #[must_use]
 pub fn cfgetospeed(termios: &Termios) -> Speed {
-    backend::termios::syscalls::cfgetospeed(termios)
+    to_code(termios.output_speed())
 }
 
 /// `cfgetispeed(termios)`
+///
+/// If the input speed has no `B*` code, this returns `BOTHER` on Linux.
+#[deprecated(note = "use `Termios::input_speed` instead")]
 #[inline]
 #[must_use]
 pub fn cfgetispeed(termios: &Termios) -> Speed {
-    backend::termios::syscalls::cfgetispeed(termios)
+    to_code(termios.input_speed())
 }
 
 /// `cfmakeraw(termios)`
+#[deprecated(note = "use `Termios::make_raw` instead")]
 #[inline]
 pub fn cfmakeraw(termios: &mut Termios) {
-    backend::termios::syscalls::cfmakeraw(termios)
+    termios.make_raw()
 }
 
 /// `cfsetospeed(termios, speed)`
+#[deprecated(note = "use `Termios::set_output_speed` instead")]
 #[inline]
 pub fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    backend::termios::syscalls::cfsetospeed(termios, speed)
+    termios.set_output_speed(to_value(speed)?)
 }
 
 /// `cfsetispeed(termios, speed)`
+#[deprecated(note = "use `Termios::set_input_speed` instead")]
 #[inline]
 pub fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    backend::termios::syscalls::cfsetispeed(termios, speed)
+    termios.set_input_speed(to_value(speed)?)
 }
 
 /// `cfsetspeed(termios, speed)`
+#[deprecated(note = "use `Termios::set_speed` instead")]
 #[inline]
 pub fn cfsetspeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
-    backend::termios::syscalls::cfsetspeed(termios, speed)
+    termios.set_speed(to_value(speed)?)
+}
+
+/// Translate a speed value to a `B*` code for the deprecated `cfget*speed`
+/// functions.
+fn to_code(speed: u32) -> Speed {
+    match speed_code(speed) {
+        Some(code) => code,
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        None => linux_raw_sys::general::BOTHER as _,
+        // On other platforms, `Termios` only holds speeds which have a code,
+        // or, on BSDs, where the codes are the speeds themselves.
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        None => speed as _,
+    }
+}
+
+/// Translate a `B*` code to a speed value for the deprecated `cfset*speed`
+/// functions.
+fn to_value(speed: Speed) -> io::Result<u32> {
+    speed_value(speed).ok_or(io::Errno::INVAL)
 }
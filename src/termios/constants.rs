@@ -99,3 +99,96 @@ pub fn speed_value(speed: backend::termios::types::Speed) -> Option<u32> {
         _ => None,
     }
 }
+
+/// Translate from a speed value `u32` to a `Speed` code, if there is one.
+pub(crate) fn speed_code(speed: u32) -> Option<backend::termios::types::Speed> {
+    match speed {
+        0 => Some(backend::termios::types::B0),
+        50 => Some(backend::termios::types::B50),
+        75 => Some(backend::termios::types::B75),
+        110 => Some(backend::termios::types::B110),
+        134 => Some(backend::termios::types::B134),
+        150 => Some(backend::termios::types::B150),
+        200 => Some(backend::termios::types::B200),
+        300 => Some(backend::termios::types::B300),
+        600 => Some(backend::termios::types::B600),
+        1200 => Some(backend::termios::types::B1200),
+        1800 => Some(backend::termios::types::B1800),
+        2400 => Some(backend::termios::types::B2400),
+        4800 => Some(backend::termios::types::B4800),
+        9600 => Some(backend::termios::types::B9600),
+        19200 => Some(backend::termios::types::B19200),
+        38400 => Some(backend::termios::types::B38400),
+        #[cfg(not(target_os = "aix"))]
+        57600 => Some(backend::termios::types::B57600),
+        #[cfg(not(target_os = "aix"))]
+        115_200 => Some(backend::termios::types::B115200),
+        #[cfg(not(target_os = "aix"))]
+        230_400 => Some(backend::termios::types::B230400),
+        #[cfg(not(any(
+            apple,
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "haiku",
+            target_os = "openbsd"
+        )))]
+        460_800 => Some(backend::termios::types::B460800),
+        #[cfg(not(any(bsd, solarish, target_os = "aix", target_os = "haiku")))]
+        500_000 => Some(backend::termios::types::B500000),
+        #[cfg(not(any(bsd, solarish, target_os = "aix", target_os = "haiku")))]
+        576_000 => Some(backend::termios::types::B576000),
+        #[cfg(not(any(
+            apple,
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "haiku",
+            target_os = "openbsd"
+        )))]
+        921_600 => Some(backend::termios::types::B921600),
+        #[cfg(not(any(bsd, target_os = "aix", target_os = "haiku", target_os = "solaris")))]
+        1_000_000 => Some(backend::termios::types::B1000000),
+        #[cfg(not(any(bsd, target_os = "aix", target_os = "haiku", target_os = "solaris")))]
+        1_152_000 => Some(backend::termios::types::B1152000),
+        #[cfg(not(any(bsd, target_os = "aix", target_os = "haiku", target_os = "solaris")))]
+        1_500_000 => Some(backend::termios::types::B1500000),
+        #[cfg(not(any(bsd, target_os = "aix", target_os = "haiku", target_os = "solaris")))]
+        2_000_000 => Some(backend::termios::types::B2000000),
+        #[cfg(not(any(
+            target_arch = "sparc",
+            target_arch = "sparc64",
+            bsd,
+            target_os = "aix",
+            target_os = "haiku",
+            target_os = "solaris",
+        )))]
+        2_500_000 => Some(backend::termios::types::B2500000),
+        #[cfg(not(any(
+            target_arch = "sparc",
+            target_arch = "sparc64",
+            bsd,
+            target_os = "aix",
+            target_os = "haiku",
+            target_os = "solaris",
+        )))]
+        3_000_000 => Some(backend::termios::types::B3000000),
+        #[cfg(not(any(
+            target_arch = "sparc",
+            target_arch = "sparc64",
+            bsd,
+            target_os = "aix",
+            target_os = "haiku",
+            target_os = "solaris",
+        )))]
+        3_500_000 => Some(backend::termios::types::B3500000),
+        #[cfg(not(any(
+            target_arch = "sparc",
+            target_arch = "sparc64",
+            bsd,
+            target_os = "aix",
+            target_os = "haiku",
+            target_os = "solaris",
+        )))]
+        4_000_000 => Some(backend::termios::types::B4000000),
+        _ => None,
+    }
+}
@@ -8,6 +8,8 @@ mod constants;
 mod tc;
 #[cfg(not(windows))]
 mod tty;
+#[cfg(not(target_os = "wasi"))]
+mod types;
 
 #[cfg(not(target_os = "wasi"))]
 pub use cf::*;
@@ -17,3 +19,5 @@ pub use constants::*;
 pub use tc::*;
 #[cfg(not(windows))]
 pub use tty::*;
+#[cfg(not(target_os = "wasi"))]
+pub use types::*;
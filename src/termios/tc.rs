@@ -2,27 +2,22 @@ use crate::fd::AsFd;
 use crate::process::Pid;
 use crate::{backend, io};
 
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
-pub use backend::termios::types::Termios2;
-pub use backend::termios::types::{
-    Action, OptionalActions, QueueSelector, Speed, Tcflag, Termios, Winsize,
-};
+use crate::termios::Termios;
+
+pub use backend::termios::types::{Action, OptionalActions, QueueSelector, Winsize};
+
+/// `struct termios2`, for use with [`tcgetattr2`] and [`tcsetattr2`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[deprecated(
+    note = "`Termios` supports arbitrary speeds with `termios2` and `BOTHER`; use it instead"
+)]
+pub type Termios2 = Termios;
 
 /// `tcgetattr(fd)`—Get terminal attributes.
 ///
-/// Also known as the `TCGETS` operation with `ioctl`.
+/// Also known as the `TCGETS` operation with `ioctl`. On Linux, this uses the
+/// `TCGETS2` operation where available, so that speeds which don't have a
+/// `B*` code are reported accurately.
 ///
 /// # References
 ///  - [POSIX `tcgetattr`]
@@ -35,39 +30,22 @@ pub use backend::termios::types::{
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[inline]
 #[doc(alias = "TCGETS")]
+#[doc(alias = "TCGETS2")]
+#[doc(alias = "tcgetattr2")]
 pub fn tcgetattr<Fd: AsFd>(fd: Fd) -> io::Result<Termios> {
     backend::termios::syscalls::tcgetattr(fd.as_fd())
 }
 
 /// `tcgetattr2(fd)`—Get terminal attributes.
 ///
-/// Also known as the `TCGETS2` operation with `ioctl`.
-///
-/// # References
-///  - [POSIX `tcgetattr`]
-///  - [Linux `ioctl_tty`]
-///  - [Linux `termios`]
-///
-/// [POSIX `tcgetattr`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetattr.html
-/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
-/// [Linux `termios`]: https://man7.org/linux/man-pages/man3/termios.3.html
+/// Also known as the `TCGETS2` operation with `ioctl`. This is now the same as
+/// [`tcgetattr`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[deprecated(note = "use `tcgetattr` instead")]
+#[allow(deprecated)]
 #[inline]
-#[doc(alias = "TCGETS2")]
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
 pub fn tcgetattr2<Fd: AsFd>(fd: Fd) -> io::Result<Termios2> {
-    backend::termios::syscalls::tcgetattr2(fd.as_fd())
+    tcgetattr(fd)
 }
 
 /// `tcgetwinsize(fd)`—Get the current terminal window size.
@@ -121,7 +99,9 @@ pub fn tcsetpgrp<Fd: AsFd>(fd: Fd, pid: Pid) -> io::Result<()> {
 
 /// `tcsetattr(fd)`—Set terminal attributes.
 ///
-/// Also known as the `TCSETS` operation with `ioctl`.
+/// Also known as the `TCSETS` operation with `ioctl`. On Linux, this uses the
+/// `TCSETS2` operation where available, so that speeds which don't have a
+/// `B*` code can be set, using `BOTHER`.
 ///
 /// # References
 ///  - [POSIX `tcsetattr`]
@@ -133,6 +113,8 @@ pub fn tcsetpgrp<Fd: AsFd>(fd: Fd, pid: Pid) -> io::Result<()> {
 /// [Linux `termios`]: https://man7.org/linux/man-pages/man3/termios.3.html
 #[inline]
 #[doc(alias = "TCSETS")]
+#[doc(alias = "TCSETS2")]
+#[doc(alias = "tcsetattr2")]
 pub fn tcsetattr<Fd: AsFd>(
     fd: Fd,
     optional_actions: OptionalActions,
@@ -141,39 +123,80 @@ pub fn tcsetattr<Fd: AsFd>(
     backend::termios::syscalls::tcsetattr(fd.as_fd(), optional_actions, termios)
 }
 
-/// `tcsetattr2(fd)`—Set terminal attributes.
-///
-/// Also known as the `TCSETS2` operation with `ioctl`.
-///
-/// # References
-///  - [POSIX `tcsetattr`]
-///  - [Linux `ioctl_tty`]
-///  - [Linux `termios`]
+/// `tcsetattr2(fd, optional_actions, termios)`—Set terminal attributes.
 ///
-/// [POSIX `tcsetattr`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcsetattr.html
-/// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
-/// [Linux `termios`]: https://man7.org/linux/man-pages/man3/termios.3.html
+/// Also known as the `TCSETS2` operation with `ioctl`. This is now the same as
+/// [`tcsetattr`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[deprecated(note = "use `tcsetattr` instead")]
+#[allow(deprecated)]
 #[inline]
-#[doc(alias = "TCSETS2")]
-#[cfg(all(
-    any(target_os = "android", target_os = "linux"),
-    any(
-        target_arch = "x86",
-        target_arch = "x86_64",
-        target_arch = "x32",
-        target_arch = "riscv64",
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "mips",
-        target_arch = "mips64",
-    )
-))]
 pub fn tcsetattr2<Fd: AsFd>(
     fd: Fd,
     optional_actions: OptionalActions,
     termios: &Termios2,
 ) -> io::Result<()> {
-    backend::termios::syscalls::tcsetattr2(fd.as_fd(), optional_actions, termios)
+    tcsetattr(fd, optional_actions, termios)
+}
+
+/// A guard which restores the terminal attributes of a file descriptor when
+/// dropped.
+///
+/// The attributes are saved with [`tcgetattr`] when the guard is created, and
+/// restored with [`tcsetattr`] and [`OptionalActions::Flush`] when it's
+/// dropped, which discards any input that was received but not read. Errors
+/// from restoring the attributes are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use rustix::termios::TermiosGuard;
+///
+/// let tty = std::fs::File::open("/dev/tty")?;
+/// let _guard = TermiosGuard::raw(&tty)?;
+///
+/// // Read individual keypresses from `tty` here. When `_guard` goes out of
+/// // scope, the terminal is restored.
+/// # Ok(())
+/// # }
+/// ```
+pub struct TermiosGuard<Fd: AsFd> {
+    fd: Fd,
+    saved: Termios,
+}
+
+impl<Fd: AsFd> TermiosGuard<Fd> {
+    /// Save the terminal attributes of `fd`, to be restored when the guard is
+    /// dropped.
+    pub fn new(fd: Fd) -> io::Result<Self> {
+        let saved = tcgetattr(&fd)?;
+        Ok(Self { fd, saved })
+    }
+
+    /// Save the terminal attributes of `fd`, to be restored when the guard is
+    /// dropped, and then put the terminal into raw mode, as if by
+    /// [`Termios::make_raw`].
+    pub fn raw(fd: Fd) -> io::Result<Self> {
+        let guard = Self::new(fd)?;
+        let mut raw = guard.saved.clone();
+        raw.make_raw();
+        tcsetattr(&guard.fd, OptionalActions::Flush, &raw)?;
+        Ok(guard)
+    }
+
+    /// Return the saved terminal attributes, which will be restored when the
+    /// guard is dropped.
+    #[inline]
+    pub fn saved(&self) -> &Termios {
+        &self.saved
+    }
+}
+
+impl<Fd: AsFd> Drop for TermiosGuard<Fd> {
+    fn drop(&mut self) {
+        let _ = tcsetattr(&self.fd, OptionalActions::Flush, &self.saved);
+    }
 }
 
 /// `tcsendbreak(fd, 0)`—Transmit zero-valued bits.
@@ -0,0 +1,188 @@
+use crate::backend::termios::types::NCCS;
+use crate::io;
+use crate::termios::{ControlModes, InputModes, LocalModes, OutputModes};
+use core::ops::{Index, IndexMut};
+
+/// `struct termios` for use with [`tcgetattr`] and [`tcsetattr`].
+///
+/// Unlike the platform's `struct termios`, this has the same layout and the
+/// same typed fields on every platform and backend. The input and output
+/// speeds are accessed with methods, such as [`Termios::input_speed`], and
+/// are always plain bits-per-second values rather than `B*` codes.
+///
+/// On Linux, [`tcgetattr`] and [`tcsetattr`] use `termios2` and `BOTHER`
+/// where needed, so the speeds may be arbitrary values. On other platforms,
+/// only speeds which have a `B*` code are supported.
+///
+/// [`tcgetattr`]: crate::termios::tcgetattr
+/// [`tcsetattr`]: crate::termios::tcsetattr
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "termios")]
+#[doc(alias = "termios2")]
+pub struct Termios {
+    /// How is input interpreted?
+    #[doc(alias = "c_iflag")]
+    pub input_modes: InputModes,
+
+    /// How is output translated?
+    #[doc(alias = "c_oflag")]
+    pub output_modes: OutputModes,
+
+    /// Low-level configuration flags, other than the speeds.
+    #[doc(alias = "c_cflag")]
+    pub control_modes: ControlModes,
+
+    /// High-level configuration flags.
+    #[doc(alias = "c_lflag")]
+    pub local_modes: LocalModes,
+
+    /// Line discipline.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[doc(alias = "c_line")]
+    pub line_discipline: u8,
+
+    /// How are various special control codes handled?
+    #[doc(alias = "c_cc")]
+    pub special_codes: SpecialCodes,
+
+    /// The input speed, in bits per second.
+    pub(crate) input_speed: u32,
+
+    /// The output speed, in bits per second.
+    pub(crate) output_speed: u32,
+}
+
+impl Termios {
+    /// `cfmakeraw(termios)`—Set the terminal to “raw” mode.
+    ///
+    /// This disables input and output processing, echoing, canonical
+    /// (line-at-a-time) input, and signal generation, and sets the character
+    /// size to 8 bits, so that reads return each byte as soon as it's
+    /// available. It makes the same changes as glibc's `cfmakeraw`, on all
+    /// platforms.
+    ///
+    /// This only modifies the `Termios` value; use [`tcsetattr`] to apply it
+    /// to a terminal, or [`TermiosGuard::raw`] to apply it and restore the
+    /// original attributes afterwards.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///  - [glibc]
+    ///
+    /// [`tcsetattr`]: crate::termios::tcsetattr
+    /// [`TermiosGuard::raw`]: crate::termios::TermiosGuard::raw
+    /// [Linux]: https://man7.org/linux/man-pages/man3/cfmakeraw.3.html
+    /// [glibc]: https://sourceware.org/git/?p=glibc.git;a=blob;f=termios/cfmakeraw.c
+    #[doc(alias = "cfmakeraw")]
+    pub fn make_raw(&mut self) {
+        self.input_modes -= InputModes::IGNBRK
+            | InputModes::BRKINT
+            | InputModes::PARMRK
+            | InputModes::ISTRIP
+            | InputModes::INLCR
+            | InputModes::IGNCR
+            | InputModes::ICRNL
+            | InputModes::IXON;
+        self.output_modes -= OutputModes::OPOST;
+        self.local_modes -= LocalModes::ECHO
+            | LocalModes::ECHONL
+            | LocalModes::ICANON
+            | LocalModes::ISIG
+            | LocalModes::IEXTEN;
+        self.control_modes -= ControlModes::CSIZE | ControlModes::PARENB;
+        self.control_modes |= ControlModes::CS8;
+
+        // Read returns when one byte is available, with no timeout.
+        self.special_codes[SpecialCodeIndex::VMIN] = 1;
+        self.special_codes[SpecialCodeIndex::VTIME] = 0;
+    }
+
+    /// `cfgetispeed(termios)`—Return the input speed, in bits per second.
+    #[inline]
+    #[doc(alias = "cfgetispeed")]
+    #[doc(alias = "c_ispeed")]
+    pub fn input_speed(&self) -> u32 {
+        self.input_speed
+    }
+
+    /// `cfgetospeed(termios)`—Return the output speed, in bits per second.
+    #[inline]
+    #[doc(alias = "cfgetospeed")]
+    #[doc(alias = "c_ospeed")]
+    pub fn output_speed(&self) -> u32 {
+        self.output_speed
+    }
+
+    /// `cfsetspeed(termios, speed)`—Set the input and output speeds, in bits
+    /// per second.
+    ///
+    /// On platforms other than Linux, this fails with [`io::Errno::INVAL`]
+    /// if `speed` doesn't have a `B*` code.
+    #[doc(alias = "cfsetspeed")]
+    pub fn set_speed(&mut self, speed: u32) -> io::Result<()> {
+        check_speed(speed)?;
+        self.input_speed = speed;
+        self.output_speed = speed;
+        Ok(())
+    }
+
+    /// `cfsetispeed(termios, speed)`—Set the input speed, in bits per second.
+    ///
+    /// On platforms other than Linux, this fails with [`io::Errno::INVAL`]
+    /// if `speed` doesn't have a `B*` code.
+    #[doc(alias = "cfsetispeed")]
+    pub fn set_input_speed(&mut self, speed: u32) -> io::Result<()> {
+        check_speed(speed)?;
+        self.input_speed = speed;
+        Ok(())
+    }
+
+    /// `cfsetospeed(termios, speed)`—Set the output speed, in bits per
+    /// second.
+    ///
+    /// On platforms other than Linux, this fails with [`io::Errno::INVAL`]
+    /// if `speed` doesn't have a `B*` code.
+    #[doc(alias = "cfsetospeed")]
+    pub fn set_output_speed(&mut self, speed: u32) -> io::Result<()> {
+        check_speed(speed)?;
+        self.output_speed = speed;
+        Ok(())
+    }
+}
+
+/// Check that `speed` can be passed to the platform's `tcsetattr`.
+#[inline]
+fn check_speed(speed: u32) -> io::Result<()> {
+    // With `BOTHER`, Linux accepts any speed. On BSDs, `speed_t` values are
+    // just the speeds themselves.
+    if cfg!(any(linux_raw, bsd, termios2)) || crate::termios::speed_code(speed).is_some() {
+        Ok(())
+    } else {
+        Err(io::Errno::INVAL)
+    }
+}
+
+/// The special codes of a [`Termios`], indexed by [`SpecialCodeIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[doc(alias = "c_cc")]
+pub struct SpecialCodes(pub(crate) [u8; NCCS]);
+
+impl Index<SpecialCodeIndex> for SpecialCodes {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, index: SpecialCodeIndex) -> &Self::Output {
+        &self.0[index.0]
+    }
+}
+
+impl IndexMut<SpecialCodeIndex> for SpecialCodes {
+    #[inline]
+    fn index_mut(&mut self, index: SpecialCodeIndex) -> &mut Self::Output {
+        &mut self.0[index.0]
+    }
+}
+
+/// `V*` indices for use with [`SpecialCodes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecialCodeIndex(pub(crate) usize);
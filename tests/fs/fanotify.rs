@@ -0,0 +1,260 @@
+//! Tests for fanotify.
+//!
+//! Most fanotify features require `CAP_SYS_ADMIN`, so these are skipped when
+//! it's unavailable.
+
+use rustix::fd::{IntoRawFd, OwnedFd};
+use rustix::fs::fanotify::{
+    fanotify_init, fanotify_mark, fanotify_respond, EventFlags, Info, InitFlags, MarkFlags,
+    MaskFlags, Reader, Response,
+};
+use rustix::fs::{cwd, fstat, openat, statat, AtFlags, Mode, OFlags};
+use rustix::io::{self, PipeFlags};
+use std::mem::MaybeUninit;
+
+/// The size of `struct fanotify_event_metadata`.
+const METADATA_LEN: u16 = 24;
+
+/// Construct the bytes of a `struct fanotify_event_metadata`.
+fn metadata(event_len: u32, metadata_len: u16, fd: i32) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&event_len.to_ne_bytes());
+    bytes.push(3); // `FANOTIFY_METADATA_VERSION`
+    bytes.push(0);
+    bytes.extend_from_slice(&metadata_len.to_ne_bytes());
+    bytes.extend_from_slice(&MaskFlags::OPEN.bits().to_ne_bytes());
+    bytes.extend_from_slice(&fd.to_ne_bytes());
+    bytes.extend_from_slice(
+        &rustix::process::getpid()
+            .as_raw_nonzero()
+            .get()
+            .to_ne_bytes(),
+    );
+    bytes
+}
+
+/// Create a fanotify group, or return `None` if we lack the privileges or
+/// kernel support to use `flags`.
+fn init(flags: InitFlags, event_flags: EventFlags) -> Option<OwnedFd> {
+    match fanotify_init(flags | InitFlags::CLOEXEC, event_flags) {
+        Ok(fd) => Some(fd),
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) => None,
+        Err(err) => panic!("fanotify_init failed: {:?}", err),
+    }
+}
+
+#[test]
+fn test_fanotify_dfid_name() {
+    let fan = match init(
+        InitFlags::NONBLOCK | InitFlags::REPORT_DFID_NAME,
+        EventFlags::RDONLY,
+    ) {
+        Some(fan) => fan,
+        None => return,
+    };
+    let tmp = tempfile::tempdir().unwrap();
+    match fanotify_mark(
+        &fan,
+        MarkFlags::ADD,
+        MaskFlags::CREATE | MaskFlags::ONDIR,
+        cwd(),
+        tmp.path(),
+    ) {
+        Ok(()) => {}
+        // The filesystem may not support file handles.
+        Err(io::Errno::XDEV) | Err(io::Errno::NODEV) | Err(io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("fanotify_mark failed: {:?}", err),
+    }
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut reader = Reader::new(&fan, &mut buf);
+    assert_eq!(reader.next().unwrap_err(), io::Errno::AGAIN);
+
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask(), MaskFlags::CREATE);
+    assert!(event.fd().is_none());
+    assert_eq!(event.pid(), Some(rustix::process::getpid()));
+
+    let info = event.info().collect::<Vec<_>>();
+    assert_eq!(info.len(), 1);
+    match info[0] {
+        Info::DfidName(fid, name) => {
+            assert!(!fid.handle().is_empty());
+            assert_eq!(name.to_bytes(), b"file");
+        }
+        other => panic!("unexpected info record: {:?}", other),
+    }
+    drop(event);
+
+    assert!(reader.is_buffer_empty());
+    assert_eq!(reader.next().unwrap_err(), io::Errno::AGAIN);
+}
+
+#[test]
+fn test_fanotify_fd() {
+    let fan = match init(
+        InitFlags::NONBLOCK | InitFlags::CLASS_NOTIF,
+        EventFlags::RDONLY | EventFlags::CLOEXEC,
+    ) {
+        Some(fan) => fan,
+        None => return,
+    };
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    fanotify_mark(&fan, MarkFlags::ADD, MaskFlags::CLOSE_WRITE, &dir, "file").unwrap();
+    drop(file);
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut reader = Reader::new(&fan, &mut buf);
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask(), MaskFlags::CLOSE_WRITE);
+    assert_eq!(event.info().count(), 0);
+    assert!(event.pidfd().is_none());
+
+    // The event's file descriptor refers to the file.
+    let fd = event.into_fd().unwrap();
+    assert_eq!(
+        fstat(&fd).unwrap().st_ino,
+        statat(&dir, "file", AtFlags::empty()).unwrap().st_ino
+    );
+}
+
+#[test]
+fn test_fanotify_permission() {
+    let fan = match init(InitFlags::CLASS_CONTENT, EventFlags::RDONLY) {
+        Some(fan) => fan,
+        None => return,
+    };
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    fanotify_mark(&fan, MarkFlags::ADD, MaskFlags::OPEN_PERM, &dir, "file").unwrap();
+
+    let path = tmp.path().join("file");
+    let opener = std::thread::spawn(move || {
+        let denied = openat(cwd(), &path, OFlags::RDONLY, Mode::empty()).map(drop);
+        let allowed = openat(cwd(), &path, OFlags::RDONLY, Mode::empty()).map(drop);
+        (denied, allowed)
+    });
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut reader = Reader::new(&fan, &mut buf);
+    for response in &[Response::DENY, Response::ALLOW] {
+        let event = reader.next().unwrap();
+        assert_eq!(event.mask(), MaskFlags::OPEN_PERM);
+        fanotify_respond(&fan, event.fd().unwrap(), *response).unwrap();
+    }
+
+    let (denied, allowed) = opener.join().unwrap();
+    assert_eq!(denied.unwrap_err(), io::Errno::PERM);
+    allowed.unwrap();
+}
+
+#[test]
+fn test_fanotify_reader_malformed() {
+    let cases = [
+        // Too short for a metadata struct.
+        metadata(u32::from(METADATA_LEN), METADATA_LEN, -1)[..8].to_vec(),
+        // An `event_len` of 0.
+        metadata(0, 0, -1),
+        // A `metadata_len` shorter than the metadata struct.
+        metadata(u32::from(METADATA_LEN), 8, -1),
+        // A `metadata_len` longer than the event.
+        metadata(u32::from(METADATA_LEN), METADATA_LEN + 8, -1),
+        // An `event_len` longer than the data read.
+        metadata(u32::from(METADATA_LEN) + 8, METADATA_LEN, -1),
+    ];
+    for bytes in &cases {
+        let (reader_end, writer_end) = io::pipe_with(PipeFlags::NONBLOCK).unwrap();
+        assert_eq!(io::write(&writer_end, bytes).unwrap(), bytes.len());
+
+        let mut buf = [MaybeUninit::uninit(); 1024];
+        let mut reader = Reader::new(&reader_end, &mut buf);
+        assert_eq!(reader.next().unwrap_err(), io::Errno::INVAL);
+        assert!(reader.is_buffer_empty());
+        assert_eq!(reader.next().unwrap_err(), io::Errno::AGAIN);
+    }
+}
+
+#[test]
+fn test_fanotify_reader_drop() {
+    // Use the write end of a pipe as the file descriptors of the events, so
+    // that we can tell when they're all closed.
+    let (check_reader, check_writer) = io::pipe_with(PipeFlags::NONBLOCK).unwrap();
+    let first_fd = io::dup(&check_writer).unwrap().into_raw_fd();
+    let second_fd = io::dup(&check_writer).unwrap().into_raw_fd();
+    let pidfd = io::dup(&check_writer).unwrap().into_raw_fd();
+    drop(check_writer);
+
+    let mut bytes = metadata(u32::from(METADATA_LEN), METADATA_LEN, first_fd);
+    bytes.extend(metadata(
+        u32::from(METADATA_LEN) + 8,
+        METADATA_LEN,
+        second_fd,
+    ));
+    // A `FAN_EVENT_INFO_TYPE_PIDFD` record.
+    bytes.push(4);
+    bytes.push(0);
+    bytes.extend_from_slice(&8_u16.to_ne_bytes());
+    bytes.extend_from_slice(&pidfd.to_ne_bytes());
+
+    let (reader_end, writer_end) = io::pipe_with(PipeFlags::NONBLOCK).unwrap();
+    assert_eq!(io::write(&writer_end, &bytes).unwrap(), bytes.len());
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut reader = Reader::new(&reader_end, &mut buf);
+    drop(reader.next().unwrap());
+    assert!(!reader.is_buffer_empty());
+    let mut check = [0_u8; 1];
+    assert_eq!(
+        io::read(&check_reader, &mut check).unwrap_err(),
+        io::Errno::AGAIN
+    );
+
+    // Dropping the reader closes the file descriptors of the second event.
+    drop(reader);
+    assert_eq!(io::read(&check_reader, &mut check).unwrap(), 0);
+}
+
+#[test]
+fn test_fanotify_info_malformed() {
+    let mut bytes = metadata(u32::from(METADATA_LEN) + 24, METADATA_LEN, -1);
+    // A `FAN_EVENT_INFO_TYPE_FID` record too short for its file handle.
+    bytes.push(1);
+    bytes.push(0);
+    bytes.extend_from_slice(&12_u16.to_ne_bytes());
+    bytes.extend_from_slice(&[0; 8]);
+    // A `FAN_EVENT_INFO_TYPE_ERROR` record.
+    bytes.push(5);
+    bytes.push(0);
+    bytes.extend_from_slice(&12_u16.to_ne_bytes());
+    bytes.extend_from_slice(&libc::EIO.to_ne_bytes());
+    bytes.extend_from_slice(&2_u32.to_ne_bytes());
+
+    let (reader_end, writer_end) = io::pipe_with(PipeFlags::NONBLOCK).unwrap();
+    assert_eq!(io::write(&writer_end, &bytes).unwrap(), bytes.len());
+
+    let mut buf = [MaybeUninit::uninit(); 1024];
+    let mut reader = Reader::new(&reader_end, &mut buf);
+    let event = reader.next().unwrap();
+    let mut info = event.info();
+
+    // The malformed record is reported as unknown, and the records after it
+    // are still read.
+    match info.next() {
+        Some(Info::Unknown(1, body)) => assert_eq!(body, &[0; 8]),
+        other => panic!("unexpected info {:?}", other),
+    }
+    match info.next() {
+        Some(Info::Error { error, error_count }) => {
+            assert_eq!(error, libc::EIO);
+            assert_eq!(error_count, 2);
+        }
+        other => panic!("unexpected info {:?}", other),
+    }
+    assert!(info.next().is_none());
+}
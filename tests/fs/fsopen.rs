@@ -0,0 +1,146 @@
+//! Tests for the file-descriptor-based mount API.
+//!
+//! These need `CAP_SYS_ADMIN`, so they're skipped when it's unavailable.
+
+use rustix::fd::AsFd;
+use rustix::fs::{
+    cwd, fsconfig_create, fsconfig_set_flag, fsconfig_set_string, fsmount, fsopen, mkdirat,
+    mount_setattr, move_mount_at, open_tree, openat, statat, unmount, AtFlags, FsMountFlags,
+    FsOpenFlags, Mode, MountAttr, MountAttrFlags, MoveMountFlags, OFlags, OpenTreeFlags,
+    UnmountFlags,
+};
+use rustix::io;
+
+/// Create a tmpfs filesystem context, or return `None` if the new mount API
+/// is unavailable or we lack the privileges to use it.
+fn tmpfs() -> Option<rustix::fd::OwnedFd> {
+    match fsopen("tmpfs", FsOpenFlags::CLOEXEC) {
+        Ok(fs) => Some(fs),
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => None,
+        Err(err) => panic!("fsopen failed: {:?}", err),
+    }
+}
+
+#[test]
+fn test_fsmount_tmpfs() {
+    let fs = match tmpfs() {
+        Some(fs) => fs,
+        None => return,
+    };
+    fsconfig_set_string(&fs, "size", "1M").unwrap();
+    fsconfig_set_string(&fs, "mode", "0755").unwrap();
+    fsconfig_create(&fs).unwrap();
+    let mnt = fsmount(
+        &fs,
+        FsMountFlags::CLOEXEC,
+        MountAttrFlags::NOSUID | MountAttrFlags::NODEV,
+    )
+    .unwrap();
+
+    // The detached mount is usable through its file descriptor.
+    let file = openat(
+        &mnt,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(io::write(&file, b"tmpfs").unwrap(), 5);
+    drop(file);
+    assert_eq!(statat(&mnt, "file", AtFlags::empty()).unwrap().st_size, 5);
+
+    // Make it read-only.
+    match mount_setattr(
+        &mnt,
+        "",
+        AtFlags::EMPTY_PATH,
+        &MountAttr::new().set(MountAttrFlags::RDONLY),
+    ) {
+        Ok(()) => {}
+        // `mount_setattr` requires Linux 5.12.
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("mount_setattr failed: {:?}", err),
+    }
+    assert_eq!(
+        openat(&mnt, "other", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap_err(),
+        io::Errno::ROFS
+    );
+
+    // And writable again.
+    mount_setattr(
+        &mnt,
+        "",
+        AtFlags::EMPTY_PATH,
+        &MountAttr::new().clear(MountAttrFlags::RDONLY),
+    )
+    .unwrap();
+    openat(&mnt, "other", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+}
+
+#[test]
+fn test_fsconfig_errors() {
+    let fs = match tmpfs() {
+        Some(fs) => fs,
+        None => return,
+    };
+    assert_eq!(
+        fsconfig_set_string(&fs, "no-such-parameter", "1").unwrap_err(),
+        io::Errno::INVAL
+    );
+    assert_eq!(
+        fsconfig_set_flag(&fs, "size").unwrap_err(),
+        io::Errno::INVAL
+    );
+
+    // A mount can't be created before the filesystem is.
+    assert_eq!(
+        fsmount(&fs, FsMountFlags::CLOEXEC, MountAttrFlags::empty()).unwrap_err(),
+        io::Errno::INVAL
+    );
+}
+
+#[test]
+fn test_open_tree_move_mount() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    mkdirat(&dir, "source", Mode::RWXU).unwrap();
+    mkdirat(&dir, "target", Mode::RWXU).unwrap();
+    openat(
+        &dir,
+        "source/file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR,
+    )
+    .unwrap();
+
+    let tree = match open_tree(
+        &dir,
+        "source",
+        OpenTreeFlags::CLONE | OpenTreeFlags::CLOEXEC,
+    ) {
+        Ok(tree) => tree,
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("open_tree failed: {:?}", err),
+    };
+    statat(&tree, "file", AtFlags::empty()).unwrap();
+
+    // A user namespace file descriptor is required for an idmapped mount.
+    match mount_setattr(
+        &tree,
+        "",
+        AtFlags::EMPTY_PATH,
+        &MountAttr::new().idmap(dir.as_fd()),
+    ) {
+        Err(io::Errno::INVAL) | Err(io::Errno::NOSYS) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    move_mount_at(&tree, "", &dir, "target", MoveMountFlags::F_EMPTY_PATH).unwrap();
+    let moved = statat(&dir, "target/file", AtFlags::empty());
+    unmount(tmp.path().join("target"), UnmountFlags::DETACH).unwrap();
+    moved.unwrap();
+    assert_eq!(
+        statat(&dir, "target/file", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+}
@@ -0,0 +1,90 @@
+use rustix::fd::AsFd;
+use rustix::fs::inotify::{
+    inotify_add_watch, inotify_init, inotify_remove_watch, CreateFlags, ReadFlags, Reader,
+    WatchFlags,
+};
+use rustix::fs::{cwd, mkdirat, openat, renameat, Mode, OFlags};
+use rustix::io;
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+/// Read all the pending events, as `(wd, events, cookie, file_name)`.
+fn read_events(inot: &rustix::fd::OwnedFd) -> Vec<(i32, ReadFlags, u32, Option<CString>)> {
+    let mut buf = [MaybeUninit::uninit(); 512];
+    let mut reader = Reader::new(inot, &mut buf);
+    let mut events = Vec::new();
+    loop {
+        match reader.next() {
+            Ok(event) => events.push((
+                event.wd(),
+                event.events(),
+                event.cookie(),
+                event.file_name().map(|name| name.to_owned()),
+            )),
+            Err(io::Errno::AGAIN) => return events,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+}
+
+#[test]
+fn test_inotify_reader() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let inot = inotify_init(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    let wd = inotify_add_watch(
+        inot.as_fd(),
+        tmp.path(),
+        WatchFlags::CREATE | WatchFlags::MOVED_FROM | WatchFlags::MOVED_TO,
+    )
+    .unwrap();
+    assert!(read_events(&inot).is_empty());
+
+    openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    mkdirat(&dir, "subdir", Mode::RWXU).unwrap();
+    renameat(&dir, "file", &dir, "renamed").unwrap();
+
+    let events = read_events(&inot);
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0].0, wd);
+    assert_eq!(events[0].1, ReadFlags::CREATE);
+    assert_eq!(events[0].3.as_deref().unwrap().to_bytes(), b"file");
+    assert_eq!(events[1].1, ReadFlags::CREATE | ReadFlags::ISDIR);
+    assert_eq!(events[1].3.as_deref().unwrap().to_bytes(), b"subdir");
+    assert_eq!(events[2].1, ReadFlags::MOVED_FROM);
+    assert_eq!(events[2].3.as_deref().unwrap().to_bytes(), b"file");
+    assert_eq!(events[3].1, ReadFlags::MOVED_TO);
+    assert_eq!(events[3].3.as_deref().unwrap().to_bytes(), b"renamed");
+    assert_ne!(events[2].2, 0);
+    assert_eq!(events[2].2, events[3].2);
+
+    // Removing the watch produces an `IN_IGNORED` event with no name.
+    inotify_remove_watch(inot.as_fd(), wd).unwrap();
+    let events = read_events(&inot);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].0, wd);
+    assert_eq!(events[0].1, ReadFlags::IGNORED);
+    assert_eq!(events[0].3, None);
+}
+
+#[test]
+fn test_inotify_reader_small_buffer() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let inot = inotify_init(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    inotify_add_watch(inot.as_fd(), tmp.path(), WatchFlags::CREATE).unwrap();
+    openat(
+        &dir,
+        "a-long-file-name",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR,
+    )
+    .unwrap();
+
+    // The buffer is too small for the event and its name.
+    let mut buf = [MaybeUninit::uninit(); 20];
+    let mut reader = Reader::new(&inot, &mut buf);
+    assert_eq!(reader.next().unwrap_err(), io::Errno::INVAL);
+}
@@ -7,6 +7,8 @@
 
 mod cwd;
 mod dir;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fanotify;
 mod fcntl;
 #[cfg(not(any(
     target_os = "emscripten",
@@ -18,7 +20,11 @@ mod fcntl_lock;
 mod file;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fsopen;
 mod futimens;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod inotify;
 mod invalid_offset;
 mod long_paths;
 #[cfg(not(any(solarish, target_os = "haiku", target_os = "redox", target_os = "wasi")))]
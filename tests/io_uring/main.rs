@@ -0,0 +1,45 @@
+//! Tests for [`rustix::io_uring`].
+
+#![cfg(feature = "io_uring")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+mod opcode;
+mod probe;
+mod register;
+mod ring;
+
+use rustix::io;
+use rustix::io_uring::{io_uring_cqe, io_uring_sqe, io_uring_user_data, IoUring};
+
+/// Create an `IoUring`, or return `None` if io_uring is unavailable.
+fn new_ring(entries: u32) -> Option<IoUring> {
+    match IoUring::new(entries) {
+        Ok(ring) => Some(ring),
+        // io_uring may be unsupported, or disabled by `kernel.io_uring_disabled`
+        // or a seccomp policy.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) | Err(io::Errno::ACCESS) => None,
+        Err(err) => panic!("failed to create io_uring: {}", err),
+    }
+}
+
+/// Submit `sqes`, wait for them all to complete, and return their
+/// completions in order of `user_data`.
+fn submit(ring: &mut IoUring, sqes: &[io_uring_sqe]) -> Vec<io_uring_cqe> {
+    {
+        let mut sq = ring.submission();
+        for sqe in sqes {
+            unsafe { sq.push(sqe).unwrap() };
+        }
+    }
+    ring.submit_and_wait(sqes.len() as u32).unwrap();
+
+    let mut cqes = ring.completion().collect::<Vec<_>>();
+    assert_eq!(cqes.len(), sqes.len());
+    cqes.sort_by_key(|cqe| cqe.user_data.u64_());
+    cqes
+}
+
+fn user_data(n: u64) -> io_uring_user_data {
+    io_uring_user_data::from_u64(n)
+}
@@ -0,0 +1,396 @@
+//! Tests for the typed `io_uring_sqe` constructors.
+
+use crate::{new_ring, submit, user_data};
+use rustix::fd::{AsFd, BorrowedFd, FromRawFd, OwnedFd};
+use rustix::fs::{cwd, AtFlags, Mode, OFlags, ResolveFlags, StatxFlags};
+use rustix::io;
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::io_uring::{
+    iovec, msghdr, opcode, open_how, FixedFiles, IoringCqeFlags, IoringSqeFlags, Timespec,
+    IORING_CQE_BUFFER_SHIFT, IORING_FILE_INDEX_ALLOC,
+};
+use rustix::net::{
+    AddressFamily, Ipv4Addr, Protocol, SocketAddrAny, SocketAddrV4, SocketFlags, SocketType,
+};
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_opcode_nop() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    let cqes = submit(
+        &mut ring,
+        &[opcode::Nop::new().user_data(user_data(7)).build()],
+    );
+    assert_eq!(cqes[0].user_data.u64_(), 7);
+    assert_eq!(cqes[0].res, 0);
+}
+
+#[test]
+fn test_opcode_read_write() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, writer) = io::pipe().unwrap();
+
+    let msg = b"hello";
+    let write = opcode::Write::new(writer.as_fd(), msg)
+        .user_data(user_data(1))
+        .flags(IoringSqeFlags::IO_LINK)
+        .build();
+    let mut buf = [0_u8; 16];
+    let read = opcode::Read::new(reader.as_fd(), &mut buf)
+        .user_data(user_data(2))
+        .build();
+
+    let cqes = submit(&mut ring, &[write, read]);
+    assert_eq!(cqes[0].res, msg.len() as i32);
+    assert_eq!(cqes[1].res, msg.len() as i32);
+    assert_eq!(&buf[..msg.len()], msg);
+}
+
+#[test]
+fn test_opcode_readv_writev() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let file = tempfile::tempfile().unwrap();
+
+    let bufs = [IoSlice::new(b"hello, "), IoSlice::new(b"world")];
+    let writev = opcode::Writev::new(file.as_fd(), &bufs)
+        .offset(0)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[writev]);
+    assert_eq!(cqes[0].res, 12);
+
+    let mut a = [0_u8; 5];
+    let mut b = [0_u8; 5];
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    let readv = opcode::Readv::new(file.as_fd(), &mut bufs)
+        .offset(2)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[readv]);
+    assert_eq!(cqes[0].res, 10);
+    assert_eq!(&a, b"llo, ");
+    assert_eq!(&b, b"world");
+}
+
+#[test]
+fn test_opcode_splice() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let file = tempfile::tempfile().unwrap();
+    let (reader, writer) = io::pipe().unwrap();
+
+    io::write(&writer, b"spliced").unwrap();
+    let splice = opcode::Splice::new(reader.as_fd(), None, file.as_fd(), Some(0), 7)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[splice]);
+    assert_eq!(cqes[0].res, 7);
+
+    let mut buf = [0_u8; 7];
+    assert_eq!(io::pread(&file, &mut buf, 0).unwrap(), 7);
+    assert_eq!(&buf, b"spliced");
+}
+
+#[test]
+fn test_opcode_openat2_statx_close() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = rustix::fs::openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let how = open_how {
+        flags: (OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC).bits() as u64,
+        mode: Mode::RUSR.bits() as u64,
+        resolve: ResolveFlags::BENEATH,
+    };
+    let path = rustix::cstr!("file");
+    let open = opcode::OpenAt2::new(dir.as_fd(), path, &how)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[open]);
+    assert!(cqes[0].res >= 0, "openat2 failed: {}", cqes[0].res);
+    let fd = unsafe { BorrowedFd::borrow_raw(cqes[0].res) };
+
+    let mut statx = MaybeUninit::uninit();
+    let stat = opcode::Statx::new(
+        dir.as_fd(),
+        path,
+        AtFlags::empty(),
+        StatxFlags::MODE | StatxFlags::SIZE,
+        &mut statx,
+    )
+    .user_data(user_data(2))
+    .build();
+    let close = opcode::Close::new(fd).user_data(user_data(3)).build();
+    let cqes = submit(&mut ring, &[stat, close]);
+    assert_eq!(cqes[0].res, 0);
+    assert_eq!(cqes[1].res, 0);
+
+    let statx = unsafe { statx.assume_init() };
+    assert_eq!(statx.stx_size, 0);
+    assert_eq!(u32::from(statx.stx_mode) & 0o777, Mode::RUSR.bits());
+}
+
+#[test]
+fn test_opcode_timeout() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    let sqe = opcode::Timeout::new(&timeout)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[sqe]);
+    assert_eq!(cqes[0].res, -io::Errno::TIME.raw_os_error());
+
+    // A linked timeout cancels a read that never completes.
+    let (reader, _writer) = io::pipe().unwrap();
+    let mut buf = [0_u8; 1];
+    let read = opcode::Read::new(reader.as_fd(), &mut buf)
+        .flags(IoringSqeFlags::IO_LINK)
+        .user_data(user_data(1))
+        .build();
+    let link_timeout = opcode::LinkTimeout::new(&timeout)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[read, link_timeout]);
+    assert_eq!(cqes[0].res, -io::Errno::CANCELED.raw_os_error());
+    assert_eq!(cqes[1].res, -io::Errno::TIME.raw_os_error());
+}
+
+#[test]
+fn test_opcode_connect_accept() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    rustix::net::listen(&listener, 1).unwrap();
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+
+    let client =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    let mut storage = MaybeUninit::uninit();
+    let connect = opcode::Connect::new(client.as_fd(), &local_addr, &mut storage)
+        .user_data(user_data(1))
+        .build();
+    let mut peer = MaybeUninit::uninit();
+    let mut peer_len = std::mem::size_of_val(&peer) as u32;
+    let accept = opcode::Accept::new(listener.as_fd())
+        .peer_addr(&mut peer, &mut peer_len)
+        .socket_flags(SocketFlags::CLOEXEC)
+        .user_data(user_data(2))
+        .build();
+
+    let cqes = submit(&mut ring, &[connect, accept]);
+    assert_eq!(cqes[0].res, 0);
+    assert!(cqes[1].res >= 0, "accept failed: {}", cqes[1].res);
+    let accepted = unsafe { OwnedFd::from_raw_fd(cqes[1].res) };
+
+    let peer = unsafe { SocketAddrAny::read(peer.as_ptr(), peer_len as usize) }.unwrap();
+    assert_eq!(peer, rustix::net::getsockname(&client).unwrap());
+    drop(accepted);
+}
+
+#[test]
+fn test_opcode_file_index_alloc() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let files = match FixedFiles::sparse(&ring, 4) {
+        Ok(files) => files,
+        // Sparse tables require Linux 5.19.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    rustix::net::listen(&listener, 1).unwrap();
+    let local_addr = match rustix::net::getsockname(&listener).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    let client =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::connect_v4(&client, &local_addr).unwrap();
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = rustix::fs::openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let how = open_how {
+        flags: (OFlags::CREATE | OFlags::WRONLY).bits() as u64,
+        mode: Mode::RUSR.bits() as u64,
+        resolve: ResolveFlags::empty(),
+    };
+
+    // With `IORING_FILE_INDEX_ALLOC`, the results are the allocated slots
+    // rather than file descriptors.
+    let accept = opcode::Accept::new(listener.as_fd())
+        .file_index(IORING_FILE_INDEX_ALLOC as u32)
+        .user_data(user_data(1))
+        .build();
+    let open = opcode::OpenAt2::new(dir.as_fd(), rustix::cstr!("file"), &how)
+        .file_index(IORING_FILE_INDEX_ALLOC as u32)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[accept, open]);
+    let slots = [cqes[0].res, cqes[1].res];
+    for slot in &slots {
+        assert!((0..files.len() as i32).contains(slot), "{:?}", slots);
+    }
+    assert_ne!(slots[0], slots[1]);
+
+    // Closing the slots succeeds only if the files were installed there.
+    let close0 = opcode::Close::fixed(slots[0] as u32)
+        .user_data(user_data(1))
+        .build();
+    let close1 = opcode::Close::fixed(slots[1] as u32)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[close0, close1]);
+    assert_eq!(cqes[0].res, 0);
+    assert_eq!(cqes[1].res, 0);
+}
+
+/// One more than `u32::MAX - 1` is `IORING_FILE_INDEX_ALLOC`, so it can't be
+/// used as a slot.
+#[test]
+#[should_panic(expected = "fixed file index out of range")]
+fn test_opcode_file_index_out_of_range() {
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    let _ = opcode::Accept::new(listener.as_fd()).file_index(u32::MAX - 1);
+}
+
+#[test]
+#[should_panic(expected = "fixed file index out of range")]
+fn test_opcode_close_fixed_out_of_range() {
+    let _ = opcode::Close::fixed(IORING_FILE_INDEX_ALLOC as u32);
+}
+
+#[test]
+fn test_opcode_sendmsg_recvmsg() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (a, b) = rustix::net::socketpair(
+        AddressFamily::UNIX,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    let mut out = *b"datagram";
+    let mut out_iov = iovec {
+        iov_base: out.as_mut_ptr().cast(),
+        iov_len: out.len(),
+    };
+    let send_hdr = msghdr {
+        msg_iov: &mut out_iov,
+        msg_iovlen: 1,
+        ..Default::default()
+    };
+
+    let mut buf = [0_u8; 16];
+    let mut in_iov = iovec {
+        iov_base: buf.as_mut_ptr().cast(),
+        iov_len: buf.len(),
+    };
+    let mut recv_hdr = msghdr {
+        msg_iov: &mut in_iov,
+        msg_iovlen: 1,
+        ..Default::default()
+    };
+
+    let send = opcode::SendMsg::new(a.as_fd(), &send_hdr)
+        .flags(IoringSqeFlags::IO_LINK)
+        .user_data(user_data(1))
+        .build();
+    let recv = opcode::RecvMsg::new(b.as_fd(), &mut recv_hdr)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[send, recv]);
+    assert_eq!(cqes[0].res, out.len() as i32);
+    assert_eq!(cqes[1].res, out.len() as i32);
+    assert_eq!(&buf[..out.len()], &out);
+}
+
+#[test]
+fn test_opcode_provide_buffers() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, writer) = io::pipe().unwrap();
+
+    let mut bufs = [0_u8; 64];
+    let provide = opcode::ProvideBuffers::new(&mut bufs, 16, 3, 10)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[provide]);
+    assert_eq!(cqes[0].res, 0);
+
+    io::write(&writer, b"selected").unwrap();
+    let read = opcode::Read::buffer_select(reader.as_fd(), 16, 3)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[read]);
+    assert_eq!(cqes[0].res, 8);
+    assert!(cqes[0].flags.contains(IoringCqeFlags::BUFFER));
+
+    let bid = (cqes[0].flags.bits() >> IORING_CQE_BUFFER_SHIFT) as usize;
+    assert!((10..14).contains(&bid));
+    let start = (bid - 10) * 16;
+    assert_eq!(&bufs[start..start + 8], b"selected");
+}
+
+#[test]
+fn test_opcode_msg_ring() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let mut target = new_ring(8).unwrap();
+
+    let msg = opcode::MsgRing::new(target.as_fd(), 77, user_data(99))
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[msg]);
+    if cqes[0].res == -io::Errno::INVAL.raw_os_error() {
+        // `IORING_OP_MSG_RING` requires Linux 5.18.
+        return;
+    }
+    assert_eq!(cqes[0].res, 0);
+
+    let cqes = target.completion().collect::<Vec<_>>();
+    assert_eq!(cqes.len(), 1);
+    assert_eq!(cqes[0].res, 77);
+    assert_eq!(cqes[0].user_data.u64_(), 99);
+}
@@ -0,0 +1,35 @@
+//! Tests for `io_uring_probe` and `IoringFeatureFlags`.
+
+use crate::new_ring;
+use rustix::io;
+use rustix::io_uring::{io_uring_probe, IoringFeatureFlags, IoringOp};
+
+#[test]
+fn test_io_uring_probe() {
+    let ring = match new_ring(1) {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    let probe = match io_uring_probe(&ring) {
+        Ok(probe) => probe,
+        // `IORING_REGISTER_PROBE` requires Linux 5.6.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("failed to probe io_uring: {}", err),
+    };
+
+    // Every kernel with `IORING_REGISTER_PROBE` supports these.
+    assert!(probe.is_supported(IoringOp::Nop));
+    assert!(probe.is_supported(IoringOp::Readv));
+    assert!(probe.is_supported(IoringOp::Openat2));
+    assert!(u32::from(probe.last_op()) >= IoringOp::Openat2 as u32);
+
+    // `IORING_REGISTER_PROBE` and `IORING_FEAT_NODROP` both arrived in 5.6.
+    assert!(ring.params().features.contains(IoringFeatureFlags::NODROP));
+}
+
+#[test]
+fn test_io_uring_probe_not_a_ring() {
+    let (reader, _writer) = io::pipe().unwrap();
+    assert!(io_uring_probe(&reader).is_err());
+}
@@ -0,0 +1,172 @@
+//! Tests for registered buffers, fixed files, and provided buffer rings.
+
+use crate::{new_ring, submit, user_data};
+use rustix::fd::AsFd;
+use rustix::io;
+use rustix::io::IoSliceMut;
+use rustix::io_uring::{opcode, BufRing, FixedFiles, IoringSqeFlags, RegisteredBuffers};
+
+#[test]
+fn test_registered_buffers() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, writer) = io::pipe().unwrap();
+
+    let mut out = *b"fixed buffers";
+    let mut input = [0_u8; 32];
+    let mut bufs = [IoSliceMut::new(&mut out), IoSliceMut::new(&mut input)];
+    let mut registered = RegisteredBuffers::new(&ring, &mut bufs).unwrap();
+
+    let (out, input) = registered.buffers().split_at_mut(1);
+    let write = opcode::WriteFixed::new(writer.as_fd(), &out[0], 0)
+        .user_data(user_data(1))
+        .flags(IoringSqeFlags::IO_LINK)
+        .build();
+    let read = opcode::ReadFixed::new(reader.as_fd(), &mut input[0], 1)
+        .user_data(user_data(2))
+        .build();
+
+    let cqes = submit(&mut ring, &[write, read]);
+    assert_eq!(cqes[0].res, 13);
+    assert_eq!(cqes[1].res, 13);
+    assert_eq!(&registered.buffers()[1][..13], b"fixed buffers");
+}
+
+#[test]
+fn test_registered_buffers_bad_index() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, _writer) = io::pipe().unwrap();
+
+    let mut buf = [0_u8; 8];
+    let mut bufs = [IoSliceMut::new(&mut buf)];
+    let mut registered = RegisteredBuffers::new(&ring, &mut bufs).unwrap();
+
+    let read = opcode::ReadFixed::new(reader.as_fd(), &mut registered.buffers()[0], 1)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[read]);
+    assert_eq!(cqes[0].res, -io::Errno::FAULT.raw_os_error());
+}
+
+#[test]
+fn test_fixed_files() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, writer) = io::pipe().unwrap();
+
+    let files = FixedFiles::new(&ring, &[reader.as_fd(), writer.as_fd()]).unwrap();
+    assert_eq!(files.len(), 2);
+
+    // The kernel holds its own references, so the originals can be closed.
+    drop(reader);
+    drop(writer);
+
+    let msg = b"fixed files";
+    let write = opcode::Write::new(opcode::Target::Fixed(1), msg)
+        .user_data(user_data(1))
+        .flags(IoringSqeFlags::IO_LINK)
+        .build();
+    let mut buf = [0_u8; 16];
+    let read = opcode::Read::new(opcode::Target::Fixed(0), &mut buf)
+        .user_data(user_data(2))
+        .build();
+
+    let cqes = submit(&mut ring, &[write, read]);
+    assert_eq!(cqes[0].res, msg.len() as i32);
+    assert_eq!(cqes[1].res, msg.len() as i32);
+    assert_eq!(&buf[..msg.len()], msg);
+}
+
+#[test]
+fn test_fixed_files_sparse_update() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let files = match FixedFiles::sparse(&ring, 4) {
+        Ok(files) => files,
+        // Sparse tables require Linux 5.19.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(files.len(), 4);
+
+    let (reader, writer) = io::pipe().unwrap();
+    assert_eq!(
+        files
+            .update(2, &[Some(reader.as_fd()), Some(writer.as_fd())])
+            .unwrap(),
+        2
+    );
+
+    let msg = b"sparse";
+    let write = opcode::Write::new(opcode::Target::Fixed(3), msg)
+        .user_data(user_data(1))
+        .build();
+    let cqes = submit(&mut ring, &[write]);
+    assert_eq!(cqes[0].res, msg.len() as i32);
+
+    // Clear the writer's slot; writing to it now fails.
+    assert_eq!(files.update(3, &[None]).unwrap(), 1);
+    let write = opcode::Write::new(opcode::Target::Fixed(3), msg)
+        .user_data(user_data(2))
+        .build();
+    let cqes = submit(&mut ring, &[write]);
+    assert_eq!(cqes[0].res, -io::Errno::BADF.raw_os_error());
+
+    let mut buf = [0_u8; 8];
+    assert_eq!(io::read(&reader, &mut buf).unwrap(), msg.len());
+    assert_eq!(&buf[..msg.len()], msg);
+}
+
+#[test]
+fn test_buf_ring() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let mut buf_ring = match BufRing::new(&ring, 7, 4, 16) {
+        Ok(buf_ring) => buf_ring,
+        // Provided buffer rings require Linux 5.19.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(buf_ring.bgid(), 7);
+    assert_eq!(buf_ring.entries(), 4);
+    assert_eq!(buf_ring.buf_len(), 16);
+
+    let (reader, writer) = io::pipe().unwrap();
+
+    // Cycle through the ring more than once, to exercise recycling.
+    for i in 0..10_u64 {
+        let msg = format!("message {}", i);
+        io::write(&writer, msg.as_bytes()).unwrap();
+
+        let read = opcode::Read::buffer_select(reader.as_fd(), 16, 7)
+            .user_data(user_data(i))
+            .build();
+        let cqes = submit(&mut ring, &[read]);
+        assert_eq!(cqes[0].res, msg.len() as i32);
+
+        let entry = unsafe { buf_ring.get(&cqes[0]) }.unwrap();
+        assert_eq!(entry.bid(), (i % 4) as u16);
+        assert_eq!(&*entry, msg.as_bytes());
+    }
+}
+
+#[test]
+fn test_buf_ring_invalid_entries() {
+    let ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    assert_eq!(BufRing::new(&ring, 0, 3, 16).unwrap_err(), io::Errno::INVAL);
+    assert_eq!(BufRing::new(&ring, 0, 4, 0).unwrap_err(), io::Errno::INVAL);
+}
@@ -0,0 +1,157 @@
+//! Tests for the `IoUring` type.
+
+use crate::new_ring;
+use rustix::io;
+use rustix::io_uring::{
+    addr_or_splice_off_in_union, io_uring_cqe, io_uring_sqe, io_uring_user_data, len_union,
+    off_or_addr2_union, IoUring, IoringOp,
+};
+use std::ffi::c_void;
+use std::os::unix::io::AsRawFd;
+
+/// Return a `Nop` entry with the given user data.
+fn nop(user_data: u64) -> io_uring_sqe {
+    io_uring_sqe {
+        opcode: IoringOp::Nop,
+        user_data: io_uring_user_data::from_u64(user_data),
+        ..Default::default()
+    }
+}
+
+/// Submit `sqe`, wait for it to complete, and return its completion.
+fn submit_one(ring: &mut IoUring, sqe: &io_uring_sqe) -> io_uring_cqe {
+    unsafe { ring.submission().push(sqe).unwrap() };
+    assert_eq!(ring.submit_and_wait(1).unwrap(), 1);
+
+    let mut cq = ring.completion();
+    assert_eq!(cq.len(), 1);
+    let cqe = cq.next().unwrap();
+    assert!(cq.next().is_none());
+    cqe
+}
+
+#[test]
+fn test_io_uring_nop() {
+    let mut ring = match new_ring(4) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let params = *ring.params();
+    assert!(params.sq_entries >= 4);
+    assert_eq!(ring.submission().capacity(), params.sq_entries as usize);
+    assert_eq!(ring.completion().capacity(), params.cq_entries as usize);
+
+    // Cycle through the rings more than once to exercise wrapping.
+    for i in 0..10_u64 {
+        let cqe = submit_one(&mut ring, &nop(i));
+        assert_eq!(cqe.user_data.u64_(), i);
+        assert_eq!(cqe.res, 0);
+    }
+
+    assert!(ring.submission().is_empty());
+    assert!(ring.completion().is_empty());
+    assert_eq!(ring.submission().dropped(), 0);
+    assert_eq!(ring.completion().overflow(), 0);
+}
+
+#[test]
+fn test_io_uring_full() {
+    let mut ring = match new_ring(2) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let capacity = ring.submission().capacity();
+
+    let sqe = nop(0);
+
+    {
+        let mut sq = ring.submission();
+        for _ in 0..capacity {
+            unsafe { sq.push(&sqe).unwrap() };
+        }
+        assert!(sq.is_full());
+        assert_eq!(unsafe { sq.push(&sqe) }, Err(io::Errno::AGAIN));
+    }
+
+    assert_eq!(
+        ring.submit_and_wait(capacity as u32).unwrap(),
+        capacity as u32
+    );
+    assert_eq!(ring.completion().count(), capacity);
+}
+
+#[test]
+fn test_io_uring_read_write() {
+    let mut ring = match new_ring(8) {
+        Some(ring) => ring,
+        None => return,
+    };
+    let (reader, writer) = io::pipe().unwrap();
+
+    let msg = b"hello, io_uring";
+    let sqe = io_uring_sqe {
+        opcode: IoringOp::Write,
+        fd: writer.as_raw_fd(),
+        addr_or_splice_off_in: addr_or_splice_off_in_union {
+            addr: (msg.as_ptr() as *mut c_void).into(),
+        },
+        len: len_union {
+            len: msg.len() as u32,
+        },
+        off_or_addr2: off_or_addr2_union { off: u64::MAX },
+        user_data: io_uring_user_data::from_u64(1),
+        ..Default::default()
+    };
+
+    let cqe = submit_one(&mut ring, &sqe);
+    assert_eq!(cqe.user_data.u64_(), 1);
+    assert_eq!(cqe.res, msg.len() as i32);
+
+    let mut buf = [0_u8; 32];
+    let sqe = io_uring_sqe {
+        opcode: IoringOp::Read,
+        fd: reader.as_raw_fd(),
+        addr_or_splice_off_in: addr_or_splice_off_in_union {
+            addr: buf.as_mut_ptr().cast::<c_void>().into(),
+        },
+        len: len_union {
+            len: buf.len() as u32,
+        },
+        off_or_addr2: off_or_addr2_union { off: u64::MAX },
+        user_data: io_uring_user_data::from_u64(2),
+        ..Default::default()
+    };
+
+    let cqe = submit_one(&mut ring, &sqe);
+    assert_eq!(cqe.user_data.u64_(), 2);
+    assert_eq!(cqe.res, msg.len() as i32);
+    assert_eq!(&buf[..msg.len()], msg);
+}
+
+#[test]
+fn test_io_uring_split() {
+    let mut ring = match new_ring(4) {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    let sqe = nop(0);
+
+    let (mut sq, cq) = ring.split();
+    unsafe {
+        sq.push(&sqe).unwrap();
+        sq.push(&sqe).unwrap();
+    }
+    assert_eq!(sq.len(), 2);
+    sq.sync();
+    drop(sq);
+    assert!(cq.is_empty());
+    drop(cq);
+
+    ring.submit_and_wait(2).unwrap();
+
+    let (sq, mut cq) = ring.split();
+    assert!(sq.is_empty());
+    assert_eq!(cq.len(), 2);
+    assert!(cq.all(|cqe| cqe.res == 0));
+}
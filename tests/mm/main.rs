@@ -3,9 +3,14 @@
 #![cfg(feature = "mm")]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod mlock;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod mmap;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "process")]
+mod process_madvise;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod prot;
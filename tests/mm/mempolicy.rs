@@ -0,0 +1,146 @@
+use rustix::io::Errno;
+use rustix::mm::{
+    get_mempolicy, get_mempolicy_addr, get_mempolicy_mems_allowed, get_mempolicy_node, mbind,
+    migrate_pages, mmap_anonymous, move_pages, munmap, set_mempolicy, MapFlags, MbindFlags,
+    MemPolicyFlags, MemPolicyMode, MovePagesFlags, NodeMask, ProtFlags,
+};
+use std::ptr::null_mut;
+
+/// Return `false` if NUMA memory policies aren't supported, or are blocked
+/// by a seccomp filter.
+fn numa_supported() -> bool {
+    match get_mempolicy_mems_allowed() {
+        Ok(_) => true,
+        Err(Errno::NOSYS) | Err(Errno::PERM) => false,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+fn node0() -> NodeMask {
+    let mut nodes = NodeMask::new();
+    nodes.set(0);
+    nodes
+}
+
+#[test]
+fn test_node_mask() {
+    let mut nodes = NodeMask::new();
+    assert_eq!(nodes.count(), 0);
+    nodes.set(0);
+    nodes.set(65);
+    nodes.set(NodeMask::MAX_NODE - 1);
+    assert!(nodes.is_set(0));
+    assert!(nodes.is_set(65));
+    assert!(!nodes.is_set(64));
+    assert_eq!(nodes.count(), 3);
+    nodes.unset(65);
+    assert!(!nodes.is_set(65));
+    assert_eq!(nodes.count(), 2);
+    nodes.clear();
+    assert_eq!(nodes, NodeMask::default());
+}
+
+#[test]
+fn test_set_mempolicy() {
+    if !numa_supported() {
+        return;
+    }
+    assert!(get_mempolicy_mems_allowed().unwrap().is_set(0));
+
+    // Memory policies are per-thread, so run this in a new thread to avoid
+    // affecting other tests.
+    std::thread::spawn(|| {
+        set_mempolicy(MemPolicyMode::Bind, MemPolicyFlags::STATIC_NODES, &node0()).unwrap();
+        let policy = get_mempolicy().unwrap();
+        assert_eq!(policy.mode, MemPolicyMode::Bind);
+        assert_eq!(policy.flags, MemPolicyFlags::STATIC_NODES);
+        assert_eq!(policy.nodes, node0());
+
+        set_mempolicy(
+            MemPolicyMode::Default,
+            MemPolicyFlags::empty(),
+            &NodeMask::new(),
+        )
+        .unwrap();
+        let policy = get_mempolicy().unwrap();
+        assert_eq!(policy.mode, MemPolicyMode::Default);
+        assert_eq!(policy.nodes.count(), 0);
+
+        // `MPOL_DEFAULT` doesn't take any nodes.
+        assert_eq!(
+            set_mempolicy(MemPolicyMode::Default, MemPolicyFlags::empty(), &node0()),
+            Err(Errno::INVAL)
+        );
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_mbind_move_pages() {
+    if !numa_supported() {
+        return;
+    }
+
+    unsafe {
+        let len = 2 * 4096;
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        mbind(
+            addr,
+            len,
+            MemPolicyMode::Bind,
+            MemPolicyFlags::empty(),
+            &node0(),
+            MbindFlags::STRICT | MbindFlags::MOVE,
+        )
+        .unwrap();
+        let policy = get_mempolicy_addr(addr).unwrap();
+        assert_eq!(policy.mode, MemPolicyMode::Bind);
+        assert_eq!(policy.nodes, node0());
+
+        addr.cast::<u8>().write_bytes(1, len);
+        assert_eq!(get_mempolicy_node(addr).unwrap(), 0);
+
+        let pages = [addr as usize, addr as usize + 4096];
+        let mut status = [-1; 2];
+        assert_eq!(
+            move_pages(
+                None,
+                &pages,
+                Some(&[0, 0]),
+                &mut status,
+                MovePagesFlags::MOVE
+            )
+            .unwrap(),
+            0
+        );
+        assert_eq!(status, [0, 0]);
+
+        // Query the nodes without moving anything.
+        let mut status = [-1; 2];
+        move_pages(None, &pages, None, &mut status, MovePagesFlags::empty()).unwrap();
+        assert_eq!(status, [0, 0]);
+
+        assert_eq!(
+            move_pages(None, &pages, None, &mut [0], MovePagesFlags::empty()),
+            Err(Errno::INVAL)
+        );
+
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[test]
+fn test_migrate_pages() {
+    if !numa_supported() {
+        return;
+    }
+    assert_eq!(migrate_pages(None, &node0(), &node0()).unwrap(), 0);
+}
@@ -0,0 +1,62 @@
+use rustix::io::Errno;
+use rustix::mm::{
+    mmap_anonymous, munmap, process_madvise, process_mrelease, Advice, MapFlags, MreleaseFlags,
+    ProcessMadviseFlags, ProtFlags,
+};
+use rustix::process::{getpid, pidfd_open, PidfdFlags, RemoteIoVec};
+use std::ptr::null_mut;
+
+#[test]
+fn test_process_madvise() {
+    let pidfd = match pidfd_open(getpid(), PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    unsafe {
+        let len = 4 * 4096;
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+        addr.cast::<u8>().write_bytes(1, len);
+
+        let remote = [RemoteIoVec {
+            base: addr as usize,
+            len,
+        }];
+        match process_madvise(
+            &pidfd,
+            &remote,
+            Advice::LinuxCold,
+            ProcessMadviseFlags::empty(),
+        ) {
+            Ok(n) => assert_eq!(n, len),
+            // `process_madvise` is new in Linux 5.10, and needs
+            // `CAP_SYS_NICE` on older kernels, even for the calling process.
+            Err(Errno::NOSYS) | Err(Errno::PERM) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[test]
+fn test_process_mrelease() {
+    let pidfd = match pidfd_open(getpid(), PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // The process isn't being killed, so its memory can't be released.
+    match process_mrelease(&pidfd, MreleaseFlags::empty()) {
+        Err(Errno::INVAL) | Err(Errno::NOSYS) => {}
+        other => panic!("{:?}", other),
+    }
+}
@@ -8,10 +8,22 @@
 
 mod addr;
 mod connect_bind_send;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mmsg;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod netlink;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(all(feature = "mm", feature = "thread"))]
+mod packet;
 mod poll;
 mod sockopt;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod udp_cmsg;
 #[cfg(unix)]
 mod unix;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod unix_creds;
 mod v4;
 mod v6;
 
@@ -0,0 +1,155 @@
+//! Test `sendmmsg` and `recvmmsg`.
+
+use rustix::fd::OwnedFd;
+use rustix::io::{Errno, IoSlice, IoSliceMut};
+use rustix::net::sockopt::set_ip_pktinfo;
+use rustix::net::{
+    bind_v4, connect_v4, getsockname, recvmmsg, sendmmsg, socket, AddressFamily, Ipv4Addr,
+    MMsgAddr, MMsgHdr, Protocol, RecvAncillaryMessage, RecvFlags, RecvMMsgHdr, SendAncillaryBuffer,
+    SendFlags, SocketAddrAny, SocketAddrV4, SocketType,
+};
+use rustix::time::Timespec;
+
+fn udp_v4() -> (OwnedFd, SocketAddrV4) {
+    let socket = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v4(&socket, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&socket).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    (socket, addr)
+}
+
+#[test]
+fn test_sendmmsg_recvmmsg() {
+    let (receiver, receiver_addr) = udp_v4();
+    let (sender, sender_addr) = udp_v4();
+    let receiver_addr = SocketAddrAny::V4(receiver_addr);
+
+    let payloads: [&[u8]; 3] = [b"one", b"two!", b"three"];
+    let iovs = [
+        [IoSlice::new(payloads[0])],
+        [IoSlice::new(payloads[1])],
+        [IoSlice::new(payloads[2])],
+    ];
+    let mut controls = [
+        SendAncillaryBuffer::default(),
+        SendAncillaryBuffer::default(),
+        SendAncillaryBuffer::default(),
+    ];
+    let mut addrs = [MMsgAddr::new(); 3];
+    let mut msgs = iovs
+        .iter()
+        .zip(controls.iter_mut())
+        .zip(addrs.iter_mut())
+        .map(|((iov, control), addr)| MMsgHdr::new_with_addr(&receiver_addr, addr, iov, control))
+        .collect::<Vec<_>>();
+    assert_eq!(sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(), 3);
+    for (msg, payload) in msgs.iter().zip(payloads.iter()) {
+        assert_eq!(msg.bytes_sent(), payload.len());
+    }
+
+    let mut buffers = [[0_u8; 16]; 4];
+    let lens = {
+        let mut iovs = buffers
+            .iter_mut()
+            .map(|buffer| [IoSliceMut::new(buffer)])
+            .collect::<Vec<_>>();
+        let mut names = [MMsgAddr::new(); 4];
+        let mut msgs = iovs
+            .iter_mut()
+            .zip(names.iter_mut())
+            .map(|(iov, name)| RecvMMsgHdr::new(name, iov, &mut []))
+            .collect::<Vec<_>>();
+
+        // All three messages are already queued, so with `WAITFORONE` this
+        // returns them without waiting for a fourth.
+        assert_eq!(
+            recvmmsg(&receiver, &mut msgs, RecvFlags::WAITFORONE, None).unwrap(),
+            3
+        );
+        for msg in &msgs[..3] {
+            assert_eq!(msg.address(), Some(SocketAddrAny::V4(sender_addr)));
+            assert!(msg.flags().is_empty());
+        }
+
+        // The fourth message wasn't filled in.
+        assert_eq!(msgs[3].bytes_received(), 0);
+        assert_eq!(msgs[3].address(), None);
+        msgs.iter()
+            .take(3)
+            .map(RecvMMsgHdr::bytes_received)
+            .collect::<Vec<_>>()
+    };
+    for ((buffer, len), payload) in buffers.iter().zip(lens).zip(payloads.iter()) {
+        assert_eq!(&buffer[..len], *payload);
+    }
+
+    // Nothing is left.
+    let mut buffer = [0_u8; 16];
+    let mut iov = [IoSliceMut::new(&mut buffer)];
+    let mut name = MMsgAddr::new();
+    let mut msgs = [RecvMMsgHdr::new(&mut name, &mut iov, &mut [])];
+    assert_eq!(
+        recvmmsg(&receiver, &mut msgs, RecvFlags::DONTWAIT, None),
+        Err(Errno::AGAIN)
+    );
+}
+
+#[test]
+fn test_recvmmsg_ancillary() {
+    let (receiver, receiver_addr) = udp_v4();
+    let (sender, _) = udp_v4();
+    set_ip_pktinfo(&receiver, true).unwrap();
+    connect_v4(&sender, &receiver_addr).unwrap();
+
+    // Send on a connected socket, without addresses.
+    let iovs = [[IoSlice::new(b"hello")], [IoSlice::new(b"world")]];
+    let mut controls = [
+        SendAncillaryBuffer::default(),
+        SendAncillaryBuffer::default(),
+    ];
+    let mut msgs = iovs
+        .iter()
+        .zip(controls.iter_mut())
+        .map(|(iov, control)| MMsgHdr::new(iov, control))
+        .collect::<Vec<_>>();
+    assert_eq!(sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(), 2);
+
+    let mut buffers = [[0_u8; 5]; 2];
+    {
+        let (first, second) = buffers.split_at_mut(1);
+        let mut iov0 = [IoSliceMut::new(&mut first[0])];
+        let mut iov1 = [IoSliceMut::new(&mut second[0])];
+        let mut space0 = vec![0; rustix::cmsg_space!(IpPktinfo(1))];
+        let mut space1 = vec![0; rustix::cmsg_space!(IpPktinfo(1))];
+        let mut names = [MMsgAddr::new(); 2];
+        let (name0, name1) = names.split_at_mut(1);
+        let mut msgs = [
+            RecvMMsgHdr::new(&mut name0[0], &mut iov0, &mut space0),
+            RecvMMsgHdr::new(&mut name1[0], &mut iov1, &mut space1),
+        ];
+        let timeout = Timespec {
+            tv_sec: 1,
+            tv_nsec: 0,
+        };
+        assert_eq!(
+            recvmmsg(&receiver, &mut msgs, RecvFlags::empty(), Some(timeout)).unwrap(),
+            2
+        );
+
+        for msg in msgs.iter_mut() {
+            assert_eq!(msg.bytes_received(), 5);
+            match msg.ancillary().drain().next() {
+                Some(RecvAncillaryMessage::IpPktinfo(info)) => {
+                    assert_eq!(info.dst_addr, Ipv4Addr::LOCALHOST);
+                }
+                _ => panic!("expected a packet info message"),
+            }
+
+            // The messages were moved out.
+            assert!(msg.ancillary().drain().next().is_none());
+        }
+    }
+    assert_eq!(&buffers, &[*b"hello", *b"world"]);
+}
@@ -0,0 +1,171 @@
+//! Test netlink sockets.
+
+use rustix::io::Errno;
+use rustix::net::netlink::{
+    self, NetlinkAttrs, NetlinkFlags, NetlinkMessageBuilder, NetlinkMessages,
+};
+use rustix::net::{
+    bind_netlink, getsockname, recv, sendto_netlink, socket, AddressFamily, RecvFlags, SendFlags,
+    SocketAddrAny, SocketAddrNetlink, SocketType,
+};
+
+const RTM_NEWLINK: u16 = 16;
+const RTM_GETLINK: u16 = 18;
+const IFLA_IFNAME: u16 = 3;
+
+/// `struct ifinfomsg` with an `AF_UNSPEC` family and the given index.
+fn ifinfomsg(index: i32) -> [u8; 16] {
+    let mut msg = [0_u8; 16];
+    msg[4..8].copy_from_slice(&index.to_ne_bytes());
+    msg
+}
+
+#[test]
+fn test_netlink_bind_getsockname() {
+    let socket = socket(AddressFamily::NETLINK, SocketType::RAW, netlink::ROUTE).unwrap();
+    bind_netlink(&socket, &SocketAddrNetlink::new(0, 0)).unwrap();
+
+    match getsockname(&socket).unwrap() {
+        SocketAddrAny::Netlink(addr) => {
+            assert_ne!(addr.pid(), 0);
+            assert_eq!(addr.groups(), 0);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_netlink_getlink_dump() {
+    let socket = socket(AddressFamily::NETLINK, SocketType::RAW, netlink::ROUTE).unwrap();
+    let kernel = SocketAddrNetlink::new(0, 0);
+
+    let mut request = Vec::new();
+    let mut builder = NetlinkMessageBuilder::new(
+        &mut request,
+        RTM_GETLINK,
+        NetlinkFlags::REQUEST | NetlinkFlags::DUMP,
+        1,
+    );
+    builder.push_bytes(&ifinfomsg(0));
+    let request = builder.finish();
+    assert_eq!(
+        sendto_netlink(&socket, request, SendFlags::empty(), &kernel).unwrap(),
+        request.len()
+    );
+
+    let mut names = Vec::new();
+    let mut buf = vec![0_u8; 32768];
+    'recv: loop {
+        let n = recv(&socket, &mut buf, RecvFlags::empty()).unwrap();
+        for message in NetlinkMessages::new(&buf[..n]) {
+            let message = message.unwrap();
+            assert_eq!(message.seq(), 1);
+            if message.is_done() {
+                break 'recv;
+            }
+            assert_eq!(message.message_type(), RTM_NEWLINK);
+            assert!(message.flags().contains(NetlinkFlags::MULTI));
+            for attr in message.attrs(16) {
+                let attr = attr.unwrap();
+                if attr.attr_type() == IFLA_IFNAME {
+                    names.push(attr.payload().to_vec());
+                }
+            }
+        }
+    }
+
+    assert!(names.iter().any(|name| name == b"lo\0"), "{:?}", names);
+}
+
+#[test]
+fn test_netlink_error() {
+    let socket = socket(AddressFamily::NETLINK, SocketType::RAW, netlink::ROUTE).unwrap();
+    let kernel = SocketAddrNetlink::new(0, 0);
+
+    // Ask for an interface which doesn't exist.
+    let mut request = Vec::new();
+    let mut builder = NetlinkMessageBuilder::new(
+        &mut request,
+        RTM_GETLINK,
+        NetlinkFlags::REQUEST | NetlinkFlags::ACK,
+        7,
+    );
+    builder.push_bytes(&ifinfomsg(i32::MAX));
+    let request = builder.finish();
+    sendto_netlink(&socket, request, SendFlags::empty(), &kernel).unwrap();
+
+    let mut buf = vec![0_u8; 8192];
+    let n = recv(&socket, &mut buf, RecvFlags::empty()).unwrap();
+    let mut messages = NetlinkMessages::new(&buf[..n]);
+    assert_eq!(messages.next().unwrap().unwrap_err(), Errno::NODEV);
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn test_netlink_builder_roundtrip() {
+    let mut buf = Vec::new();
+
+    let mut builder = NetlinkMessageBuilder::new(&mut buf, 0x20, NetlinkFlags::REQUEST, 3);
+    builder.push_bytes(&[1, 2, 3]);
+    builder.push_attr(1, b"hello");
+    let nested = builder.begin_nested(2);
+    builder.push_attr(3, &42_u32.to_ne_bytes());
+    builder.push_attr(4, &[]);
+    builder.end_nested(nested);
+    builder.finish();
+
+    // A second message in the same buffer.
+    let builder = NetlinkMessageBuilder::new(&mut buf, netlink::MSG_DONE, NetlinkFlags::MULTI, 4);
+    assert_eq!(builder.finish().len(), 16);
+    assert_eq!(buf.len() % 4, 0);
+
+    let mut messages = NetlinkMessages::new(&buf);
+
+    let message = messages.next().unwrap().unwrap();
+    assert_eq!(message.message_type(), 0x20);
+    assert_eq!(message.flags(), NetlinkFlags::REQUEST);
+    assert_eq!(message.seq(), 3);
+    assert_eq!(message.pid(), 0);
+    assert!(!message.is_done());
+    assert_eq!(&message.payload()[..3], &[1, 2, 3]);
+
+    let mut attrs = message.attrs(3);
+    let attr = attrs.next().unwrap().unwrap();
+    assert_eq!(attr.attr_type(), 1);
+    assert!(!attr.is_nested());
+    assert_eq!(attr.payload(), b"hello");
+
+    let attr = attrs.next().unwrap().unwrap();
+    assert_eq!(attr.attr_type(), 2);
+    assert!(attr.is_nested());
+    let mut nested = attr.nested();
+    let inner = nested.next().unwrap().unwrap();
+    assert_eq!(inner.attr_type(), 3);
+    assert_eq!(inner.payload(), &42_u32.to_ne_bytes());
+    let inner = nested.next().unwrap().unwrap();
+    assert_eq!(inner.attr_type(), 4);
+    assert!(inner.payload().is_empty());
+    assert!(nested.next().is_none());
+    assert!(attrs.next().is_none());
+
+    let message = messages.next().unwrap().unwrap();
+    assert!(message.is_done());
+    assert_eq!(message.seq(), 4);
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn test_netlink_malformed() {
+    // A header claiming to be longer than the buffer.
+    let mut buf = Vec::new();
+    NetlinkMessageBuilder::new(&mut buf, netlink::MSG_NOOP, NetlinkFlags::empty(), 0).finish();
+    buf[0] = 0xff;
+    let mut messages = NetlinkMessages::new(&buf);
+    assert_eq!(messages.next().unwrap().unwrap_err(), Errno::INVAL);
+    assert!(messages.next().is_none());
+
+    // A truncated attribute.
+    let mut attrs = NetlinkAttrs::new(&[8, 0, 1]);
+    assert_eq!(attrs.next().unwrap().unwrap_err(), Errno::INVAL);
+    assert!(attrs.next().is_none());
+}
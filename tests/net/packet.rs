@@ -0,0 +1,213 @@
+//! Test `AF_PACKET` sockets, capturing on the loopback interface in a new
+//! network namespace.
+
+use rustix::fd::OwnedFd;
+use rustix::io::{poll, Errno, PollFd, PollFlags};
+use rustix::net::netlink::{self, NetlinkFlags, NetlinkMessageBuilder, NetlinkMessages};
+use rustix::net::packet::{
+    self, PacketFanoutFlags, PacketFanoutMode, PacketMembership, PacketRxRing, PacketType,
+    PacketVersion, TpacketReq3,
+};
+use rustix::net::sockopt::{
+    get_packet_version, set_packet_add_membership, set_packet_drop_membership, set_packet_fanout,
+    set_packet_version,
+};
+use rustix::net::{
+    bind_link, bind_v4, getsockname, recvfrom, sendto_netlink, sendto_v4, socket, AddressFamily,
+    Ipv4Addr, Protocol, RecvFlags, SendFlags, SocketAddrAny, SocketAddrLink, SocketAddrNetlink,
+    SocketAddrV4, SocketType,
+};
+use rustix::thread::{unshare, UnshareFlags};
+
+const RTM_NEWLINK: u16 = 16;
+const IFF_UP: u32 = 1;
+const LOOPBACK_IFINDEX: u32 = 1;
+
+/// Move the current thread into a new network namespace, and bring up its
+/// loopback interface. Return `false` if we don't have permission.
+fn enter_new_netns() -> bool {
+    match unshare(UnshareFlags::NEWNET) {
+        Ok(()) => {}
+        Err(Errno::PERM) => return false,
+        Err(err) => panic!("unshare: {:?}", err),
+    }
+
+    let socket = socket(AddressFamily::NETLINK, SocketType::RAW, netlink::ROUTE).unwrap();
+    let mut ifinfomsg = [0_u8; 16];
+    ifinfomsg[4..8].copy_from_slice(&(LOOPBACK_IFINDEX as i32).to_ne_bytes());
+    ifinfomsg[8..12].copy_from_slice(&IFF_UP.to_ne_bytes());
+    ifinfomsg[12..16].copy_from_slice(&IFF_UP.to_ne_bytes());
+
+    let mut request = Vec::new();
+    let mut builder = NetlinkMessageBuilder::new(
+        &mut request,
+        RTM_NEWLINK,
+        NetlinkFlags::REQUEST | NetlinkFlags::ACK,
+        1,
+    );
+    builder.push_bytes(&ifinfomsg);
+    let request = builder.finish();
+    let kernel = SocketAddrNetlink::new(0, 0);
+    sendto_netlink(&socket, request, SendFlags::empty(), &kernel).unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let n = rustix::net::recv(&socket, &mut buf, RecvFlags::empty()).unwrap();
+    let ack = NetlinkMessages::new(&buf[..n]).next().unwrap().unwrap();
+    assert!(ack.is_ack());
+    true
+}
+
+fn packet_socket() -> OwnedFd {
+    let socket = socket(AddressFamily::PACKET, SocketType::RAW, packet::ALL).unwrap();
+    bind_link(&socket, &SocketAddrLink::new(packet::ALL, LOOPBACK_IFINDEX)).unwrap();
+    socket
+}
+
+/// Send a UDP datagram over the loopback interface.
+fn send_udp(payload: &[u8]) {
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v4(&receiver, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    sendto_v4(&sender, payload, SendFlags::empty(), &addr).unwrap();
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[test]
+fn test_packet_addr() {
+    if !enter_new_netns() {
+        return;
+    }
+    let socket = packet_socket();
+
+    match getsockname(&socket).unwrap() {
+        SocketAddrAny::Link(addr) => {
+            assert_eq!(addr.protocol(), packet::ALL);
+            assert_eq!(addr.ifindex(), LOOPBACK_IFINDEX);
+            // `ARPHRD_LOOPBACK`, with an all-zeros Ethernet address.
+            assert_eq!(addr.hatype(), 772);
+            assert_eq!(addr.addr(), &[0; 6]);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    send_udp(b"packet addr test");
+
+    // Loopback packets are seen once going out and once coming in.
+    let mut pkttypes = Vec::new();
+    let mut buf = [0_u8; 2048];
+    while pkttypes.len() < 2 {
+        let (n, addr) = recvfrom(&socket, &mut buf, RecvFlags::empty()).unwrap();
+        if !contains(&buf[..n], b"packet addr test") {
+            continue;
+        }
+        let addr = match addr {
+            Some(SocketAddrAny::Link(addr)) => addr,
+            other => panic!("unexpected address {:?}", other),
+        };
+        assert_eq!(addr.protocol(), packet::IP);
+        assert_eq!(addr.ifindex(), LOOPBACK_IFINDEX);
+        pkttypes.push(addr.pkttype());
+    }
+    pkttypes.sort_by_key(|pkttype| pkttype.as_raw());
+    assert_eq!(pkttypes, [PacketType::HOST, PacketType::OUTGOING]);
+}
+
+#[test]
+fn test_packet_addr_set_addr() {
+    let mut addr = SocketAddrLink::new(packet::IPV6, 3);
+    assert!(addr.addr().is_empty());
+    addr.set_addr(&[1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(addr.addr(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(addr.set_addr(&[0; 9]), Err(Errno::INVAL));
+    addr.set_addr(&[7]).unwrap();
+    assert_eq!(addr.addr(), &[7]);
+    assert_eq!(addr.protocol(), packet::IPV6);
+    assert_eq!(addr.ifindex(), 3);
+}
+
+#[test]
+fn test_packet_sockopts() {
+    if !enter_new_netns() {
+        return;
+    }
+    let socket = packet_socket();
+
+    assert_eq!(get_packet_version(&socket).unwrap(), PacketVersion::V1);
+    set_packet_version(&socket, PacketVersion::V2).unwrap();
+    assert_eq!(get_packet_version(&socket).unwrap(), PacketVersion::V2);
+
+    set_packet_add_membership(&socket, LOOPBACK_IFINDEX, PacketMembership::Promisc, &[]).unwrap();
+    set_packet_drop_membership(&socket, LOOPBACK_IFINDEX, PacketMembership::Promisc, &[]).unwrap();
+    assert_eq!(
+        set_packet_add_membership(
+            &socket,
+            LOOPBACK_IFINDEX,
+            PacketMembership::Multicast,
+            &[0; 9]
+        ),
+        Err(Errno::INVAL)
+    );
+
+    let other = packet_socket();
+    set_packet_fanout(
+        &socket,
+        7,
+        PacketFanoutMode::Hash,
+        PacketFanoutFlags::DEFRAG,
+    )
+    .unwrap();
+    set_packet_fanout(&other, 7, PacketFanoutMode::Hash, PacketFanoutFlags::DEFRAG).unwrap();
+}
+
+#[test]
+fn test_packet_rx_ring() {
+    if !enter_new_netns() {
+        return;
+    }
+    let socket = packet_socket();
+
+    let req = TpacketReq3 {
+        block_size: 1 << 16,
+        block_nr: 4,
+        frame_size: 1 << 11,
+        frame_nr: (1 << 16) / (1 << 11) * 4,
+        retire_blk_tov: 10,
+        ..TpacketReq3::default()
+    };
+    let mut ring = PacketRxRing::new(&socket, &req).unwrap();
+    assert_eq!(get_packet_version(&socket).unwrap(), PacketVersion::V3);
+    assert!(ring.next_block().is_none());
+
+    send_udp(b"packet ring test");
+
+    let mut found = 0;
+    while found < 2 {
+        let mut fds = [PollFd::new(&socket, PollFlags::IN)];
+        assert_eq!(poll(&mut fds, 5000).unwrap(), 1, "timed out");
+
+        while let Some(block) = ring.next_block() {
+            assert_eq!(block.packets().count(), block.num_packets() as usize);
+            for packet in block.packets() {
+                if !contains(packet.data(), b"packet ring test") {
+                    continue;
+                }
+                assert_eq!(packet.len(), packet.data().len());
+                assert!(!packet.is_truncated());
+                // An Ethernet header, then an IPv4 header.
+                assert_eq!(packet.network_offset(), 14);
+                assert_eq!(packet.data()[14] >> 4, 4);
+                assert!(packet.vlan().is_none());
+                found += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! Test UDP and IP ancillary messages.
+
+use rustix::fd::OwnedFd;
+use rustix::io::{Errno, IoSlice, IoSliceMut};
+use rustix::net::sockopt::{
+    get_ip_pktinfo, get_ip_recvtos, get_ip_recvttl, get_ip_ttl, get_ipv6_recvhoplimit,
+    get_ipv6_recvpktinfo, get_ipv6_recvtclass, get_socket_timestamping, get_socket_timestampns,
+    get_udp_gro, get_udp_segment, set_ip_pktinfo, set_ip_recvtos, set_ip_recvttl,
+    set_ipv6_recvhoplimit, set_ipv6_recvpktinfo, set_ipv6_recvtclass, set_socket_timestamping,
+    set_socket_timestampns, set_udp_gro, set_udp_segment, TimestampingFlags,
+};
+use rustix::net::{
+    bind_v4, bind_v6, getsockname, recvmsg, sendmsg_v4, sendmsg_v6, socket, AddressFamily,
+    Ipv4Addr, Ipv4PacketInfo, Ipv6Addr, Ipv6PacketInfo, Protocol, RecvAncillaryBuffer,
+    RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer, SendAncillaryMessage, SendFlags,
+    SocketAddrAny, SocketAddrV4, SocketAddrV6, SocketType,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn udp_v4() -> (OwnedFd, SocketAddrV4) {
+    let socket = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v4(&socket, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&socket).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    (socket, addr)
+}
+
+fn udp_v6() -> (OwnedFd, SocketAddrV6) {
+    let socket = socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v6(&socket, &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)).unwrap();
+    let addr = match getsockname(&socket).unwrap() {
+        SocketAddrAny::V6(addr) => addr,
+        _ => panic!(),
+    };
+    (socket, addr)
+}
+
+/// Check that a timestamp is within a minute of now.
+fn assert_recent(timestamp: Duration) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let diff = if now > timestamp {
+        now - timestamp
+    } else {
+        timestamp - now
+    };
+    assert!(
+        diff < Duration::from_secs(60),
+        "{:?} vs {:?}",
+        timestamp,
+        now
+    );
+}
+
+#[test]
+fn test_udp_v4_cmsgs() {
+    let (receiver, receiver_addr) = udp_v4();
+    let (sender, _) = udp_v4();
+
+    set_ip_pktinfo(&receiver, true).unwrap();
+    set_ip_recvttl(&receiver, true).unwrap();
+    set_ip_recvtos(&receiver, true).unwrap();
+    set_socket_timestampns(&receiver, true).unwrap();
+    assert!(get_ip_pktinfo(&receiver).unwrap());
+    assert!(get_ip_recvttl(&receiver).unwrap());
+    assert!(get_ip_recvtos(&receiver).unwrap());
+    assert!(get_socket_timestampns(&receiver).unwrap());
+
+    // Select the source address explicitly.
+    let msg = SendAncillaryMessage::IpPktinfo(Ipv4PacketInfo {
+        ifindex: 0,
+        local_addr: Ipv4Addr::LOCALHOST,
+        dst_addr: Ipv4Addr::UNSPECIFIED,
+    });
+    let mut space = vec![0; msg.size()];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(msg));
+    sendmsg_v4(
+        &sender,
+        &receiver_addr,
+        &[IoSlice::new(b"hello")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buffer = [0; 5];
+    let mut space =
+        vec![0; rustix::cmsg_space!((IpPktinfo(1), IpTtl(1), IpTos(1), ScmTimestampns(1)))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &receiver,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buffer[..result.bytes], b"hello");
+
+    let (mut pktinfo, mut ttl, mut tos, mut timestamp) = (None, None, None, None);
+    for msg in cmsg_buffer.drain() {
+        match msg {
+            RecvAncillaryMessage::IpPktinfo(info) => pktinfo = Some(info),
+            RecvAncillaryMessage::IpTtl(value) => ttl = Some(value),
+            RecvAncillaryMessage::IpTos(value) => tos = Some(value),
+            RecvAncillaryMessage::ScmTimestampns(value) => timestamp = Some(value),
+            _ => panic!("unexpected message"),
+        }
+    }
+
+    let pktinfo = pktinfo.unwrap();
+    assert_ne!(pktinfo.ifindex, 0);
+    assert_eq!(pktinfo.local_addr, Ipv4Addr::LOCALHOST);
+    assert_eq!(pktinfo.dst_addr, Ipv4Addr::LOCALHOST);
+    assert_eq!(u32::from(ttl.unwrap()), get_ip_ttl(&sender).unwrap());
+    assert_eq!(tos.unwrap(), 0);
+    assert_recent(timestamp.unwrap());
+}
+
+#[test]
+fn test_udp_v6_cmsgs() {
+    let (receiver, receiver_addr) = udp_v6();
+    let (sender, _) = udp_v6();
+
+    set_ipv6_recvpktinfo(&receiver, true).unwrap();
+    set_ipv6_recvhoplimit(&receiver, true).unwrap();
+    set_ipv6_recvtclass(&receiver, true).unwrap();
+    assert!(get_ipv6_recvpktinfo(&receiver).unwrap());
+    assert!(get_ipv6_recvhoplimit(&receiver).unwrap());
+    assert!(get_ipv6_recvtclass(&receiver).unwrap());
+
+    let msg = SendAncillaryMessage::Ipv6Pktinfo(Ipv6PacketInfo {
+        addr: Ipv6Addr::LOCALHOST,
+        ifindex: 0,
+    });
+    let mut space = vec![0; msg.size()];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(msg));
+    sendmsg_v6(
+        &sender,
+        &receiver_addr,
+        &[IoSlice::new(b"hello")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buffer = [0; 5];
+    let mut space = vec![0; rustix::cmsg_space!((Ipv6Pktinfo(1), Ipv6HopLimit(1), Ipv6Tclass(1)))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &receiver,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buffer[..result.bytes], b"hello");
+
+    let (mut pktinfo, mut hop_limit, mut tclass) = (None, None, None);
+    for msg in cmsg_buffer.drain() {
+        match msg {
+            RecvAncillaryMessage::Ipv6Pktinfo(info) => pktinfo = Some(info),
+            RecvAncillaryMessage::Ipv6HopLimit(value) => hop_limit = Some(value),
+            RecvAncillaryMessage::Ipv6Tclass(value) => tclass = Some(value),
+            _ => panic!("unexpected message"),
+        }
+    }
+
+    let pktinfo = pktinfo.unwrap();
+    assert_ne!(pktinfo.ifindex, 0);
+    assert_eq!(pktinfo.addr, Ipv6Addr::LOCALHOST);
+    assert_ne!(hop_limit.unwrap(), 0);
+    assert_eq!(tclass.unwrap(), 0);
+}
+
+#[test]
+fn test_udp_timestamping() {
+    let (receiver, receiver_addr) = udp_v4();
+    let (sender, _) = udp_v4();
+
+    let flags = TimestampingFlags::RX_SOFTWARE | TimestampingFlags::SOFTWARE;
+    set_socket_timestamping(&receiver, flags).unwrap();
+    assert_eq!(get_socket_timestamping(&receiver).unwrap(), flags);
+
+    sendmsg_v4(
+        &sender,
+        &receiver_addr,
+        &[IoSlice::new(b"hello")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buffer = [0; 5];
+    let mut space = vec![0; rustix::cmsg_space!(ScmTimestamping(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    recvmsg(
+        &receiver,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+
+    match cmsg_buffer.drain().next() {
+        Some(RecvAncillaryMessage::ScmTimestamping(timestamps)) => {
+            assert_recent(timestamps[0]);
+            assert_eq!(timestamps[2], Duration::from_secs(0));
+        }
+        _ => panic!("expected a timestamping message"),
+    };
+}
+
+#[test]
+fn test_udp_gso_gro() {
+    let (receiver, receiver_addr) = udp_v4();
+    let (sender, _) = udp_v4();
+
+    match set_udp_gro(&receiver, true) {
+        Err(Errno::NOPROTOOPT) => return, // Linux < 5.0
+        otherwise => otherwise.unwrap(),
+    }
+    assert!(get_udp_gro(&receiver).unwrap());
+
+    set_udp_segment(&sender, 100).unwrap();
+    assert_eq!(get_udp_segment(&sender).unwrap(), 100);
+    set_udp_segment(&sender, 0).unwrap();
+    assert_eq!(get_udp_segment(&sender).unwrap(), 0);
+
+    // Send three segments in one call.
+    let data = [0xa5_u8; 300];
+    let msg = SendAncillaryMessage::UdpSegment(100);
+    let mut space = vec![0; msg.size()];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(msg));
+    assert_eq!(
+        sendmsg_v4(
+            &sender,
+            &receiver_addr,
+            &[IoSlice::new(&data)],
+            &mut cmsg_buffer,
+            SendFlags::empty(),
+        )
+        .unwrap(),
+        300
+    );
+
+    // The segments may or may not be coalesced, but when they are, the
+    // segment size is reported.
+    let mut total = 0;
+    while total < data.len() {
+        let mut buffer = [0; 300];
+        let mut space = vec![0; rustix::cmsg_space!(UdpGro(1))];
+        let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+        let result = recvmsg(
+            &receiver,
+            &mut [IoSliceMut::new(&mut buffer)],
+            &mut cmsg_buffer,
+            RecvFlags::empty(),
+        )
+        .unwrap();
+        assert_eq!(result.bytes % 100, 0);
+        assert_eq!(&buffer[..result.bytes], &data[..result.bytes]);
+        for msg in cmsg_buffer.drain() {
+            match msg {
+                RecvAncillaryMessage::UdpGro(size) => assert_eq!(size, 100),
+                _ => panic!("unexpected message"),
+            }
+        }
+        total += result.bytes;
+    }
+    assert_eq!(total, data.len());
+}
+
+#[test]
+fn test_cmsg_space_combo() {
+    assert_eq!(
+        rustix::cmsg_space!((IpPktinfo(2), IpTtl(1),)),
+        rustix::cmsg_space!(IpPktinfo(2)) + rustix::cmsg_space!(IpTtl(1))
+    );
+    assert_eq!(
+        rustix::cmsg_space!((ScmTimestampns(1))),
+        rustix::cmsg_space!(ScmTimestampns(1))
+    );
+}
@@ -0,0 +1,151 @@
+//! Test passing process credentials over Unix-domain sockets.
+
+use rustix::fd::{AsRawFd, OwnedFd};
+use rustix::io::{Errno, IoSlice, IoSliceMut};
+use rustix::net::sockopt::{
+    get_socket_passpidfd, get_socket_peercred, get_socket_peerpidfd, set_socket_passcred,
+    set_socket_passpidfd,
+};
+use rustix::net::{
+    recvmsg, sendmsg_noaddr, socketpair, AddressFamily, Protocol, RecvAncillaryBuffer,
+    RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer, SendAncillaryMessage, SendFlags,
+    SocketFlags, SocketType, UCred,
+};
+use rustix::process::{getgid, getpid, getuid};
+
+fn our_creds() -> UCred {
+    UCred {
+        pid: Some(getpid()),
+        uid: getuid(),
+        gid: getgid(),
+    }
+}
+
+/// Return the PID that a pidfd refers to, from its fdinfo.
+fn pidfd_pid(pidfd: &OwnedFd) -> i32 {
+    let fdinfo =
+        std::fs::read_to_string(format!("/proc/self/fdinfo/{}", pidfd.as_raw_fd())).unwrap();
+    let line = fdinfo
+        .lines()
+        .find(|line| line.starts_with("Pid:"))
+        .unwrap();
+    line["Pid:".len()..].trim().parse().unwrap()
+}
+
+fn pair() -> (OwnedFd, OwnedFd) {
+    socketpair(
+        AddressFamily::UNIX,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_unix_msg_with_scm_credentials() {
+    let (sender, receiver) = pair();
+    set_socket_passcred(&receiver, true).unwrap();
+
+    let msg = SendAncillaryMessage::ScmCredentials(our_creds());
+    let mut space = vec![0; msg.size()];
+    let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+    assert!(cmsg_buffer.push(msg));
+    sendmsg_noaddr(
+        &sender,
+        &[IoSlice::new(b"hello")],
+        &mut cmsg_buffer,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    // With `SO_PASSCRED`, the kernel sends credentials even if the sender
+    // doesn't.
+    sendmsg_noaddr(
+        &sender,
+        &[IoSlice::new(b"world")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    for expected in [b"hello", b"world"].iter() {
+        let mut buffer = [0; 5];
+        let mut space = vec![0; rustix::cmsg_space!(ScmCredentials(1))];
+        let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+        let result = recvmsg(
+            &receiver,
+            &mut [IoSliceMut::new(&mut buffer)],
+            &mut cmsg_buffer,
+            RecvFlags::empty(),
+        )
+        .unwrap();
+        assert_eq!(&buffer[..result.bytes], *expected);
+
+        let creds: Vec<UCred> = cmsg_buffer
+            .drain()
+            .filter_map(|msg| match msg {
+                RecvAncillaryMessage::ScmCredentials(ucred) => Some(ucred),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(creds, [our_creds()]);
+    }
+}
+
+#[test]
+fn test_unix_msg_with_scm_pidfd() {
+    let (sender, receiver) = pair();
+    match set_socket_passpidfd(&receiver, true) {
+        Ok(()) => {}
+        // `SO_PASSPIDFD` is new in Linux 6.5.
+        Err(Errno::NOPROTOOPT) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert!(get_socket_passpidfd(&receiver).unwrap());
+
+    sendmsg_noaddr(
+        &sender,
+        &[IoSlice::new(b"hello")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buffer = [0; 5];
+    let mut space = vec![0; rustix::cmsg_space!(ScmPidfd(1))];
+    let mut cmsg_buffer = RecvAncillaryBuffer::new(&mut space);
+    recvmsg(
+        &receiver,
+        &mut [IoSliceMut::new(&mut buffer)],
+        &mut cmsg_buffer,
+        RecvFlags::CMSG_CLOEXEC,
+    )
+    .unwrap();
+
+    let pidfds: Vec<OwnedFd> = cmsg_buffer
+        .drain()
+        .filter_map(|msg| match msg {
+            RecvAncillaryMessage::ScmPidfd(pidfd) => Some(pidfd),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(pidfds.len(), 1);
+    assert_eq!(
+        pidfd_pid(&pidfds[0]),
+        getpid().as_raw_nonzero().get() as i32
+    );
+}
+
+#[test]
+fn test_unix_peercred() {
+    let (a, _b) = pair();
+    assert_eq!(get_socket_peercred(&a).unwrap(), our_creds());
+
+    match get_socket_peerpidfd(&a) {
+        Ok(pidfd) => assert_eq!(pidfd_pid(&pidfd), getpid().as_raw_nonzero().get() as i32),
+        // `SO_PEERPIDFD` is new in Linux 6.5.
+        Err(Errno::NOPROTOOPT) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
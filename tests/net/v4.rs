@@ -193,3 +193,34 @@ fn test_v4_msg() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+/// `sendmsg_v4` must pass the full `sockaddr_in` length, which is larger
+/// than `SocketAddrV4`, or the kernel rejects the address.
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_v4_sendmsg_addr() {
+    use rustix::io::IoSlice;
+    use rustix::net::sendmsg_v4;
+
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&receiver, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    let nsent = sendmsg_v4(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"hello")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(nsent, 5);
+
+    let mut buffer = [0; BUFFER_SIZE];
+    let nread = recv(&receiver, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..nread], b"hello");
+}
@@ -209,3 +209,34 @@ fn test_v6_msg() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+/// `sendmsg_v6` must pass the `sockaddr_in6` length, which may differ from
+/// the size of `SocketAddrV6`.
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_v6_sendmsg_addr() {
+    use rustix::io::IoSlice;
+    use rustix::net::sendmsg_v6;
+
+    let receiver = socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v6(&receiver, &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V6(addr) => addr,
+        _ => panic!(),
+    };
+
+    let sender = socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()).unwrap();
+    let nsent = sendmsg_v6(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"hello")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(nsent, 5);
+
+    let mut buffer = [0; BUFFER_SIZE];
+    let nread = recv(&receiver, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..nread], b"hello");
+}
@@ -0,0 +1,80 @@
+//! Tests for `clone3`.
+
+use rustix::fd::AsFd;
+use rustix::io;
+use rustix::process::{self, clone3, CloneArgs, CloneFlags, CloneResult};
+use serial_test::serial;
+
+/// Call `clone3`, with a child that immediately exits with the status
+/// returned by `child`. Returns `None` if `clone3` is unavailable.
+fn spawn(
+    args: &CloneArgs<'_>,
+    child: fn() -> i32,
+) -> Option<(process::Pid, Option<rustix::fd::OwnedFd>)> {
+    match unsafe { clone3(args) } {
+        Ok(CloneResult::Child) => unsafe { libc::_exit(child()) },
+        Ok(CloneResult::Parent { pid, pidfd }) => Some((pid, pidfd)),
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => None,
+        Err(err) => panic!("clone3 failed: {:?}", err),
+    }
+}
+
+#[test]
+#[serial]
+fn test_clone3() {
+    let (pid, pidfd) = match spawn(&CloneArgs::new(CloneFlags::empty()), || 42) {
+        Some(child) => child,
+        None => return,
+    };
+    assert!(pidfd.is_none());
+
+    let status = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.exit_status(), Some(42));
+}
+
+#[test]
+#[serial]
+fn test_clone3_pidfd() {
+    let (_pid, pidfd) = match spawn(&CloneArgs::new(CloneFlags::PIDFD), || 7) {
+        Some(child) => child,
+        None => return,
+    };
+    let pidfd = pidfd.unwrap();
+
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitidOptions::EXITED,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(status.exit_status(), Some(7));
+}
+
+#[test]
+#[serial]
+fn test_clone3_newuser() {
+    let args = CloneArgs::new(CloneFlags::NEWUSER | CloneFlags::PIDFD);
+    let (_pid, pidfd) = match unsafe { clone3(&args) } {
+        // Until a mapping is written, the child's user ID is the overflow
+        // user ID.
+        Ok(CloneResult::Child) => unsafe { libc::_exit((libc::getuid() == 65534) as i32) },
+        Ok(CloneResult::Parent { pid, pidfd }) => (pid, pidfd),
+        // User namespaces may be unavailable or disabled.
+        Err(io::Errno::NOSYS)
+        | Err(io::Errno::PERM)
+        | Err(io::Errno::INVAL)
+        | Err(io::Errno::NOSPC)
+        | Err(io::Errno::USERS) => return,
+        Err(err) => panic!("clone3 failed: {:?}", err),
+    };
+
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.unwrap().as_fd()),
+        process::WaitidOptions::EXITED,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(status.exit_status(), Some(1));
+}
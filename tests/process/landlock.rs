@@ -0,0 +1,109 @@
+use rustix::fd::AsFd;
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::io::Errno;
+use rustix::process::landlock::*;
+use rustix::thread::set_no_new_privs;
+
+/// Return the supported ABI version, or `None` if Landlock isn't available.
+fn abi_version() -> Option<u32> {
+    match landlock_abi_version() {
+        Ok(abi) => {
+            assert!(abi >= 1);
+            Some(abi)
+        }
+        Err(Errno::NOSYS) | Err(Errno::OPNOTSUPP) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_landlock_access_from_abi() {
+    assert_eq!(AccessFs::from_abi(0), AccessFs::empty());
+    assert!(AccessFs::from_abi(1).contains(AccessFs::MAKE_SYM));
+    assert!(!AccessFs::from_abi(1).contains(AccessFs::REFER));
+    assert!(AccessFs::from_abi(3).contains(AccessFs::TRUNCATE));
+    assert_eq!(AccessFs::from_abi(5), AccessFs::all());
+    assert_eq!(AccessNet::from_abi(3), AccessNet::empty());
+    assert_eq!(AccessNet::from_abi(4), AccessNet::all());
+}
+
+#[test]
+fn test_landlock_restrict_self() {
+    let abi = match abi_version() {
+        Some(abi) => abi,
+        None => return,
+    };
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("file"), b"data").unwrap();
+
+    // Rulesets can't be removed, so enforce it on a thread of its own.
+    std::thread::spawn(move || {
+        let ruleset = landlock_create_ruleset(&RulesetAttr {
+            handled_access_fs: AccessFs::from_abi(abi),
+            handled_access_net: AccessNet::empty(),
+        })
+        .unwrap();
+
+        let parent = openat(
+            cwd(),
+            dir.path(),
+            OFlags::PATH | OFlags::CLOEXEC,
+            Mode::empty(),
+        )
+        .unwrap();
+        landlock_add_rule(
+            &ruleset,
+            &Rule::PathBeneath {
+                allowed_access: AccessFs::READ_FILE,
+                parent: parent.as_fd(),
+            },
+        )
+        .unwrap();
+
+        // Rules must allow some access.
+        assert_eq!(
+            landlock_add_rule(
+                &ruleset,
+                &Rule::PathBeneath {
+                    allowed_access: AccessFs::empty(),
+                    parent: parent.as_fd(),
+                },
+            )
+            .unwrap_err(),
+            Errno::NOMSG
+        );
+
+        set_no_new_privs(true).unwrap();
+        landlock_restrict_self(&ruleset).unwrap();
+
+        openat(&parent, "file", OFlags::RDONLY, Mode::empty()).unwrap();
+        assert_eq!(
+            openat(cwd(), "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap_err(),
+            Errno::ACCESS
+        );
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_landlock_net_port() {
+    match abi_version() {
+        Some(abi) if abi >= 4 => {}
+        _ => return,
+    }
+
+    let ruleset = landlock_create_ruleset(&RulesetAttr {
+        handled_access_fs: AccessFs::empty(),
+        handled_access_net: AccessNet::BIND_TCP | AccessNet::CONNECT_TCP,
+    })
+    .unwrap();
+    landlock_add_rule(
+        &ruleset,
+        &Rule::NetPort {
+            allowed_access: AccessNet::CONNECT_TCP,
+            port: 443,
+        },
+    )
+    .unwrap();
+}
@@ -5,10 +5,15 @@
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod clone;
 mod cpu_set;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(all(feature = "fs", feature = "thread"))]
+mod landlock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
 #[cfg(target_os = "linux")]
 mod pidfd;
@@ -18,9 +23,20 @@ mod prctl;
 mod priority;
 #[cfg(target_os = "freebsd")]
 mod procctl;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod process_vm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 mod sched_yield;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "thread")]
+mod seccomp;
+#[cfg(not(target_os = "wasi"))]
+mod signal;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod signalfd;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
 mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
@@ -1,7 +1,7 @@
 //! Tests for the `pidfd` type.
 
 use libc::{kill, SIGSTOP};
-use rustix::fd::AsFd;
+use rustix::fd::{AsFd, AsRawFd};
 use rustix::{io, process};
 use serial_test::serial;
 use std::process::Command;
@@ -10,7 +10,7 @@ use std::process::Command;
 #[serial]
 fn test_pidfd_waitid() {
     // Create a new process.
-    let child = Command::new("yes")
+    let mut child = Command::new("yes")
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
         .spawn()
@@ -22,6 +22,8 @@ fn test_pidfd_waitid() {
         Ok(pidfd) => pidfd,
         Err(e) if e == rustix::io::Errno::NOSYS => {
             // The kernel does not support pidfds.
+            child.kill().unwrap();
+            child.wait().unwrap();
             return;
         }
         Err(e) => panic!("failed to open pidfd: {}", e),
@@ -39,13 +41,16 @@ fn test_pidfd_waitid() {
 
     // TODO
     let _ = status;
+
+    child.kill().unwrap();
+    child.wait().unwrap();
 }
 
 #[test]
 #[serial]
 fn test_pidfd_poll() {
     // Create a new process.
-    let child = Command::new("sleep")
+    let mut child = Command::new("sleep")
         .arg("1")
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
@@ -58,6 +63,8 @@ fn test_pidfd_poll() {
         Ok(pidfd) => pidfd,
         Err(e) if e == rustix::io::Errno::NOSYS || e == rustix::io::Errno::INVAL => {
             // The kernel does not support non-blocking pidfds.
+            child.kill().unwrap();
+            child.wait().unwrap();
             return;
         }
         Err(e) => panic!("failed to open pidfd: {}", e),
@@ -76,14 +83,185 @@ fn test_pidfd_poll() {
     let pfd = io::PollFd::new(&pidfd, io::PollFlags::IN);
     io::poll(&mut [pfd], -1).unwrap();
 
-    // The child process should have exited.
+    // The child process should have exited. Leave it to be reaped by
+    // `child.wait()`.
     let status = process::waitid(
         process::WaitId::PidFd(pidfd.as_fd()),
-        process::WaitidOptions::EXITED,
+        process::WaitidOptions::EXITED | process::WaitidOptions::NOWAIT,
     )
     .expect("failed to wait")
     .unwrap();
 
     // TODO
     let _ = status;
+
+    child.wait().unwrap();
+}
+
+#[test]
+#[serial]
+fn test_pidfd_send_signal() {
+    // Create a new process.
+    let mut child = Command::new("sleep")
+        .arg("10")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+
+    // Create a pidfd for the child process.
+    let pid = process::Pid::from_child(&child);
+    let pidfd = match process::pidfd_open(pid, process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(e) if e == rustix::io::Errno::NOSYS => {
+            // The kernel does not support pidfds.
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return;
+        }
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    // Terminate the child process through the pidfd.
+    process::pidfd_send_signal(
+        &pidfd,
+        process::Signal::Term,
+        None,
+        process::PidfdSignalFlags::empty(),
+    )
+    .unwrap();
+
+    // The pidfd becomes readable once the child process has exited.
+    let pfd = io::PollFd::new(&pidfd, io::PollFlags::IN);
+    io::poll(&mut [pfd], -1).unwrap();
+
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitidOptions::EXITED | process::WaitidOptions::NOWAIT,
+    )
+    .expect("failed to wait")
+    .unwrap();
+    assert_eq!(status.terminating_signal(), Some(process::Signal::Term));
+    child.wait().unwrap();
+
+    // Once the child has been reaped, signals can't be sent through the
+    // pidfd, even if its PID is reused.
+    assert_eq!(
+        process::pidfd_send_signal(
+            &pidfd,
+            process::Signal::Term,
+            None,
+            process::PidfdSignalFlags::empty(),
+        ),
+        Err(io::Errno::SRCH)
+    );
+}
+
+#[test]
+#[serial]
+fn test_pidfd_send_signal_siginfo() {
+    // Block `SIGUSR1` so that the child inherits the blocked mask and can
+    // wait for it with `sigwaitinfo`.
+    let mut set = unsafe { core::mem::zeroed() };
+    let mut old = unsafe { core::mem::zeroed() };
+    unsafe {
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGUSR1);
+        assert_eq!(libc::pthread_sigmask(libc::SIG_BLOCK, &set, &mut old), 0);
+    }
+
+    let child = unsafe { libc::fork() };
+    if child == 0 {
+        unsafe {
+            let mut info: libc::siginfo_t = core::mem::zeroed();
+            let ok = libc::sigwaitinfo(&set, &mut info) == libc::SIGUSR1
+                && info.si_code == libc::SI_QUEUE
+                && info.si_value().sival_ptr as usize == 42;
+            libc::_exit(if ok { 0 } else { 1 });
+        }
+    }
+    assert_ne!(child, -1);
+    unsafe {
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_SETMASK, &old, core::ptr::null_mut()),
+            0
+        );
+    }
+
+    let pid = unsafe { process::Pid::from_raw(child as _) }.unwrap();
+    let pidfd = match process::pidfd_open(pid, process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(e) if e == rustix::io::Errno::NOSYS => {
+            // The kernel does not support pidfds.
+            unsafe { kill(child, libc::SIGKILL) };
+            return;
+        }
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    // The signal number in the `siginfo_t` must match.
+    let info = process::PidfdSiginfo::new(process::Signal::Usr1, libc::SI_QUEUE, 42);
+    assert_eq!(info.signal(), Some(process::Signal::Usr1));
+    assert_eq!(info.code(), libc::SI_QUEUE);
+    assert_eq!(info.value(), 42);
+    assert_eq!(
+        process::pidfd_send_signal(
+            &pidfd,
+            process::Signal::Usr2,
+            Some(&info),
+            process::PidfdSignalFlags::empty(),
+        ),
+        Err(io::Errno::INVAL)
+    );
+
+    process::pidfd_send_signal(
+        &pidfd,
+        process::Signal::Usr1,
+        Some(&info),
+        process::PidfdSignalFlags::empty(),
+    )
+    .unwrap();
+
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitidOptions::EXITED,
+    )
+    .expect("failed to wait")
+    .unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+fn test_pidfd_getfd() {
+    let pidfd = match process::pidfd_open(process::getpid(), process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(e) if e == rustix::io::Errno::NOSYS => {
+            // The kernel does not support pidfds.
+            return;
+        }
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    let (reader, writer) = io::pipe().unwrap();
+    let dup = match process::pidfd_getfd(
+        &pidfd,
+        writer.as_raw_fd(),
+        process::PidfdGetfdFlags::empty(),
+    ) {
+        Ok(dup) => dup,
+        Err(e) if e == io::Errno::NOSYS || e == io::Errno::PERM => {
+            // The kernel does not support `pidfd_getfd`, or we aren't
+            // permitted to use it.
+            return;
+        }
+        Err(e) => panic!("failed to get fd: {}", e),
+    };
+    assert_ne!(dup.as_raw_fd(), writer.as_raw_fd());
+    drop(writer);
+
+    // The duplicate refers to the same pipe.
+    assert_eq!(io::write(&dup, b"pidfd").unwrap(), 5);
+    let mut buf = [0_u8; 8];
+    assert_eq!(io::read(&reader, &mut buf).unwrap(), 5);
+    assert_eq!(&buf[..5], b"pidfd");
 }
@@ -0,0 +1,66 @@
+use rustix::io::{Errno, IoSlice, IoSliceMut};
+use rustix::process::{getpid, process_vm_readv, process_vm_writev, RemoteIoVec};
+
+fn remote(buf: &[u8]) -> RemoteIoVec {
+    RemoteIoVec {
+        base: buf.as_ptr() as usize,
+        len: buf.len(),
+    }
+}
+
+#[test]
+fn test_process_vm_readv() {
+    let src = *b"hello world";
+    let mut a = [0_u8; 6];
+    let mut b = [0_u8; 5];
+    let n = process_vm_readv(
+        getpid(),
+        &mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)],
+        &[remote(&src[..5]), remote(&src[5..])],
+    )
+    .unwrap();
+    assert_eq!(n, 11);
+    assert_eq!(&a, b"hello ");
+    assert_eq!(&b, b"world");
+}
+
+#[test]
+fn test_process_vm_readv_partial() {
+    let src = *b"hello";
+    let mut buf = [0_u8; 10];
+    let bad = RemoteIoVec { base: 0, len: 5 };
+
+    // The transfer stops at the first unreadable range.
+    let n = process_vm_readv(
+        getpid(),
+        &mut [IoSliceMut::new(&mut buf)],
+        &[remote(&src), bad],
+    )
+    .unwrap();
+    assert_eq!(n, 5);
+    assert_eq!(&buf[..5], b"hello");
+
+    assert_eq!(
+        process_vm_readv(getpid(), &mut [IoSliceMut::new(&mut buf)], &[bad]),
+        Err(Errno::FAULT)
+    );
+}
+
+#[test]
+fn test_process_vm_writev() {
+    let mut dst = [0_u8; 11];
+    let remote = RemoteIoVec {
+        base: dst.as_mut_ptr() as usize,
+        len: dst.len(),
+    };
+    let n = unsafe {
+        process_vm_writev(
+            getpid(),
+            &[IoSlice::new(b"hello "), IoSlice::new(b"world")],
+            &[remote],
+        )
+        .unwrap()
+    };
+    assert_eq!(n, 11);
+    assert_eq!(&dst, b"hello world");
+}
@@ -0,0 +1,131 @@
+use rustix::process::{
+    getpid, ptrace_cont, ptrace_detach, ptrace_get_syscall_info, ptrace_geteventmsg,
+    ptrace_getsiginfo, ptrace_interrupt, ptrace_peekdata, ptrace_pokedata, ptrace_seize,
+    ptrace_setoptions, ptrace_syscall, ptrace_traceme, waitpid, Pid, PtraceEvent, PtraceOptions,
+    PtraceSyscallInfoOp, Signal, WaitOptions,
+};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static VALUE: AtomicUsize = AtomicUsize::new(1);
+
+/// Fork a child which stops itself with `SIGSTOP` as a tracee of the calling
+/// process, and then exits with the value of `VALUE`.
+fn fork_tracee() -> Pid {
+    unsafe {
+        match libc::fork() {
+            -1 => panic!("fork failed"),
+            0 => {
+                // Only async-signal-safe functions may be called here.
+                if ptrace_traceme().is_err() {
+                    libc::_exit(100);
+                }
+                libc::raise(libc::SIGSTOP);
+                libc::_exit(VALUE.load(Ordering::SeqCst) as i32);
+            }
+            pid => Pid::from_raw(pid as _).unwrap(),
+        }
+    }
+}
+
+fn wait_stopped(pid: Pid) -> rustix::process::WaitStatus {
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert!(status.stopped(), "{:?}", status);
+    status
+}
+
+#[test]
+fn test_ptrace_peek_poke() {
+    let pid = fork_tracee();
+    let status = wait_stopped(pid);
+    assert_eq!(status.stopping_signal(), Some(Signal::Stop));
+    assert_eq!(status.ptrace_event(), None);
+
+    let siginfo = ptrace_getsiginfo(pid).unwrap();
+    assert_eq!(siginfo.signal(), Some(Signal::Stop));
+    assert_eq!(siginfo.pid(), Some(pid));
+
+    ptrace_setoptions(pid, PtraceOptions::TRACEEXIT | PtraceOptions::EXITKILL).unwrap();
+
+    // The child is a copy of this process, so `VALUE` is at the same address.
+    let addr = &VALUE as *const AtomicUsize as usize;
+    assert_eq!(ptrace_peekdata(pid, addr).unwrap(), 1);
+    // SAFETY: The forked child has its own address space.
+    unsafe { ptrace_pokedata(pid, addr, 42) }.unwrap();
+    assert_eq!(ptrace_peekdata(pid, addr).unwrap(), 42);
+
+    // Resume without delivering the `SIGSTOP`, and stop again at exit.
+    ptrace_cont(pid, None).unwrap();
+    let status = wait_stopped(pid);
+    assert_eq!(status.ptrace_event(), Some(PtraceEvent::Exit));
+    assert_eq!(ptrace_geteventmsg(pid).unwrap(), 42 << 8);
+
+    ptrace_cont(pid, None).unwrap();
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(42));
+}
+
+#[test]
+fn test_ptrace_syscall() {
+    let pid = fork_tracee();
+    wait_stopped(pid);
+    ptrace_setoptions(pid, PtraceOptions::TRACESYSGOOD | PtraceOptions::EXITKILL).unwrap();
+
+    // The next system call is the `exit_group` in `_exit`.
+    ptrace_syscall(pid, None).unwrap();
+    let status = wait_stopped(pid);
+    assert!(status.syscall_stopped());
+    assert_eq!(status.ptrace_event(), None);
+    assert_eq!(
+        status.stopping_signal_raw(),
+        Some(Signal::Trap.as_raw() as u32 | 0x80)
+    );
+    assert_eq!(status.stopping_signal(), None);
+
+    match ptrace_get_syscall_info(pid) {
+        Ok(info) => match info.op {
+            PtraceSyscallInfoOp::Entry { nr, args } => {
+                assert_eq!(nr, libc::SYS_exit_group as u64);
+                assert_eq!(args[0], 1);
+            }
+            op => panic!("{:?}", op),
+        },
+        // `PTRACE_GET_SYSCALL_INFO` is new in Linux 5.3.
+        Err(rustix::io::Errno::IO) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        let regs = rustix::process::ptrace_getregset(pid).unwrap();
+        assert_eq!(regs.orig_rax, libc::SYS_exit_group as u64);
+        assert_eq!(regs.rdi, 1);
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        let regs = rustix::process::ptrace_getregset(pid).unwrap();
+        assert_eq!(regs.regs[8], libc::SYS_exit_group as u64);
+    }
+
+    ptrace_cont(pid, None).unwrap();
+    let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(1));
+}
+
+#[test]
+fn test_ptrace_seize_interrupt() {
+    let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    let pid = Pid::from_child(&child);
+
+    ptrace_seize(pid, PtraceOptions::EXITKILL).unwrap();
+    ptrace_interrupt(pid).unwrap();
+    let status = wait_stopped(pid);
+    assert_eq!(status.ptrace_event(), Some(PtraceEvent::Stop));
+
+    // Tracing ourselves isn't allowed.
+    assert!(ptrace_seize(getpid(), PtraceOptions::empty()).is_err());
+
+    ptrace_detach(pid, None).unwrap();
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
@@ -0,0 +1,101 @@
+use rustix::io::{dup, Errno};
+use rustix::process::seccomp::*;
+use rustix::thread::{gettid, set_no_new_privs};
+use std::sync::mpsc;
+
+// Classic BPF opcodes.
+const BPF_LD_W_ABS: u16 = 0x20;
+const BPF_JMP_JEQ_K: u16 = 0x15;
+const BPF_RET_K: u16 = 0x06;
+
+/// A filter which returns `action` for `dup`, and allows everything else.
+fn dup_filter(action: SeccompAction) -> [SockFilter; 4] {
+    [
+        SockFilter::stmt(BPF_LD_W_ABS, 0),
+        SockFilter::jump(BPF_JMP_JEQ_K, libc::SYS_dup as u32, 0, 1),
+        SockFilter::stmt(BPF_RET_K, action.as_raw()),
+        SockFilter::stmt(BPF_RET_K, SeccompAction::ALLOW.as_raw()),
+    ]
+}
+
+#[test]
+fn test_seccomp_get_action_avail() {
+    match seccomp_get_action_avail(SeccompAction::ALLOW) {
+        Ok(avail) => assert!(avail),
+        // `SECCOMP_GET_ACTION_AVAIL` is new in Linux 4.14.
+        Err(Errno::INVAL) | Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert!(seccomp_get_action_avail(SeccompAction::errno(Errno::PERM)).unwrap());
+    assert!(!seccomp_get_action_avail(SeccompAction::from_raw(0x1234_0000)).unwrap());
+}
+
+#[test]
+fn test_seccomp_set_mode_filter() {
+    // Filters can't be removed, so install it on a thread of its own.
+    std::thread::spawn(|| {
+        let stdout = std::io::stdout();
+        dup(&stdout).unwrap();
+
+        set_no_new_privs(true).unwrap();
+        let filter = dup_filter(SeccompAction::errno(Errno::PERM));
+        // SAFETY: The filter only makes `dup` fail.
+        let listener =
+            unsafe { seccomp_set_mode_filter(&filter, SeccompFilterFlags::empty()) }.unwrap();
+        assert!(listener.is_none());
+
+        assert_eq!(dup(&stdout).unwrap_err(), Errno::PERM);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_seccomp_set_mode_filter_too_long() {
+    let filter = vec![SockFilter::stmt(BPF_RET_K, 0); 0x1_0000];
+    assert_eq!(
+        unsafe { seccomp_set_mode_filter(&filter, SeccompFilterFlags::empty()) }.unwrap_err(),
+        Errno::INVAL
+    );
+}
+
+#[test]
+fn test_seccomp_notif() {
+    let (sender, receiver) = mpsc::channel();
+    let target = std::thread::spawn(move || {
+        set_no_new_privs(true).unwrap();
+        let filter = dup_filter(SeccompAction::USER_NOTIF);
+        // SAFETY: The supervisor below only makes `dup` fail.
+        let listener =
+            match unsafe { seccomp_set_mode_filter(&filter, SeccompFilterFlags::NEW_LISTENER) } {
+                Ok(listener) => listener.unwrap(),
+                // `SECCOMP_FILTER_FLAG_NEW_LISTENER` is new in Linux 5.0.
+                Err(Errno::INVAL) => {
+                    sender.send(None).unwrap();
+                    return;
+                }
+                Err(err) => panic!("{:?}", err),
+            };
+        sender.send(Some((listener, gettid()))).unwrap();
+
+        // This blocks until the supervisor responds.
+        assert_eq!(dup(std::io::stdout()).unwrap_err(), Errno::ACCESS);
+    });
+
+    let (listener, tid) = match receiver.recv().unwrap() {
+        Some(pair) => pair,
+        None => return,
+    };
+    let notif = seccomp_notif_recv(&listener).unwrap();
+    assert_eq!(notif.data.nr, libc::SYS_dup as i32);
+    assert_eq!(notif.data.args[0], 1);
+    assert_eq!(notif.pid, Some(tid));
+    assert!(seccomp_notif_id_valid(&listener, notif.id).unwrap());
+
+    let resp = SeccompNotifResp::error(notif.id, Errno::ACCESS);
+    assert_eq!(resp.id(), notif.id);
+    seccomp_notif_send(&listener, &resp).unwrap();
+
+    target.join().unwrap();
+    assert!(!seccomp_notif_id_valid(&listener, notif.id).unwrap());
+}
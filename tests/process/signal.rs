@@ -0,0 +1,61 @@
+//! Tests for the `Signal` type.
+
+use rustix::process::{self, Signal};
+use serial_test::serial;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_signal_round_trip() {
+    assert_eq!(Signal::from_raw(libc::SIGTERM), Some(Signal::Term));
+    assert_eq!(Signal::Term.as_raw(), libc::SIGTERM);
+    assert_eq!(Signal::from_raw(0), None);
+    assert_eq!(Signal::from_raw(-1), None);
+    assert_eq!(format!("{:?}", Signal::Usr1), "Usr1");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_signal_rt() {
+    let min = Signal::rt_min();
+    let max = Signal::rt_max();
+    assert!(min.as_raw() < max.as_raw());
+    // libc may reserve some real-time signals for itself, in which case the
+    // libc backend skips them.
+    assert!(min.as_raw() <= libc::SIGRTMIN());
+    #[cfg(rustix_use_libc)]
+    assert_eq!(min.as_raw(), libc::SIGRTMIN());
+    assert_eq!(max.as_raw(), libc::SIGRTMAX());
+
+    assert_eq!(Signal::rt(0), Some(min));
+    assert_eq!(Signal::rt((max.as_raw() - min.as_raw()) as u32), Some(max));
+    assert_eq!(Signal::rt((max.as_raw() - min.as_raw() + 1) as u32), None);
+
+    // Every real-time signal round-trips through its raw number.
+    for raw in min.as_raw()..=max.as_raw() {
+        assert_eq!(Signal::from_raw(raw).unwrap().as_raw(), raw);
+    }
+    assert_eq!(Signal::from_raw(max.as_raw() + 1), None);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[serial]
+fn test_kill_rt_signal() {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut child = Command::new("sleep")
+        .arg("10")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { process::Pid::from_raw(child.id() as _) }.unwrap();
+
+    // Use `SIGRTMAX`, since the low real-time signals may be handled by the
+    // child's libc.
+    let sig = Signal::rt_max();
+    process::kill_process(pid, sig).unwrap();
+
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(sig.as_raw()));
+}
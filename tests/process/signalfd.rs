@@ -0,0 +1,73 @@
+//! Tests for `signalfd`.
+
+use rustix::io;
+use rustix::process::{
+    signalfd, signalfd_read, signalfd_set_mask, SigSet, Signal, SignalfdFlags, SignalfdSiginfo,
+};
+
+/// Block `sig` in the current thread, so that it's only delivered through a
+/// signalfd.
+fn block(sig: libc::c_int) {
+    unsafe {
+        let mut set = std::mem::MaybeUninit::<libc::sigset_t>::uninit();
+        libc::sigemptyset(set.as_mut_ptr());
+        libc::sigaddset(set.as_mut_ptr(), sig);
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, set.as_ptr(), std::ptr::null_mut()),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_sigset() {
+    let mut set = SigSet::empty();
+    assert!(set.is_empty());
+    assert!(!set.contains(Signal::Usr1));
+
+    set.insert(Signal::Usr1);
+    set.insert(Signal::Term);
+    assert!(set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+    assert!(!set.contains(Signal::Usr2));
+    assert_eq!(set, [Signal::Term, Signal::Usr1].iter().copied().collect());
+
+    set.remove(Signal::Usr1);
+    assert!(!set.contains(Signal::Usr1));
+    assert!(SigSet::full().contains(Signal::Kill));
+}
+
+#[test]
+fn test_signalfd() {
+    block(libc::SIGUSR1);
+    block(libc::SIGUSR2);
+
+    let mask = [Signal::Usr1].iter().copied().collect::<SigSet>();
+    let fd = signalfd(&mask, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK).unwrap();
+
+    let mut buf = [SignalfdSiginfo::default(); 4];
+    assert_eq!(signalfd_read(&fd, &mut buf), Err(io::Errno::AGAIN));
+
+    unsafe { libc::raise(libc::SIGUSR1) };
+    assert_eq!(signalfd_read(&fd, &mut buf).unwrap(), 1);
+    assert_eq!(buf[0].signal(), Some(Signal::Usr1));
+    assert_eq!(buf[0].raw_signal(), libc::SIGUSR1);
+    assert_eq!(buf[0].pid(), Some(rustix::process::getpid()));
+    assert_eq!(buf[0].uid(), rustix::process::getuid());
+
+    // Switch the mask to `SIGUSR2`.
+    let mask = [Signal::Usr2].iter().copied().collect::<SigSet>();
+    signalfd_set_mask(&fd, &mask, SignalfdFlags::empty()).unwrap();
+
+    unsafe { libc::raise(libc::SIGUSR2) };
+    assert_eq!(signalfd_read(&fd, &mut buf).unwrap(), 1);
+    assert_eq!(buf[0].signal(), Some(Signal::Usr2));
+}
+
+#[test]
+fn test_signalfd_siginfo_layout() {
+    assert_eq!(
+        std::mem::size_of::<SignalfdSiginfo>(),
+        std::mem::size_of::<libc::signalfd_siginfo>()
+    );
+}
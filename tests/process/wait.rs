@@ -51,7 +51,7 @@ fn test_waitid() {
 
     assert!(status.stopped());
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia")))]
-    assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+    assert_eq!(status.stopping_signal(), Some(process::Signal::Stop));
 
     unsafe { kill(child.id() as _, SIGCONT) };
 
@@ -83,7 +83,7 @@ fn test_waitid() {
 
     assert!(status.killed());
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia")))]
-    assert_eq!(status.terminating_signal(), Some(SIGKILL as _));
+    assert_eq!(status.terminating_signal(), Some(process::Signal::Kill));
 
     let status = process::waitid(
         process::WaitId::Pid(pid.unwrap()),
@@ -94,5 +94,10 @@ fn test_waitid() {
 
     assert!(status.killed());
     #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia")))]
-    assert_eq!(status.terminating_signal(), Some(SIGKILL as _));
+    assert_eq!(status.terminating_signal(), Some(process::Signal::Kill));
+    #[cfg(not(any(target_os = "netbsd", target_os = "fuchsia")))]
+    assert_eq!(
+        status.terminating_signal_raw(),
+        Some(process::Signal::Kill.as_raw() as u32)
+    );
 }
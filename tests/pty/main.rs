@@ -0,0 +1,8 @@
+//! Tests for [`rustix::pty`].
+
+#![cfg(feature = "pty")]
+#![cfg(not(any(windows, target_os = "fuchsia", target_os = "wasi")))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod openpt;
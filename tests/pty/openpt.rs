@@ -0,0 +1,67 @@
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::io::{read, write};
+use rustix::pty::{grantpt, openpt, ptsname, unlockpt, OpenptFlags};
+
+#[test]
+fn test_openpt() {
+    let controller = openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC)
+        .expect("opening the pty controller");
+    grantpt(&controller).unwrap();
+    unlockpt(&controller).unwrap();
+
+    let name = ptsname(&controller, Vec::new()).unwrap();
+    assert!(name.to_bytes().starts_with(b"/dev/pts/"));
+
+    let user = openat(
+        cwd(),
+        &name,
+        OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // Data written to the user side is readable from the controller side.
+    // With the default line discipline, "\n" is translated to "\r\n".
+    assert_eq!(write(&user, b"hello\n").unwrap(), 6);
+    let mut buf = [0_u8; 16];
+    let n = read(&controller, &mut buf).unwrap();
+    assert_eq!(&buf[..n], b"hello\r\n");
+}
+
+#[test]
+fn test_ptsname_reuse() {
+    let controller = openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY).unwrap();
+    let name = ptsname(&controller, Vec::new()).unwrap();
+
+    // A tiny buffer is grown as needed.
+    let again = ptsname(&controller, vec![0_u8; 1]).unwrap();
+    assert_eq!(name, again);
+}
+
+#[test]
+fn test_ptsname_not_a_pty() {
+    let file = openat(cwd(), "Cargo.toml", OFlags::RDONLY, Mode::empty()).unwrap();
+    assert!(ptsname(&file, Vec::new()).is_err());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_ioctl_tiocgptpeer() {
+    use rustix::pty::ioctl_tiocgptpeer;
+
+    let controller = openpt(OpenptFlags::RDWR | OpenptFlags::NOCTTY).unwrap();
+    grantpt(&controller).unwrap();
+    unlockpt(&controller).unwrap();
+
+    let user = match ioctl_tiocgptpeer(&controller, OpenptFlags::RDWR | OpenptFlags::NOCTTY) {
+        Ok(user) => user,
+        // `TIOCGPTPEER` is new in Linux 4.13.
+        Err(rustix::io::Errno::INVAL) | Err(rustix::io::Errno::NOTTY) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    assert_eq!(write(&controller, b"x\n").unwrap(), 2);
+    let mut buf = [0_u8; 16];
+    let n = read(&user, &mut buf).unwrap();
+    assert_eq!(&buf[..n], b"x\n");
+}
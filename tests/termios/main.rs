@@ -5,6 +5,8 @@
 
 #[cfg(not(windows))]
 mod isatty;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod termios;
 #[cfg(not(any(windows, target_os = "fuchsia")))]
 #[cfg(feature = "procfs")]
 mod ttyname;
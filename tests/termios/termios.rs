@@ -0,0 +1,201 @@
+//! Tests for the [`rustix::termios::Termios`] accessors, using a
+//! pseudoterminal so that they don't need a real terminal.
+
+use rustix::fd::{AsRawFd, FromRawFd, OwnedFd};
+use rustix::termios::{
+    tcgetattr, tcsetattr, InputModes, LocalModes, OptionalActions, OutputModes, SpecialCodeIndex,
+    TermiosGuard,
+};
+use std::ffi::CStr;
+use std::fs::{File, OpenOptions};
+use std::os::unix::fs::OpenOptionsExt;
+
+/// Open a pseudoterminal, returning the controller and user sides.
+fn openpty() -> (OwnedFd, File) {
+    unsafe {
+        let controller = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC);
+        assert!(controller >= 0);
+        let controller = OwnedFd::from_raw_fd(controller);
+        assert_eq!(libc::grantpt(controller.as_raw_fd()), 0);
+        assert_eq!(libc::unlockpt(controller.as_raw_fd()), 0);
+
+        let mut name = [0 as libc::c_char; 64];
+        assert_eq!(
+            libc::ptsname_r(controller.as_raw_fd(), name.as_mut_ptr(), name.len()),
+            0
+        );
+        let name = CStr::from_ptr(name.as_ptr()).to_str().unwrap();
+        let user = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NOCTTY)
+            .open(name)
+            .unwrap();
+        (controller, user)
+    }
+}
+
+#[test]
+fn test_termios_modes() {
+    let (_controller, user) = openpty();
+
+    let mut termios = tcgetattr(&user).unwrap();
+    assert!(termios.local_modes.contains(LocalModes::ICANON));
+
+    termios.local_modes -= LocalModes::ICANON | LocalModes::ECHO;
+    termios.input_modes |= InputModes::IGNBRK;
+    termios.special_codes[SpecialCodeIndex::VMIN] = 3;
+    termios.special_codes[SpecialCodeIndex::VTIME] = 7;
+    tcsetattr(&user, OptionalActions::Now, &termios).unwrap();
+
+    let new = tcgetattr(&user).unwrap();
+    assert!(!new.local_modes.contains(LocalModes::ICANON));
+    assert!(!new.local_modes.contains(LocalModes::ECHO));
+    assert!(new.input_modes.contains(InputModes::IGNBRK));
+    assert_eq!(new.special_codes[SpecialCodeIndex::VMIN], 3);
+    assert_eq!(new.special_codes[SpecialCodeIndex::VTIME], 7);
+    assert_eq!(new, termios);
+}
+
+#[test]
+fn test_termios_speed() {
+    let (_controller, user) = openpty();
+
+    let mut termios = tcgetattr(&user).unwrap();
+    termios.set_speed(9600).unwrap();
+    tcsetattr(&user, OptionalActions::Now, &termios).unwrap();
+    let new = tcgetattr(&user).unwrap();
+    assert_eq!(new.input_speed(), 9600);
+    assert_eq!(new.output_speed(), 9600);
+
+    termios.set_input_speed(4800).unwrap();
+    termios.set_output_speed(115_200).unwrap();
+    tcsetattr(&user, OptionalActions::Drain, &termios).unwrap();
+    let new = tcgetattr(&user).unwrap();
+    assert_eq!(new.input_speed(), 4800);
+    assert_eq!(new.output_speed(), 115_200);
+}
+
+/// Speeds without `B*` codes work on Linux, via `BOTHER`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_termios_arbitrary_speed() {
+    let (_controller, user) = openpty();
+
+    let mut termios = tcgetattr(&user).unwrap();
+    if termios.set_speed(123_456).is_err() {
+        // Some architectures don't support `BOTHER` with the libc backend.
+        return;
+    }
+    tcsetattr(&user, OptionalActions::Now, &termios).unwrap();
+    let new = tcgetattr(&user).unwrap();
+    assert_eq!(new.input_speed(), 123_456);
+    assert_eq!(new.output_speed(), 123_456);
+}
+
+/// The deprecated `cf*speed` and `*attr2` functions forward to the `Termios`
+/// methods and `tcgetattr`/`tcsetattr`.
+#[allow(deprecated)]
+#[test]
+fn test_termios_deprecated_speed() {
+    use rustix::termios::{
+        cfgetispeed, cfgetospeed, cfsetispeed, cfsetospeed, cfsetspeed, tcgetattr2, tcsetattr2,
+        B115200, B4800, B9600,
+    };
+
+    let (_controller, user) = openpty();
+
+    let mut termios = tcgetattr2(&user).unwrap();
+    cfsetspeed(&mut termios, B9600).unwrap();
+    assert_eq!(termios.input_speed(), 9600);
+    assert_eq!(termios.output_speed(), 9600);
+    tcsetattr2(&user, OptionalActions::Now, &termios).unwrap();
+    let new = tcgetattr(&user).unwrap();
+    assert_eq!(cfgetispeed(&new), B9600);
+    assert_eq!(cfgetospeed(&new), B9600);
+
+    cfsetispeed(&mut termios, B4800).unwrap();
+    cfsetospeed(&mut termios, B115200).unwrap();
+    assert_eq!(termios.input_speed(), 4800);
+    assert_eq!(termios.output_speed(), 115_200);
+    assert_eq!(cfgetispeed(&termios), B4800);
+    assert_eq!(cfgetospeed(&termios), B115200);
+
+    // Speeds without `B*` codes are reported as `BOTHER`.
+    if termios.set_speed(123_456).is_ok() {
+        assert_eq!(cfgetospeed(&termios), libc::BOTHER as _);
+    }
+}
+
+/// `make_raw` makes the same changes as the platform's `cfmakeraw`.
+#[test]
+fn test_make_raw() {
+    let (_controller, user) = openpty();
+
+    let mut expected = unsafe {
+        let mut expected = std::mem::zeroed::<libc::termios>();
+        assert_eq!(libc::tcgetattr(user.as_raw_fd(), &mut expected), 0);
+        libc::cfmakeraw(&mut expected);
+        expected
+    };
+
+    let mut termios = tcgetattr(&user).unwrap();
+    termios.make_raw();
+
+    // The deprecated `cfmakeraw` does the same.
+    let mut deprecated = tcgetattr(&user).unwrap();
+    #[allow(deprecated)]
+    rustix::termios::cfmakeraw(&mut deprecated);
+    assert_eq!(deprecated, termios);
+
+    tcsetattr(&user, OptionalActions::Now, &termios).unwrap();
+
+    let actual = unsafe {
+        let mut actual = std::mem::zeroed::<libc::termios>();
+        assert_eq!(libc::tcgetattr(user.as_raw_fd(), &mut actual), 0);
+        actual
+    };
+
+    // `cfmakeraw` doesn't change the speeds, so ignore them.
+    expected.c_ispeed = actual.c_ispeed;
+    expected.c_ospeed = actual.c_ospeed;
+    assert_eq!(actual.c_iflag, expected.c_iflag);
+    assert_eq!(actual.c_oflag, expected.c_oflag);
+    assert_eq!(actual.c_cflag, expected.c_cflag);
+    assert_eq!(actual.c_lflag, expected.c_lflag);
+    assert_eq!(actual.c_cc, expected.c_cc);
+}
+
+#[test]
+fn test_termios_guard() {
+    let (_controller, user) = openpty();
+
+    let original = tcgetattr(&user).unwrap();
+    assert!(original.output_modes.contains(OutputModes::OPOST));
+    assert!(original.local_modes.contains(LocalModes::ICANON));
+
+    {
+        let guard = TermiosGuard::raw(&user).unwrap();
+        assert_eq!(guard.saved(), &original);
+
+        let raw = tcgetattr(&user).unwrap();
+        assert!(!raw.output_modes.contains(OutputModes::OPOST));
+        assert!(!raw.local_modes.contains(LocalModes::ICANON));
+        assert!(!raw.local_modes.contains(LocalModes::ECHO));
+        assert_eq!(raw.special_codes[SpecialCodeIndex::VMIN], 1);
+        assert_eq!(raw.special_codes[SpecialCodeIndex::VTIME], 0);
+    }
+
+    assert_eq!(tcgetattr(&user).unwrap(), original);
+
+    // A plain guard restores changes made while it's alive.
+    {
+        let _guard = TermiosGuard::new(&user).unwrap();
+        let mut termios = original.clone();
+        termios.local_modes -= LocalModes::ECHO;
+        tcsetattr(&user, OptionalActions::Now, &termios).unwrap();
+        assert_ne!(tcgetattr(&user).unwrap(), original);
+    }
+
+    assert_eq!(tcgetattr(&user).unwrap(), original);
+}